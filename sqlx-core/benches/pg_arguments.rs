@@ -0,0 +1,73 @@
+//! Counts the allocations made to encode the arguments of a query.
+//!
+//! ```text
+//! cargo bench --manifest-path sqlx-core/Cargo.toml --features postgres --bench pg_arguments
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use sqlx_core::arguments::Arguments;
+use sqlx_core::postgres::PgArguments;
+
+const ITERATIONS: usize = 1_000_000;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn encode(id: i64) -> PgArguments {
+    let mut args = PgArguments::default();
+
+    args.add(id);
+    args.add("a name that is a little longer than a few bytes");
+    args.add(true);
+    args.add(2.5_f64);
+
+    args
+}
+
+fn main() {
+    let start = Instant::now();
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+
+    // the arguments of a query are dropped once it has been sent
+    let mut len = 0;
+
+    for id in 0..ITERATIONS {
+        len += encode(id as i64).len();
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    let elapsed = start.elapsed();
+
+    assert_eq!(len, ITERATIONS * 4);
+
+    println!(
+        "pg_arguments: {} queries in {:?}; {} allocations ({:.3} per query)",
+        ITERATIONS,
+        elapsed,
+        allocations,
+        allocations as f64 / ITERATIONS as f64
+    );
+}
@@ -0,0 +1,59 @@
+use crate::any::Any;
+use crate::arguments::Arguments;
+use crate::encode::{Encode, IsNull};
+use crate::types::{AnyValue, Type};
+
+/// The arguments of a query on the [`Any`] database, which are converted to the arguments of
+/// the database that the query is run on.
+#[derive(Default)]
+pub struct AnyArguments {
+    pub(super) values: Vec<AnyValue>,
+}
+
+impl Arguments for AnyArguments {
+    type Database = Any;
+
+    fn reserve(&mut self, len: usize, _size_hint: usize) {
+        self.values.reserve(len);
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn add<T>(&mut self, value: T)
+    where
+        T: Type<Self::Database>,
+        T: Encode<Self::Database>,
+    {
+        if let IsNull::Yes = value.encode_nullable(&mut self.values) {
+            self.values.push(AnyValue::Null);
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<AnyArguments> for crate::postgres::PgArguments {
+    fn from(arguments: AnyArguments) -> Self {
+        let mut pg_arguments = Self::default();
+
+        for value in arguments.values {
+            pg_arguments.add_any(value);
+        }
+
+        pg_arguments
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<AnyArguments> for crate::sqlite::SqliteArguments {
+    fn from(arguments: AnyArguments) -> Self {
+        let mut sqlite_arguments = Self::default();
+
+        for value in arguments.values {
+            sqlite_arguments.add(value);
+        }
+
+        sqlite_arguments
+    }
+}
@@ -0,0 +1,110 @@
+use std::convert::TryInto;
+
+use futures_core::future::BoxFuture;
+
+use crate::any::Any;
+use crate::connection::{Connect, Connection, QueryLogger};
+use crate::url::Url;
+
+#[cfg(feature = "postgres")]
+use crate::postgres::PgConnection;
+
+#[cfg(feature = "sqlite")]
+use crate::sqlite::SqliteConnection;
+
+/// A connection to a **Postgres** or **SQLite** database, chosen from the scheme of the URL
+/// that it is connected with.
+///
+/// ```rust,ignore
+/// // `postgres://` or `postgresql://` connects to Postgres and `sqlite:` to SQLite
+/// let mut conn = AnyConnection::connect(&env::var("DATABASE_URL")?).await?;
+///
+/// let (id,): (i64,) = sqlx::query_as("SELECT $1")
+///     .bind(10_i64)
+///     .fetch_one(&mut conn)
+///     .await?;
+/// ```
+pub struct AnyConnection(pub(super) AnyConnectionKind);
+
+pub(super) enum AnyConnectionKind {
+    #[cfg(feature = "postgres")]
+    Postgres(PgConnection),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteConnection),
+}
+
+impl Connect for AnyConnection {
+    fn connect<T>(url: T) -> BoxFuture<'static, crate::Result<Any, AnyConnection>>
+    where
+        T: TryInto<Url, Error = url::ParseError>,
+        Self: Sized,
+    {
+        let url = url.try_into();
+
+        Box::pin(async move {
+            let url = url?;
+
+            let kind = match url.scheme() {
+                #[cfg(feature = "postgres")]
+                "postgres" | "postgresql" => {
+                    AnyConnectionKind::Postgres(PgConnection::connect(url.as_str()).await?)
+                }
+
+                #[cfg(feature = "sqlite")]
+                "sqlite" => {
+                    AnyConnectionKind::Sqlite(SqliteConnection::connect(url.as_str()).await?)
+                }
+
+                scheme => {
+                    return Err(crate::Error::Configuration(
+                        format!("unsupported database URL scheme: {:?}", scheme).into(),
+                    ));
+                }
+            };
+
+            Ok(AnyConnection(kind))
+        })
+    }
+}
+
+impl Connection for AnyConnection {
+    fn close(self) -> BoxFuture<'static, crate::Result<Any, ()>> {
+        Box::pin(async move {
+            match self.0 {
+                #[cfg(feature = "postgres")]
+                AnyConnectionKind::Postgres(conn) => conn.close().await?,
+
+                #[cfg(feature = "sqlite")]
+                AnyConnectionKind::Sqlite(conn) => conn.close().await?,
+            }
+
+            Ok(())
+        })
+    }
+
+    fn ping(&mut self) -> BoxFuture<crate::Result<Any, ()>> {
+        Box::pin(async move {
+            match &mut self.0 {
+                #[cfg(feature = "postgres")]
+                AnyConnectionKind::Postgres(conn) => conn.ping().await?,
+
+                #[cfg(feature = "sqlite")]
+                AnyConnectionKind::Sqlite(conn) => conn.ping().await?,
+            }
+
+            Ok(())
+        })
+    }
+
+    #[doc(hidden)]
+    fn query_logger(&mut self) -> &mut QueryLogger {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.query_logger(),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.query_logger(),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::{HashMap, VecDeque};
+
+use futures_core::future::BoxFuture;
+
+use crate::any::connection::AnyConnectionKind;
+use crate::any::executor::Parts;
+use crate::any::row::AnyRowKind;
+use crate::any::{Any, AnyArguments, AnyConnection, AnyRow};
+use crate::cursor::Cursor;
+use crate::executor::{Execute, Executor};
+use crate::pool::Pool;
+use crate::row::Row;
+use crate::types::AnyValue;
+
+#[cfg(feature = "postgres")]
+use crate::postgres::{PgCursor, Postgres};
+
+#[cfg(feature = "sqlite")]
+use crate::sqlite::{Sqlite, SqliteCursor};
+
+pub struct AnyCursor<'c, 'q>(AnyCursorKind<'c, 'q>);
+
+enum AnyCursorKind<'c, 'q> {
+    #[cfg(feature = "postgres")]
+    Postgres(PgCursor<'c, 'q>),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteCursor<'c, 'q>),
+
+    // The rows of a driver borrow from the connection that they are fetched on, which can not
+    // be kept next to it in the cursor; so, all of the rows of a query on a pool are fetched
+    // when the first row is requested
+    Pool {
+        pool: Pool<AnyConnection>,
+        query: Option<(&'q str, Option<AnyArguments>)>,
+        columns: HashMap<Box<str>, usize>,
+        rows: VecDeque<Vec<AnyValue>>,
+        row: Option<Vec<AnyValue>>,
+    },
+}
+
+impl crate::cursor::private::Sealed for AnyCursor<'_, '_> {}
+
+impl<'c, 'q> Cursor<'c, 'q> for AnyCursor<'c, 'q> {
+    type Database = Any;
+
+    #[doc(hidden)]
+    fn from_pool<E>(pool: &Pool<AnyConnection>, query: E) -> Self
+    where
+        Self: Sized,
+        E: Execute<'q, Any>,
+    {
+        Self(AnyCursorKind::Pool {
+            pool: pool.clone(),
+            query: Some(query.into_parts()),
+            columns: HashMap::new(),
+            rows: VecDeque::new(),
+            row: None,
+        })
+    }
+
+    #[doc(hidden)]
+    fn from_connection<E>(conn: &'c mut AnyConnection, query: E) -> Self
+    where
+        Self: Sized,
+        E: Execute<'q, Any>,
+    {
+        let (query, arguments) = query.into_parts();
+
+        Self(match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                AnyCursorKind::Postgres(PgCursor::from_connection(
+                    conn,
+                    Parts::<Postgres>(query, arguments.map(Into::into)),
+                ))
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                AnyCursorKind::Sqlite(SqliteCursor::from_connection(
+                    conn,
+                    Parts::<Sqlite>(query, arguments.map(Into::into)),
+                ))
+            }
+        })
+    }
+
+    fn next(&mut self) -> BoxFuture<crate::Result<Any, Option<AnyRow<'_>>>> {
+        Box::pin(next(self))
+    }
+}
+
+async fn next<'a, 'c: 'a, 'q: 'a>(
+    cursor: &'a mut AnyCursor<'c, 'q>,
+) -> crate::Result<Any, Option<AnyRow<'a>>> {
+    let kind = match &mut cursor.0 {
+        #[cfg(feature = "postgres")]
+        AnyCursorKind::Postgres(cursor) => {
+            return Ok(cursor.next().await?.map(AnyRowKind::Postgres).map(AnyRow))
+        }
+
+        #[cfg(feature = "sqlite")]
+        AnyCursorKind::Sqlite(cursor) => {
+            return Ok(cursor.next().await?.map(AnyRowKind::Sqlite).map(AnyRow))
+        }
+
+        AnyCursorKind::Pool {
+            pool,
+            query,
+            columns,
+            rows,
+            row,
+        } => {
+            if let Some((query, arguments)) = query.take() {
+                let mut conn = pool.acquire().await?;
+                let mut cursor = conn.fetch(Parts::<Any>(query, arguments));
+
+                while let Some(next) = cursor.next().await? {
+                    if columns.is_empty() {
+                        *columns = next.column_names();
+                    }
+
+                    let values = (0..next.len())
+                        .map(|index| next.try_get_raw(index))
+                        .collect::<crate::Result<Any, _>>()?;
+
+                    rows.push_back(values);
+                }
+            }
+
+            *row = rows.pop_front();
+
+            let columns = &*columns;
+
+            row.as_deref()
+                .map(move |values| AnyRowKind::Buffered { columns, values })
+        }
+    };
+
+    Ok(kind.map(AnyRow))
+}
@@ -0,0 +1,53 @@
+use crate::any::error::AnyError;
+use crate::database::{Database, HasCursor, HasRawValue, HasRow};
+use crate::transaction::Options as TransactionOptions;
+use crate::types::AnyValue;
+
+/// A database driver for **Postgres** or **SQLite**, chosen at runtime from the scheme of
+/// the connection URL.
+#[derive(Debug)]
+pub struct Any;
+
+impl Database for Any {
+    type Connection = super::AnyConnection;
+
+    type Arguments = super::AnyArguments;
+
+    type TypeInfo = super::AnyTypeInfo;
+
+    type TableId = String;
+
+    type RawBuffer = Vec<AnyValue>;
+
+    type Error = AnyError;
+
+    // The statement to begin a transaction with options depends on the database, which is not
+    // known here; only a transaction with the default options can be begun
+    fn begin_transaction(
+        options: &TransactionOptions,
+    ) -> crate::Result<Self, (String, Option<&'static str>)> {
+        if options.read_only || options.isolation_level.is_some() {
+            return Err(crate::Error::Configuration(
+                "transaction options are not supported with the `Any` driver".into(),
+            ));
+        }
+
+        Ok(("BEGIN".into(), None))
+    }
+}
+
+impl<'c> HasRow<'c> for Any {
+    type Database = Any;
+
+    type Row = super::AnyRow<'c>;
+}
+
+impl<'c, 'q> HasCursor<'c, 'q> for Any {
+    type Database = Any;
+
+    type Cursor = super::AnyCursor<'c, 'q>;
+}
+
+impl<'c> HasRawValue<'c> for Any {
+    type RawValue = AnyValue;
+}
@@ -0,0 +1,95 @@
+use std::error::Error as StdError;
+use std::fmt::{self, Display};
+
+use crate::any::Any;
+use crate::database::Database;
+use crate::error::DatabaseError;
+
+/// An error returned by the database that an [`AnyConnection`] is connected to.
+///
+/// [`AnyConnection`]: crate::any::AnyConnection
+#[derive(Debug)]
+pub struct AnyError(Box<dyn DatabaseError>);
+
+impl Display for AnyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl DatabaseError for AnyError {
+    fn message(&self) -> &str {
+        self.0.message()
+    }
+
+    fn code(&self) -> Option<&str> {
+        self.0.code()
+    }
+
+    fn details(&self) -> Option<&str> {
+        self.0.details()
+    }
+
+    fn hint(&self) -> Option<&str> {
+        self.0.hint()
+    }
+
+    fn table_name(&self) -> Option<&str> {
+        self.0.table_name()
+    }
+
+    fn column_name(&self) -> Option<&str> {
+        self.0.column_name()
+    }
+
+    fn constraint_name(&self) -> Option<&str> {
+        self.0.constraint_name()
+    }
+
+    fn is_retryable(&self) -> bool {
+        self.0.is_retryable()
+    }
+}
+
+impl StdError for AnyError {}
+
+// Every error of a driver is kept as it is except for the error of the database itself
+pub(super) fn from_driver<DB>(err: crate::Error<DB>) -> crate::Error<Any>
+where
+    DB: Database,
+    DB::Error: 'static,
+{
+    use crate::Error;
+
+    match err {
+        Error::Io(err) => Error::Io(err),
+        Error::UrlParse(err) => Error::UrlParse(err),
+        Error::Database(err) => Error::Database(Box::new(AnyError(err))),
+        Error::RowNotFound => Error::RowNotFound,
+        Error::TooManyRows => Error::TooManyRows,
+        Error::ColumnNotFound(name) => Error::ColumnNotFound(name),
+        Error::ColumnIndexOutOfBounds { index, len } => {
+            Error::ColumnIndexOutOfBounds { index, len }
+        }
+        Error::Protocol(err) => Error::Protocol(err),
+        Error::PoolTimedOut(err) => Error::PoolTimedOut(err),
+        Error::PoolClosed => Error::PoolClosed,
+        Error::Tls(err) => Error::Tls(err),
+        Error::Decode(err) => Error::Decode(err),
+        Error::Configuration(err) => Error::Configuration(err),
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<crate::Error<crate::postgres::Postgres>> for crate::Error<Any> {
+    fn from(err: crate::Error<crate::postgres::Postgres>) -> Self {
+        from_driver(err)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<crate::Error<crate::sqlite::Sqlite>> for crate::Error<Any> {
+    fn from(err: crate::Error<crate::sqlite::Sqlite>) -> Self {
+        from_driver(err)
+    }
+}
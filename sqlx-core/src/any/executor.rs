@@ -0,0 +1,125 @@
+use futures_core::future::BoxFuture;
+
+use crate::any::connection::AnyConnectionKind;
+use crate::any::{Any, AnyConnection, AnyCursor, AnyTypeInfo};
+use crate::cursor::Cursor;
+use crate::database::Database;
+use crate::describe::{Column, Describe};
+use crate::executor::{Execute, Executor, RefExecutor};
+
+#[cfg(feature = "postgres")]
+use crate::postgres::Postgres;
+
+#[cfg(feature = "sqlite")]
+use crate::sqlite::Sqlite;
+
+// A query with its arguments, converted for the database that it is run on
+pub(super) struct Parts<'q, DB: Database>(pub(super) &'q str, pub(super) Option<DB::Arguments>);
+
+impl<'q, DB> Execute<'q, DB> for Parts<'q, DB>
+where
+    DB: Database,
+{
+    fn into_parts(self) -> (&'q str, Option<DB::Arguments>) {
+        (self.0, self.1)
+    }
+}
+
+fn describe_from<DB>(describe: Describe<DB>) -> Describe<Any>
+where
+    DB: Database,
+    for<'a> AnyTypeInfo: From<&'a DB::TypeInfo>,
+{
+    Describe {
+        param_types: describe
+            .param_types
+            .iter()
+            .map(|ty| ty.as_ref().map(AnyTypeInfo::from))
+            .collect(),
+
+        result_columns: describe
+            .result_columns
+            .into_vec()
+            .into_iter()
+            .map(|column| Column {
+                name: column.name,
+                table_id: column.table_id.map(|id| id.to_string()),
+                type_info: column.type_info.as_ref().map(AnyTypeInfo::from),
+                non_null: column.non_null,
+            })
+            .collect(),
+    }
+}
+
+impl Executor for AnyConnection {
+    type Database = Any;
+
+    fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
+        &'c mut self,
+        query: E,
+    ) -> BoxFuture<'e, crate::Result<Any, u64>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        let (query, arguments) = query.into_parts();
+
+        Box::pin(async move {
+            Ok(match &mut self.0 {
+                #[cfg(feature = "postgres")]
+                AnyConnectionKind::Postgres(conn) => {
+                    conn.execute(Parts::<Postgres>(query, arguments.map(Into::into)))
+                        .await?
+                }
+
+                #[cfg(feature = "sqlite")]
+                AnyConnectionKind::Sqlite(conn) => {
+                    conn.execute(Parts::<Sqlite>(query, arguments.map(Into::into)))
+                        .await?
+                }
+            })
+        })
+    }
+
+    fn fetch<'q, E>(&mut self, query: E) -> AnyCursor<'_, 'q>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        AnyCursor::from_connection(self, query)
+    }
+
+    #[doc(hidden)]
+    fn describe<'e, 'q, E: 'e>(
+        &'e mut self,
+        query: E,
+    ) -> BoxFuture<'e, crate::Result<Any, Describe<Self::Database>>>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        Box::pin(async move {
+            let query = query.into_parts().0;
+
+            Ok(match &mut self.0 {
+                #[cfg(feature = "postgres")]
+                AnyConnectionKind::Postgres(conn) => {
+                    describe_from(conn.describe(Parts::<Postgres>(query, None)).await?)
+                }
+
+                #[cfg(feature = "sqlite")]
+                AnyConnectionKind::Sqlite(conn) => {
+                    describe_from(conn.describe(Parts::<Sqlite>(query, None)).await?)
+                }
+            })
+        })
+    }
+}
+
+impl<'c> RefExecutor<'c> for &'c mut AnyConnection {
+    type Database = Any;
+
+    fn fetch_by_ref<'q, E>(self, query: E) -> AnyCursor<'c, 'q>
+    where
+        E: Execute<'q, Self::Database>,
+    {
+        AnyCursor::from_connection(self, query)
+    }
+}
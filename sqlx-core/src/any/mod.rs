@@ -0,0 +1,32 @@
+//! **Postgres** or **SQLite** database and connection types, for a driver that is chosen at
+//! runtime from the connection URL.
+//!
+//! Values are sent and received as an [`AnyValue`](crate::types::AnyValue), which each driver
+//! encodes and decodes as one of its own types.
+
+mod arguments;
+mod connection;
+mod cursor;
+mod database;
+mod error;
+mod executor;
+mod row;
+mod types;
+
+pub use arguments::AnyArguments;
+pub use connection::AnyConnection;
+pub use cursor::AnyCursor;
+pub use database::Any;
+pub use error::AnyError;
+pub use row::AnyRow;
+pub use types::AnyTypeInfo;
+
+pub(crate) use types::AnyTypeKind;
+
+/// An alias for [`Pool`][crate::pool::Pool], specialized for the **Any** driver.
+#[cfg_attr(docsrs, doc(cfg(feature = "any")))]
+pub type AnyPool = crate::pool::Pool<AnyConnection>;
+
+make_query_as!(AnyQueryAs, Any, AnyRow);
+impl_map_row_for_row!(Any, AnyRow);
+impl_from_row_for_tuples!(Any, AnyRow);
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use crate::any::Any;
+use crate::row::{ColumnIndex, Row};
+use crate::types::AnyValue;
+
+#[cfg(feature = "postgres")]
+use crate::postgres::PgRow;
+
+#[cfg(feature = "sqlite")]
+use crate::sqlite::SqliteRow;
+
+/// A row of a query on the [`Any`] database.
+///
+/// A value of the row is decoded through the [`AnyValue`] that it is received as.
+pub struct AnyRow<'c>(pub(super) AnyRowKind<'c>);
+
+pub(super) enum AnyRowKind<'c> {
+    #[cfg(feature = "postgres")]
+    Postgres(PgRow<'c>),
+
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteRow<'c>),
+
+    // A row that was fetched ahead of time on a connection from a pool
+    Buffered {
+        columns: &'c HashMap<Box<str>, usize>,
+        values: &'c [AnyValue],
+    },
+}
+
+impl crate::row::private_row::Sealed for AnyRow<'_> {}
+
+impl AnyRow<'_> {
+    // The position of each named column, to look it up in a row that was fetched ahead of time
+    pub(super) fn column_names(&self) -> HashMap<Box<str>, usize> {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyRowKind::Postgres(row) => row
+                .columns()
+                .iter()
+                .filter_map(|column| Some((column.name()?.into(), column.ordinal())))
+                .collect(),
+
+            #[cfg(feature = "sqlite")]
+            AnyRowKind::Sqlite(row) => row
+                .columns()
+                .iter()
+                .map(|column| (column.name().into(), column.ordinal()))
+                .collect(),
+
+            AnyRowKind::Buffered { columns, .. } => (*columns).clone(),
+        }
+    }
+}
+
+impl<'c> Row<'c> for AnyRow<'c> {
+    type Database = Any;
+
+    fn len(&self) -> usize {
+        match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyRowKind::Postgres(row) => row.len(),
+
+            #[cfg(feature = "sqlite")]
+            AnyRowKind::Sqlite(row) => row.len(),
+
+            AnyRowKind::Buffered { values, .. } => values.len(),
+        }
+    }
+
+    #[doc(hidden)]
+    fn try_get_raw<I>(&self, index: I) -> crate::Result<Any, AnyValue>
+    where
+        I: ColumnIndex<'c, Self>,
+    {
+        let index = index.index(self)?;
+
+        Ok(match &self.0 {
+            #[cfg(feature = "postgres")]
+            AnyRowKind::Postgres(row) => row.try_get_any(index)?,
+
+            #[cfg(feature = "sqlite")]
+            AnyRowKind::Sqlite(row) => row.try_get::<AnyValue, _>(index)?,
+
+            AnyRowKind::Buffered { values, .. } => values[index].clone(),
+        })
+    }
+}
+
+impl<'c> ColumnIndex<'c, AnyRow<'c>> for usize {
+    fn index(&self, row: &AnyRow<'c>) -> crate::Result<Any, usize> {
+        let len = Row::len(row);
+
+        if *self >= len {
+            return Err(crate::Error::ColumnIndexOutOfBounds { len, index: *self });
+        }
+
+        Ok(*self)
+    }
+}
+
+impl<'c> ColumnIndex<'c, AnyRow<'c>> for str {
+    fn index(&self, row: &AnyRow<'c>) -> crate::Result<Any, usize> {
+        Ok(match &row.0 {
+            #[cfg(feature = "postgres")]
+            AnyRowKind::Postgres(row) => ColumnIndex::index(self, row)?,
+
+            #[cfg(feature = "sqlite")]
+            AnyRowKind::Sqlite(row) => ColumnIndex::index(self, row)?,
+
+            AnyRowKind::Buffered { columns, .. } => *columns
+                .get(self)
+                .ok_or_else(|| crate::Error::<Any>::ColumnNotFound((*self).into()))?,
+        })
+    }
+}
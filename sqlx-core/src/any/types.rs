@@ -0,0 +1,323 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+
+use crate::any::Any;
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::error::UnexpectedNullError;
+use crate::types::{AnyValue, Type, TypeInfo};
+
+/// Type information for the [`Any`] database; the kind of [`AnyValue`] that a value is sent
+/// or received as.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnyTypeInfo(pub(crate) AnyTypeKind);
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum AnyTypeKind {
+    Bool,
+    Int,
+    Float,
+    Text,
+    Bytes,
+
+    #[cfg(feature = "json")]
+    Json,
+
+    #[cfg(feature = "uuid")]
+    Uuid,
+
+    // Any other type, by its name in the database
+    Other(String),
+}
+
+impl Display for AnyTypeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name())
+    }
+}
+
+impl TypeInfo for AnyTypeInfo {
+    fn compatible(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(match &self.0 {
+            AnyTypeKind::Bool => "BOOL",
+            AnyTypeKind::Int => "INT",
+            AnyTypeKind::Float => "FLOAT",
+            AnyTypeKind::Text => "TEXT",
+            AnyTypeKind::Bytes => "BYTES",
+
+            #[cfg(feature = "json")]
+            AnyTypeKind::Json => "JSON",
+
+            #[cfg(feature = "uuid")]
+            AnyTypeKind::Uuid => "UUID",
+
+            AnyTypeKind::Other(name) => name,
+        })
+    }
+}
+
+fn unexpected(expected: &str, value: AnyValue) -> crate::Error<Any> {
+    match value {
+        AnyValue::Null => crate::Error::decode(UnexpectedNullError),
+
+        value => crate::Error::Decode(format!("expected {}, got {:?}", expected, value).into()),
+    }
+}
+
+impl<'de, T> Decode<'de, Any> for Option<T>
+where
+    T: Decode<'de, Any>,
+{
+    fn decode(value: AnyValue) -> crate::Result<Any, Self> {
+        match value {
+            AnyValue::Null => Ok(None),
+            value => T::decode(value).map(Some),
+        }
+    }
+}
+
+impl Type<Any> for bool {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo(AnyTypeKind::Bool)
+    }
+}
+
+impl Encode<Any> for bool {
+    fn encode(&self, buf: &mut Vec<AnyValue>) {
+        buf.push(AnyValue::Bool(*self));
+    }
+}
+
+// SQLite has no boolean values; a boolean is stored as an integer
+impl<'de> Decode<'de, Any> for bool {
+    fn decode(value: AnyValue) -> crate::Result<Any, Self> {
+        match value {
+            AnyValue::Bool(value) => Ok(value),
+            AnyValue::Int(value) => Ok(value != 0),
+            value => Err(unexpected("a boolean", value)),
+        }
+    }
+}
+
+macro_rules! impl_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Type<Any> for $ty {
+                fn type_info() -> AnyTypeInfo {
+                    AnyTypeInfo(AnyTypeKind::Int)
+                }
+            }
+
+            impl Encode<Any> for $ty {
+                fn encode(&self, buf: &mut Vec<AnyValue>) {
+                    buf.push(AnyValue::Int((*self).into()));
+                }
+            }
+
+            impl<'de> Decode<'de, Any> for $ty {
+                fn decode(value: AnyValue) -> crate::Result<Any, Self> {
+                    match value {
+                        AnyValue::Int(int) => <$ty>::try_from(int).map_err(|_| {
+                            crate::Error::Decode(
+                                format!("{} is out of range for {}", int, stringify!($ty)).into(),
+                            )
+                        }),
+
+                        value => Err(unexpected("an integer", value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(i16, i32, i64);
+
+impl Type<Any> for f32 {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo(AnyTypeKind::Float)
+    }
+}
+
+impl Encode<Any> for f32 {
+    fn encode(&self, buf: &mut Vec<AnyValue>) {
+        buf.push(AnyValue::Float((*self).into()));
+    }
+}
+
+impl<'de> Decode<'de, Any> for f32 {
+    fn decode(value: AnyValue) -> crate::Result<Any, Self> {
+        <f64 as Decode<Any>>::decode(value).map(|value| value as f32)
+    }
+}
+
+impl Type<Any> for f64 {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo(AnyTypeKind::Float)
+    }
+}
+
+impl Encode<Any> for f64 {
+    fn encode(&self, buf: &mut Vec<AnyValue>) {
+        buf.push(AnyValue::Float(*self));
+    }
+}
+
+impl<'de> Decode<'de, Any> for f64 {
+    fn decode(value: AnyValue) -> crate::Result<Any, Self> {
+        match value {
+            AnyValue::Float(value) => Ok(value),
+            value => Err(unexpected("a float", value)),
+        }
+    }
+}
+
+impl Type<Any> for str {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo(AnyTypeKind::Text)
+    }
+}
+
+impl Type<Any> for String {
+    fn type_info() -> AnyTypeInfo {
+        <str as Type<Any>>::type_info()
+    }
+}
+
+impl Encode<Any> for str {
+    fn encode(&self, buf: &mut Vec<AnyValue>) {
+        buf.push(AnyValue::Text(self.to_owned()));
+    }
+}
+
+impl Encode<Any> for String {
+    fn encode(&self, buf: &mut Vec<AnyValue>) {
+        <str as Encode<Any>>::encode(self, buf)
+    }
+}
+
+impl<'de> Decode<'de, Any> for String {
+    fn decode(value: AnyValue) -> crate::Result<Any, Self> {
+        match value {
+            AnyValue::Text(value) => Ok(value),
+            value => Err(unexpected("text", value)),
+        }
+    }
+}
+
+impl Type<Any> for [u8] {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo(AnyTypeKind::Bytes)
+    }
+}
+
+impl Type<Any> for Vec<u8> {
+    fn type_info() -> AnyTypeInfo {
+        <[u8] as Type<Any>>::type_info()
+    }
+}
+
+impl Encode<Any> for [u8] {
+    fn encode(&self, buf: &mut Vec<AnyValue>) {
+        buf.push(AnyValue::Bytes(self.to_vec()));
+    }
+}
+
+impl Encode<Any> for Vec<u8> {
+    fn encode(&self, buf: &mut Vec<AnyValue>) {
+        <[u8] as Encode<Any>>::encode(self, buf)
+    }
+}
+
+impl<'de> Decode<'de, Any> for Vec<u8> {
+    fn decode(value: AnyValue) -> crate::Result<Any, Self> {
+        match value {
+            AnyValue::Bytes(value) => Ok(value),
+            value => Err(unexpected("bytes", value)),
+        }
+    }
+}
+
+// SQLite has no JSON values; JSON is stored as text
+#[cfg(feature = "json")]
+mod json {
+    use serde_json::Value as JsonValue;
+
+    use super::*;
+
+    impl Type<Any> for JsonValue {
+        fn type_info() -> AnyTypeInfo {
+            AnyTypeInfo(AnyTypeKind::Json)
+        }
+    }
+
+    impl Encode<Any> for JsonValue {
+        fn encode(&self, buf: &mut Vec<AnyValue>) {
+            buf.push(AnyValue::Json(self.clone()));
+        }
+    }
+
+    impl<'de> Decode<'de, Any> for JsonValue {
+        fn decode(value: AnyValue) -> crate::Result<Any, Self> {
+            match value {
+                AnyValue::Json(value) => Ok(value),
+                AnyValue::Text(text) => serde_json::from_str(&text).map_err(crate::Error::decode),
+                value => Err(unexpected("JSON", value)),
+            }
+        }
+    }
+}
+
+// SQLite has no UUID values; a UUID is stored as its 16 bytes
+#[cfg(feature = "uuid")]
+mod uuid {
+    use crate::types::Uuid;
+
+    use super::*;
+
+    impl Type<Any> for Uuid {
+        fn type_info() -> AnyTypeInfo {
+            AnyTypeInfo(AnyTypeKind::Uuid)
+        }
+    }
+
+    impl Encode<Any> for Uuid {
+        fn encode(&self, buf: &mut Vec<AnyValue>) {
+            buf.push(AnyValue::Uuid(*self));
+        }
+    }
+
+    impl<'de> Decode<'de, Any> for Uuid {
+        fn decode(value: AnyValue) -> crate::Result<Any, Self> {
+            match value {
+                AnyValue::Uuid(value) => Ok(value),
+                AnyValue::Bytes(bytes) => Uuid::from_slice(&bytes).map_err(crate::Error::decode),
+                AnyValue::Text(text) => text.parse().map_err(crate::Error::decode),
+                value => Err(unexpected("a UUID", value)),
+            }
+        }
+    }
+}
+
+impl Type<Any> for AnyValue {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo(AnyTypeKind::Other("ANY".into()))
+    }
+}
+
+impl Encode<Any> for AnyValue {
+    fn encode(&self, buf: &mut Vec<AnyValue>) {
+        buf.push(self.clone());
+    }
+}
+
+impl<'de> Decode<'de, Any> for AnyValue {
+    fn decode(value: AnyValue) -> crate::Result<Any, Self> {
+        Ok(value)
+    }
+}
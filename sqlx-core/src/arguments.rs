@@ -12,6 +12,15 @@ pub trait Arguments: Send + Sized + Default + 'static {
     /// be added to the arguments without a reallocation.  
     fn reserve(&mut self, len: usize, size: usize);
 
+    /// Returns the number of values that have been added.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if no values have been added.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Add the value to the end of the arguments.
     fn add<T>(&mut self, value: T)
     where
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+/// A cache of prepared statements by their query, which evicts the least recently used
+/// statement when it is full.
+///
+/// The cache holds only a handful of statements so finding the one to evict is a linear scan.
+pub(crate) struct StatementCache<T> {
+    capacity: usize,
+    entries: HashMap<Box<str>, Entry<T>>,
+    // Incremented on every use; the entry with the smallest value was used the longest ago
+    clock: u64,
+}
+
+struct Entry<T> {
+    value: T,
+    last_used: u64,
+}
+
+impl<T> StatementCache<T> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::with_capacity(capacity.min(10)),
+            clock: 0,
+        }
+    }
+
+    /// The maximum number of statements; a capacity of 0 disables caching.
+    #[inline]
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the statement for the query and marks it as the most recently used.
+    pub(crate) fn get_mut(&mut self, query: &str) -> Option<&mut T> {
+        self.clock += 1;
+
+        let clock = self.clock;
+
+        self.entries.get_mut(query).map(|entry| {
+            entry.last_used = clock;
+            &mut entry.value
+        })
+    }
+
+    /// Inserts the statement for a query that is not cached yet, evicting and returning
+    /// the least recently used statement if the cache is full.
+    ///
+    /// Nothing is cached with a capacity of 0 and the statement is returned as is.
+    pub(crate) fn insert(&mut self, query: &str, value: T) -> Option<T> {
+        if self.capacity == 0 {
+            return Some(value);
+        }
+
+        let evicted = if self.entries.len() >= self.capacity {
+            self.remove_lru()
+        } else {
+            None
+        };
+
+        self.clock += 1;
+
+        self.entries.insert(
+            query.into(),
+            Entry {
+                value,
+                last_used: self.clock,
+            },
+        );
+
+        evicted
+    }
+
+    /// Removes the statement for a query, e.g., because it failed to prepare.
+    #[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+    pub(crate) fn remove(&mut self, query: &str) -> Option<T> {
+        self.entries.remove(query).map(|entry| entry.value)
+    }
+
+    /// Removes and returns the least recently used statement.
+    pub(crate) fn remove_lru(&mut self) -> Option<T> {
+        let query = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(query, _)| query.clone())?;
+
+        self.entries.remove(&query).map(|entry| entry.value)
+    }
+}
+
+#[test]
+fn test_statement_cache() {
+    let mut cache = StatementCache::new(2);
+
+    assert_eq!(cache.insert("SELECT 1", 1), None);
+    assert_eq!(cache.insert("SELECT 2", 2), None);
+
+    // `SELECT 2` is now the least recently used
+    assert_eq!(cache.get_mut("SELECT 1"), Some(&mut 1));
+    assert_eq!(cache.insert("SELECT 3", 3), Some(2));
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get_mut("SELECT 2"), None);
+    assert_eq!(cache.get_mut("SELECT 1"), Some(&mut 1));
+    assert_eq!(cache.get_mut("SELECT 3"), Some(&mut 3));
+
+    assert_eq!(cache.remove_lru(), Some(1));
+    assert_eq!(cache.remove_lru(), Some(3));
+    assert_eq!(cache.remove_lru(), None);
+}
+
+#[test]
+fn test_statement_cache_disabled() {
+    let mut cache = StatementCache::new(0);
+
+    assert_eq!(cache.insert("SELECT 1", 1), Some(1));
+    assert_eq!(cache.len(), 0);
+    assert_eq!(cache.get_mut("SELECT 1"), None);
+}
@@ -1,6 +1,8 @@
 //! Contains the `Connection` and `Connect` traits.
 
 use std::convert::TryInto;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use futures_core::future::BoxFuture;
 
@@ -52,6 +54,53 @@ where
 
     /// Checks if a connection to the database is still valid.
     fn ping(&mut self) -> BoxFuture<crate::Result<Self::Database, ()>>;
+
+    /// Sets a hook that is called with each statement run by this connection, replacing any
+    /// previous hook.
+    ///
+    /// The hook is called once a statement has finished; when [`execute`] returns, or when the
+    /// last row of a [`Cursor`] has been fetched or the cursor is dropped. Only the number of
+    /// arguments that were bound is passed to the hook, never their values.
+    ///
+    /// ```rust,ignore
+    /// conn.on_query(|query| println!("{} took {:?}", query.sql(), query.elapsed()));
+    /// ```
+    ///
+    /// [`execute`]: crate::executor::Executor::execute
+    /// [`Cursor`]: crate::cursor::Cursor
+    fn on_query<F>(&mut self, hook: F)
+    where
+        F: Fn(&QueryLog<'_>) + Send + Sync + 'static,
+        Self: Sized,
+    {
+        self.query_logger().hook = Some(Arc::new(hook));
+    }
+
+    /// Emits a warning with [`tracing`] for each statement run by this connection that takes
+    /// at least `threshold`, replacing any previous threshold.
+    ///
+    /// The warning has the target `sqlx::query` and the SQL and the elapsed time of the
+    /// statement as its fields. A statement is timed like it is for [`on_query`].
+    ///
+    /// ```rust,ignore
+    /// conn.log_slow_queries(Duration::from_secs(1));
+    /// ```
+    ///
+    /// [`tracing`]: https://docs.rs/tracing
+    /// [`on_query`]: #method.on_query
+    #[cfg(feature = "tracing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+    fn log_slow_queries(&mut self, threshold: Duration) {
+        self.query_logger().slow_threshold = Some(threshold);
+    }
+
+    #[doc(hidden)]
+    fn query_logger(&mut self) -> &mut QueryLogger;
+
+    // Called on a connection that is about to be dropped in an unknown state, e.g., with a
+    // transaction that could not be rolled back, so it is not reused once dropped
+    #[doc(hidden)]
+    fn discard(&mut self) {}
 }
 
 /// Represents a type that can directly establish a new connection.
@@ -63,6 +112,106 @@ pub trait Connect: Connection {
         Self: Sized;
 }
 
+/// A statement that was run by a connection, passed to the hook set with
+/// [`Connection::on_query`].
+#[derive(Debug)]
+pub struct QueryLog<'q> {
+    sql: &'q str,
+    arguments: usize,
+    elapsed: Duration,
+}
+
+impl QueryLog<'_> {
+    /// The SQL of the statement.
+    #[inline]
+    pub fn sql(&self) -> &str {
+        self.sql
+    }
+
+    /// The number of arguments that were bound to the statement.
+    #[inline]
+    pub fn arguments(&self) -> usize {
+        self.arguments
+    }
+
+    /// The time from when the statement was sent until it finished.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+pub(crate) type QueryHook = Arc<dyn Fn(&QueryLog<'_>) + Send + Sync>;
+
+// How the statements run by a connection are logged
+#[doc(hidden)]
+#[derive(Default, Clone)]
+pub struct QueryLogger {
+    pub(crate) hook: Option<QueryHook>,
+
+    #[cfg(feature = "tracing")]
+    pub(crate) slow_threshold: Option<Duration>,
+}
+
+// Times a statement for the logger of its connection, which logs it when this is dropped; that
+// is when the statement has finished or when what was running it was dropped
+pub(crate) struct QueryTimer {
+    logger: QueryLogger,
+    sql: Box<str>,
+    arguments: usize,
+    started: Instant,
+}
+
+impl QueryTimer {
+    // Starts timing a statement if its connection logs statements
+    pub(crate) fn start(logger: &QueryLogger, sql: &str, arguments: usize) -> Option<Self> {
+        #[cfg(feature = "tracing")]
+        let enabled = logger.hook.is_some() || logger.slow_threshold.is_some();
+
+        #[cfg(not(feature = "tracing"))]
+        let enabled = logger.hook.is_some();
+
+        if !enabled {
+            return None;
+        }
+
+        Some(Self {
+            logger: logger.clone(),
+            sql: sql.into(),
+            arguments,
+            started: Instant::now(),
+        })
+    }
+}
+
+impl Drop for QueryTimer {
+    fn drop(&mut self) {
+        let elapsed = self.started.elapsed();
+
+        if let Some(hook) = &self.logger.hook {
+            hook(&QueryLog {
+                sql: &self.sql,
+                arguments: self.arguments,
+                elapsed,
+            });
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            if let Some(threshold) = self.logger.slow_threshold {
+                if elapsed >= threshold {
+                    tracing::warn!(
+                        target: "sqlx::query",
+                        sql = %self.sql,
+                        elapsed = ?elapsed,
+                        "slow statement",
+                    );
+                }
+            }
+        }
+    }
+}
+
 #[allow(dead_code)]
 pub(crate) enum ConnectionSource<'c, C>
 where
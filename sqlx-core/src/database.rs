@@ -5,6 +5,7 @@ use crate::connection::Connect;
 use crate::cursor::Cursor;
 use crate::error::DatabaseError;
 use crate::row::Row;
+use crate::transaction::Options as TransactionOptions;
 use crate::types::TypeInfo;
 
 /// A database driver.
@@ -38,6 +39,14 @@ where
 
     /// The concrete `DatabaseError` type used to report errors from the database.
     type Error: DatabaseError + Send + Sync;
+
+    // Returns the statement that begins a transaction with the given options and, if the
+    // options outlive the transaction on the connection, the statement that resets them after
+    // the transaction ends
+    #[doc(hidden)]
+    fn begin_transaction(
+        options: &TransactionOptions,
+    ) -> crate::Result<Self, (String, Option<&'static str>)>;
 }
 
 /// Associate [`Database`] with a `RawValue` of a generic lifetime.
@@ -9,6 +9,19 @@ where
     DB: Database,
 {
     fn decode(value: <DB as HasRawValue<'de>>::RawValue) -> crate::Result<DB, Self>;
+
+    // Decodes a value of a column of the given type, e.g., from a row; a type that can be
+    // decoded from more than one column type overrides this to tell them apart by their type
+    // instead of by the value
+    #[doc(hidden)]
+    fn decode_with_type(
+        type_info: &DB::TypeInfo,
+        value: <DB as HasRawValue<'de>>::RawValue,
+    ) -> crate::Result<DB, Self> {
+        let _ = type_info;
+
+        Self::decode(value)
+    }
 }
 
 /// A type that can be decoded without borrowing from the connection.
@@ -39,10 +39,20 @@ pub struct Column<DB>
 where
     DB: Database + ?Sized,
 {
+    /// The name of the column, if it has one.
     pub name: Option<Box<str>>,
+
+    /// The table the column was selected from, if it is a reference to a column of a table.
     pub table_id: Option<DB::TableId>,
+
+    /// The type of the column, if it is known.
     pub type_info: Option<DB::TypeInfo>,
+
     /// Whether or not the column cannot be `NULL` (or if that is even knowable).
+    ///
+    /// Postgres only knows this for a column that refers directly to a column of a table,
+    /// from whether the table column is `NOT NULL`; it is `None` for an expression. A `NOT NULL`
+    /// column on the nullable side of an outer join is still reported as `Some(true)`.
     pub non_null: Option<bool>,
 }
 
@@ -26,6 +26,12 @@ pub enum Error<DB: Database> {
     /// [`query::Map::fetch_one`]: crate::query::Map::fetch_one
     RowNotFound,
 
+    /// More than one row was returned during [`query::Map::fetch_at_most_one`] or
+    /// `QueryAs::fetch_at_most_one`.
+    ///
+    /// [`query::Map::fetch_at_most_one`]: crate::query::Map::fetch_at_most_one
+    TooManyRows,
+
     /// Column was not found by name in a Row (during [`Row::get`]).
     ///
     /// [`Row::get`]: crate::row::Row::get
@@ -60,6 +66,9 @@ pub enum Error<DB: Database> {
 
     /// An error occurred decoding data received from the database.
     Decode(Box<dyn StdError + Send + Sync>),
+
+    /// A parameter of the connection URL had an invalid value.
+    Configuration(Box<dyn StdError + Send + Sync>),
 }
 
 impl<DB: Database> Error<DB> {
@@ -80,6 +89,7 @@ impl<DB: Database + Debug> StdError for Error<DB> {
             Error::PoolTimedOut(Some(error)) => Some(&**error),
             Error::Decode(error) => Some(&**error),
             Error::Tls(error) => Some(&**error),
+            Error::Configuration(error) => Some(&**error),
 
             _ => None,
         }
@@ -101,6 +111,10 @@ impl<DB: Database> Display for Error<DB> {
 
             Error::RowNotFound => f.write_str("found no row when we expected at least one"),
 
+            Error::TooManyRows => {
+                f.write_str("found more than one row when we expected at most one")
+            }
+
             Error::ColumnNotFound(ref name) => {
                 write!(f, "no column found with the name {:?}", name)
             }
@@ -124,6 +138,8 @@ impl<DB: Database> Display for Error<DB> {
             Error::PoolClosed => f.write_str("attempted to acquire a connection on a closed pool"),
 
             Error::Tls(ref err) => write!(f, "error during TLS upgrade: {}", err),
+
+            Error::Configuration(ref err) => write!(f, "invalid connection URL: {}", err),
         }
     }
 }
@@ -201,6 +217,12 @@ pub trait DatabaseError: StdError + Send + Sync {
     fn constraint_name(&self) -> Option<&str> {
         None
     }
+
+    /// Returns `true` if the error is transient and the transaction or query may succeed
+    /// if it is retried, e.g., after a deadlock.
+    fn is_retryable(&self) -> bool {
+        false
+    }
 }
 
 /// Used by the `protocol_error!()` macro for a lazily evaluated conversion to
@@ -45,9 +45,18 @@ where
     /// Prepare the SQL query and return type information about its parameters
     /// and results.
     ///
+    /// The query is not executed and no values need to be bound for its parameters.
     /// This is used by the query macros during compilation to
     /// power their type inference.
-    #[doc(hidden)]
+    ///
+    /// ```rust,ignore
+    /// let describe = conn
+    ///     .describe("SELECT id, name FROM users WHERE id = $1")
+    ///     .await?;
+    ///
+    /// assert_eq!(describe.param_types.len(), 1);
+    /// assert_eq!(describe.result_columns.len(), 2);
+    /// ```
     fn describe<'e, 'q, E: 'e>(
         &'e mut self,
         query: E,
@@ -9,6 +9,10 @@ use futures_util::ready;
 use crate::runtime::{AsyncRead, AsyncReadExt, AsyncWrite};
 
 const RBUF_SIZE: usize = 8 * 1024;
+const WBUF_SIZE: usize = 1024;
+
+// A buffer that grew past this for a large message is shrunk back once the message is done
+const MAX_RETAINED_CAPACITY: usize = 64 * 1024;
 
 pub struct BufStream<S> {
     pub(crate) stream: S,
@@ -38,7 +42,7 @@ where
         Self {
             stream,
             stream_eof: false,
-            wbuf: Vec::with_capacity(1024),
+            wbuf: Vec::with_capacity(WBUF_SIZE),
             rbuf: vec![0; RBUF_SIZE],
             rbuf_rindex: 0,
             rbuf_windex: 0,
@@ -93,38 +97,30 @@ where
                 return Ok(Some(buf));
             }
 
-            // If we are out of space to write to in the read buffer ..
-            if self.rbuf.len() < (self.rbuf_windex + cnt) {
-                if self.rbuf_rindex == self.rbuf_windex {
-                    // We have consumed all data; simply reset the indexes
-                    self.rbuf_rindex = 0;
-                    self.rbuf_windex = 0;
-                } else {
-                    // Allocate a new buffer
-                    let mut new_rbuf = Vec::with_capacity(RBUF_SIZE);
-
-                    // Take the minimum of the read and write indexes
-                    let min_index = self.rbuf_rindex.min(self.rbuf_windex);
-
-                    // Copy the old buffer to our new buffer
-                    new_rbuf.extend_from_slice(&self.rbuf[min_index..]);
+            // If everything that was read has been consumed, start over at the front
+            if self.rbuf_rindex == self.rbuf_windex {
+                self.rbuf_rindex = 0;
+                self.rbuf_windex = 0;
 
-                    // Zero-extend the new buffer
-                    new_rbuf.resize(new_rbuf.capacity(), 0);
+                if self.rbuf.len() > MAX_RETAINED_CAPACITY {
+                    self.rbuf.truncate(RBUF_SIZE);
+                    self.rbuf.shrink_to_fit();
+                }
+            }
 
-                    // Replace the old buffer with our new buffer
-                    self.rbuf = new_rbuf;
+            // If we are out of space to write to in the read buffer ..
+            if self.rbuf.len() < (self.rbuf_windex + cnt) {
+                // Move the unread data to the front of the buffer
+                if self.rbuf_rindex > 0 {
+                    self.rbuf.copy_within(self.rbuf_rindex..self.rbuf_windex, 0);
 
-                    // And reduce the indexes
-                    self.rbuf_rindex -= min_index;
-                    self.rbuf_windex -= min_index;
+                    self.rbuf_windex -= self.rbuf_rindex;
+                    self.rbuf_rindex = 0;
                 }
 
                 // Do we need more space still
                 if self.rbuf.len() < (self.rbuf_windex + cnt) {
-                    let needed = (self.rbuf_windex + cnt) - self.rbuf.len();
-
-                    self.rbuf.resize(self.rbuf.len() + needed, 0);
+                    self.rbuf.resize(self.rbuf_windex + cnt, 0);
                 }
             }
 
@@ -193,7 +189,59 @@ impl<'a, S: AsyncWrite + Unpin> Future for GuardedFlush<'a, S> {
 
 impl<'a, S> Drop for GuardedFlush<'a, S> {
     fn drop(&mut self) {
+        let buf = self.buf.get_mut();
+
         // clear the buffer regardless of whether the flush succeeded or not
-        self.buf.get_mut().clear();
+        if buf.capacity() > MAX_RETAINED_CAPACITY {
+            **buf = Vec::with_capacity(WBUF_SIZE);
+        } else {
+            buf.clear();
+        }
     }
 }
+
+#[test]
+#[cfg(feature = "runtime-async-std")]
+fn test_read_buffer_reuse() {
+    use async_std::io::Cursor;
+
+    async_std::task::block_on(async {
+        let data: Vec<u8> = (0..RBUF_SIZE + 100).map(|i| i as u8).collect();
+        let mut stream = BufStream::new(Cursor::new(data.clone()));
+
+        // the unread end of the buffer is moved to the front to make room
+        assert_eq!(
+            stream.peek(RBUF_SIZE - 5).await.unwrap(),
+            &data[..RBUF_SIZE - 5]
+        );
+        stream.consume(RBUF_SIZE - 5);
+
+        assert_eq!(
+            stream.peek(20).await.unwrap(),
+            &data[RBUF_SIZE - 5..RBUF_SIZE + 15]
+        );
+        assert_eq!(stream.rbuf.len(), RBUF_SIZE);
+    });
+}
+
+#[test]
+#[cfg(feature = "runtime-async-std")]
+fn test_read_buffer_shrinks_after_a_large_message() {
+    use async_std::io::Cursor;
+
+    async_std::task::block_on(async {
+        let large = vec![1_u8; MAX_RETAINED_CAPACITY * 2];
+
+        let mut data = large.clone();
+        data.extend_from_slice(b"small");
+
+        let mut stream = BufStream::new(Cursor::new(data));
+
+        assert_eq!(stream.peek(large.len()).await.unwrap(), &*large);
+        assert!(stream.rbuf.len() >= large.len());
+        stream.consume(large.len());
+
+        assert_eq!(stream.peek(5).await.unwrap(), b"small");
+        assert_eq!(stream.rbuf.len(), RBUF_SIZE);
+    });
+}
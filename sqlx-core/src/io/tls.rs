@@ -2,8 +2,11 @@ use std::io;
 use std::net::Shutdown;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use crate::database::Database;
+#[cfg(unix)]
+use crate::runtime::UnixStream;
 use crate::runtime::{AsyncRead, AsyncWrite, TcpStream};
 use crate::url::Url;
 
@@ -15,6 +18,8 @@ pub struct MaybeTlsStream {
 
 enum Inner {
     NotTls(TcpStream),
+    #[cfg(unix)]
+    Unix(UnixStream),
     #[cfg(feature = "tls")]
     Tls(async_native_tls::TlsStream<TcpStream>),
     #[cfg(feature = "tls")]
@@ -22,17 +27,61 @@ enum Inner {
 }
 
 impl MaybeTlsStream {
-    pub async fn connect<DB: Database>(url: &Url, default_port: u16) -> crate::Result<DB, Self> {
-        let conn = TcpStream::connect((url.host(), url.port(default_port))).await?;
+    /// Connects over TCP, with TCP keepalive enabled on the socket if `keepalive` is the idle time
+    /// before the first probe.
+    pub async fn connect<DB: Database>(
+        url: &Url,
+        default_port: u16,
+        keepalive: Option<Duration>,
+    ) -> crate::Result<DB, Self> {
+        let conn = connect_tcp((url.host(), url.port(default_port)), keepalive).await?;
         Ok(Self {
             inner: Inner::NotTls(conn),
         })
     }
 
+    /// Sets `TCP_NODELAY` on the socket; a Unix domain socket is left as is.
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        match self.tcp_stream() {
+            Some(conn) => conn.set_nodelay(nodelay),
+            None => Ok(()),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn nodelay(&self) -> io::Result<bool> {
+        match self.tcp_stream() {
+            Some(conn) => conn.nodelay(),
+            None => Ok(false),
+        }
+    }
+
+    fn tcp_stream(&self) -> Option<&TcpStream> {
+        match self.inner {
+            NotTls(ref conn) => Some(conn),
+            #[cfg(unix)]
+            Unix(_) => None,
+            #[cfg(feature = "tls")]
+            Tls(ref conn) => Some(conn.get_ref()),
+            #[cfg(feature = "tls")]
+            Upgrading => None,
+        }
+    }
+
+    #[cfg(unix)]
+    pub async fn connect_uds<DB: Database>(path: &std::path::Path) -> crate::Result<DB, Self> {
+        let conn = UnixStream::connect(path).await?;
+        Ok(Self {
+            inner: Inner::Unix(conn),
+        })
+    }
+
     #[allow(dead_code)]
     pub fn is_tls(&self) -> bool {
         match self.inner {
             Inner::NotTls(_) => false,
+            #[cfg(unix)]
+            Inner::Unix(_) => false,
             #[cfg(feature = "tls")]
             Inner::Tls(_) => true,
             #[cfg(feature = "tls")]
@@ -49,6 +98,11 @@ impl MaybeTlsStream {
     ) -> crate::Result<DB, ()> {
         let conn = match std::mem::replace(&mut self.inner, Upgrading) {
             NotTls(conn) => conn,
+            #[cfg(unix)]
+            Unix(conn) => {
+                self.inner = Unix(conn);
+                return Err(tls_err!("TLS is not supported over a Unix domain socket").into());
+            }
             Tls(_) => return Err(tls_err!("connection already upgraded").into()),
             Upgrading => return Err(tls_err!("connection already failed to upgrade").into()),
         };
@@ -61,6 +115,8 @@ impl MaybeTlsStream {
     pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
         match self.inner {
             NotTls(ref conn) => conn.shutdown(how),
+            #[cfg(unix)]
+            Unix(ref conn) => conn.shutdown(how),
             #[cfg(feature = "tls")]
             Tls(ref conn) => conn.get_ref().shutdown(how),
             #[cfg(feature = "tls")]
@@ -70,10 +126,57 @@ impl MaybeTlsStream {
     }
 }
 
+// TCP keepalive is set once the socket is connected, before it is used
+
+#[cfg(feature = "runtime-tokio")]
+async fn connect_tcp(addr: (&str, u16), keepalive: Option<Duration>) -> io::Result<TcpStream> {
+    let conn = TcpStream::connect(addr).await?;
+    conn.set_keepalive(keepalive)?;
+
+    Ok(conn)
+}
+
+#[cfg(feature = "runtime-smol")]
+async fn connect_tcp(addr: (&str, u16), keepalive: Option<Duration>) -> io::Result<TcpStream> {
+    let conn = TcpStream::connect(addr).await?;
+    conn.set_keepalive(keepalive)?;
+
+    Ok(conn)
+}
+
+// async-std has no option for TCP keepalive and does not give out its `std` socket, so a socket
+// that needs keepalive is connected in a blocking task and converted afterwards
+#[cfg(feature = "runtime-async-std")]
+async fn connect_tcp(
+    (host, port): (&str, u16),
+    keepalive: Option<Duration>,
+) -> io::Result<TcpStream> {
+    use net2::TcpStreamExt;
+
+    let keepalive = match keepalive {
+        Some(keepalive) => keepalive,
+        None => return TcpStream::connect((host, port)).await,
+    };
+
+    let host = host.to_owned();
+
+    let conn = async_std::task::spawn_blocking(move || {
+        let conn = std::net::TcpStream::connect((&*host, port))?;
+        conn.set_keepalive(Some(keepalive))?;
+
+        Ok::<_, io::Error>(conn)
+    })
+    .await?;
+
+    Ok(TcpStream::from(conn))
+}
+
 macro_rules! forward_pin (
     ($self:ident.$method:ident($($arg:ident),*)) => (
         match &mut $self.inner {
             NotTls(ref mut conn) => Pin::new(conn).$method($($arg),*),
+            #[cfg(unix)]
+            Unix(ref mut conn) => Pin::new(conn).$method($($arg),*),
             #[cfg(feature = "tls")]
             Tls(ref mut conn) => Pin::new(conn).$method($($arg),*),
             #[cfg(feature = "tls")]
@@ -91,7 +194,7 @@ impl AsyncRead for MaybeTlsStream {
         forward_pin!(self.poll_read(cx, buf))
     }
 
-    #[cfg(feature = "runtime-async-std")]
+    #[cfg(any(feature = "runtime-async-std", feature = "runtime-smol"))]
     fn poll_read_vectored(
         mut self: Pin<&mut Self>,
         cx: &mut Context,
@@ -114,7 +217,7 @@ impl AsyncWrite for MaybeTlsStream {
         forward_pin!(self.poll_flush(cx))
     }
 
-    #[cfg(feature = "runtime-async-std")]
+    #[cfg(any(feature = "runtime-async-std", feature = "runtime-smol"))]
     fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
         forward_pin!(self.poll_close(cx))
     }
@@ -124,7 +227,7 @@ impl AsyncWrite for MaybeTlsStream {
         forward_pin!(self.poll_shutdown(cx))
     }
 
-    #[cfg(feature = "runtime-async-std")]
+    #[cfg(any(feature = "runtime-async-std", feature = "runtime-smol"))]
     fn poll_write_vectored(
         mut self: Pin<&mut Self>,
         cx: &mut Context,
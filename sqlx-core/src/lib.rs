@@ -11,6 +11,9 @@
 
 mod runtime;
 
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
+mod cache;
+
 #[macro_use]
 pub mod error;
 
@@ -59,6 +62,10 @@ pub mod postgres;
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 pub mod sqlite;
 
+#[cfg(all(feature = "any", any(feature = "postgres", feature = "sqlite")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "any")))]
+pub mod any;
+
 pub use error::{Error, Result};
 
 // Named Lifetimes:
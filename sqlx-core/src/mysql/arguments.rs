@@ -25,6 +25,10 @@ impl Arguments for MySqlArguments {
         self.null_bitmap.reserve((len + 7 - spare_bits) / 8);
     }
 
+    fn len(&self) -> usize {
+        self.param_types.len()
+    }
+
     fn add<T>(&mut self, value: T)
     where
         T: Type<Self::Database>,
@@ -5,7 +5,7 @@ use std::ops::Range;
 use futures_core::future::BoxFuture;
 use sha1::Sha1;
 
-use crate::connection::{Connect, Connection};
+use crate::connection::{Connect, Connection, QueryLogger};
 use crate::executor::Executor;
 use crate::mysql::protocol::{
     AuthPlugin, AuthSwitch, Capabilities, ComPing, Handshake, HandshakeResponse,
@@ -90,6 +90,9 @@ pub struct MySqlConnection {
     // Work buffer for the value ranges of the current row
     // This is used as the backing memory for each Row's value indexes
     pub(super) current_row_values: Vec<Option<Range<usize>>>,
+
+    // How the statements that are run are logged, e.g., by [Connection::on_query]
+    pub(super) query_logger: QueryLogger,
 }
 
 fn to_asciz(s: &str) -> Vec<u8> {
@@ -281,6 +284,7 @@ impl MySqlConnection {
             current_row_values: Vec::with_capacity(10),
             is_ready: true,
             cache_statement: HashMap::new(),
+            query_logger: QueryLogger::default(),
         };
 
         // After the connection is established, we initialize by configuring a few
@@ -338,4 +342,9 @@ impl Connection for MySqlConnection {
     fn ping(&mut self) -> BoxFuture<crate::Result<MySql, ()>> {
         Box::pin(ping(&mut self.stream))
     }
+
+    #[doc(hidden)]
+    fn query_logger(&mut self) -> &mut QueryLogger {
+        &mut self.query_logger
+    }
 }
@@ -3,7 +3,8 @@ use std::sync::Arc;
 
 use futures_core::future::BoxFuture;
 
-use crate::connection::ConnectionSource;
+use crate::arguments::Arguments;
+use crate::connection::{ConnectionSource, QueryTimer};
 use crate::cursor::Cursor;
 use crate::executor::Execute;
 use crate::mysql::protocol::{ColumnCount, ColumnDefinition, Row, Status, TypeId};
@@ -16,6 +17,9 @@ pub struct MySqlCursor<'c, 'q> {
     column_names: Arc<HashMap<Box<str>, u16>>,
     column_types: Vec<TypeId>,
     binary: bool,
+
+    // Times the query for the hook of the connection until the last row has been fetched
+    timer: Option<QueryTimer>,
 }
 
 impl crate::cursor::private::Sealed for MySqlCursor<'_, '_> {}
@@ -35,6 +39,7 @@ impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
             column_types: Vec::new(),
             binary: true,
             query: Some(query.into_parts()),
+            timer: None,
         }
     }
 
@@ -50,6 +55,7 @@ impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
             column_types: Vec::new(),
             binary: true,
             query: Some(query.into_parts()),
+            timer: None,
         }
     }
 
@@ -67,6 +73,9 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
     // contained query. We guard against this happening on _all_ next calls
     // by using [Option::take] which replaces the potential value in the Option with `None
     let mut initial = if let Some((query, arguments)) = cursor.query.take() {
+        let num_arguments = arguments.as_ref().map_or(0, Arguments::len);
+        cursor.timer = QueryTimer::start(&conn.query_logger, query, num_arguments);
+
         let statement = conn.run(query, arguments).await?;
 
         // No statement ID = TEXT mode
@@ -96,6 +105,8 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                     initial = true;
                 } else {
                     conn.is_ready = true;
+                    cursor.timer = None;
+
                     return Ok(None);
                 }
             }
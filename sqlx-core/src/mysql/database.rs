@@ -1,5 +1,6 @@
 use crate::database::{Database, HasCursor, HasRawValue, HasRow};
 use crate::mysql::error::MySqlError;
+use crate::transaction::Options as TransactionOptions;
 
 /// **MySQL** database driver.
 #[derive(Debug)]
@@ -17,6 +18,27 @@ impl Database for MySql {
     type RawBuffer = Vec<u8>;
 
     type Error = MySqlError;
+
+    fn begin_transaction(
+        options: &TransactionOptions,
+    ) -> crate::Result<Self, (String, Option<&'static str>)> {
+        let mut begin = String::new();
+
+        // applies to the next transaction only
+        if let Some(level) = options.isolation_level {
+            begin.push_str("SET TRANSACTION ISOLATION LEVEL ");
+            begin.push_str(level.as_sql());
+            begin.push_str("; ");
+        }
+
+        begin.push_str("START TRANSACTION");
+
+        if options.read_only {
+            begin.push_str(" READ ONLY");
+        }
+
+        Ok((begin, None))
+    }
 }
 
 impl<'c> HasRow<'c> for MySql {
@@ -1,5 +1,7 @@
 use futures_core::future::BoxFuture;
 
+use crate::arguments::Arguments;
+use crate::connection::QueryTimer;
 use crate::cursor::Cursor;
 use crate::describe::{Column, Describe};
 use crate::executor::{Execute, Executor, RefExecutor};
@@ -184,6 +186,8 @@ impl Executor for super::MySqlConnection {
     {
         Box::pin(async move {
             let (query, arguments) = query.into_parts();
+            let num_arguments = arguments.as_ref().map_or(0, Arguments::len);
+            let _timer = QueryTimer::start(&self.query_logger, query, num_arguments);
 
             self.run(query, arguments).await?;
             self.affected_rows().await
@@ -34,7 +34,7 @@ pub(crate) struct MySqlStream {
 
 impl MySqlStream {
     pub(super) async fn new(url: &Url) -> crate::Result<MySql, Self> {
-        let stream = MaybeTlsStream::connect(&url, 3306).await?;
+        let stream = MaybeTlsStream::connect(&url, 3306, None).await?;
 
         let mut capabilities = Capabilities::PROTOCOL_41
             | Capabilities::IGNORE_SPACE
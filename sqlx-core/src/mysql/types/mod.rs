@@ -11,6 +11,7 @@ mod chrono;
 #[cfg(feature = "time")]
 mod time;
 
+use std::borrow::Cow;
 use std::fmt::{self, Debug, Display};
 
 use crate::decode::Decode;
@@ -84,6 +85,13 @@ impl Display for MySqlTypeInfo {
 }
 
 impl TypeInfo for MySqlTypeInfo {
+    fn name(&self) -> Cow<'_, str> {
+        match self.id.type_name() {
+            "<unknown>" => Cow::Owned(self.to_string()),
+            name => Cow::Borrowed(name),
+        }
+    }
+
     fn compatible(&self, other: &Self) -> bool {
         match self.id {
             TypeId::VAR_CHAR
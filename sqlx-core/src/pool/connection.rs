@@ -5,7 +5,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use super::inner::{DecrementSizeGuard, SharedPool};
-use crate::connection::{Connect, Connection};
+use crate::connection::{Connect, Connection, QueryLogger};
 
 /// A connection checked out from [`Pool`][crate::pool::Pool].
 ///
@@ -89,6 +89,20 @@ where
     fn ping(&mut self) -> BoxFuture<crate::Result<C::Database, ()>> {
         Box::pin(self.deref_mut().ping())
     }
+
+    #[doc(hidden)]
+    #[inline]
+    fn query_logger(&mut self) -> &mut QueryLogger {
+        self.deref_mut().query_logger()
+    }
+
+    // The connection is closed and the pool opens a new one in its place when needed
+    #[doc(hidden)]
+    fn discard(&mut self) {
+        if let Some(live) = self.live.take() {
+            drop(live.float(&self.pool));
+        }
+    }
 }
 
 /// Returns the connection to the [`Pool`][crate::pool::Pool] it was checked-out from.
@@ -12,11 +12,12 @@ use futures_util::future;
 use crate::pool::deadline_as_timeout;
 use crate::runtime::{sleep, spawn, timeout};
 use crate::{
-    connection::{Connect, Connection},
+    connection::{Connect, Connection, QueryLogger},
     error::{DatabaseError, Error},
 };
 
 use super::connection::{Floating, Idle, Live};
+use super::options::AfterConnect;
 use super::Options;
 
 pub(crate) struct SharedPool<C> {
@@ -26,6 +27,8 @@ pub(crate) struct SharedPool<C> {
     pub(super) size: AtomicU32,
     is_closed: AtomicBool,
     options: Options,
+    after_connect: Option<Box<dyn AfterConnect<C>>>,
+    query_logger: QueryLogger,
 }
 
 impl<C> SharedPool<C>
@@ -135,6 +138,8 @@ where
     pub(super) async fn new_arc(
         url: &str,
         options: Options,
+        after_connect: Option<Box<dyn AfterConnect<C>>>,
+        query_logger: QueryLogger,
     ) -> crate::Result<C::Database, Arc<Self>> {
         let mut pool = Self {
             url: url.to_owned(),
@@ -143,6 +148,8 @@ where
             size: AtomicU32::new(0),
             is_closed: AtomicBool::new(false),
             options,
+            after_connect,
+            query_logger,
         };
 
         pool.init_min_connections().await?;
@@ -221,7 +228,28 @@ where
         // result here is `Result<Result<C, Error>, TimeoutError>`
         match crate::runtime::timeout(timeout, C::connect(&self.url)).await {
             // successfully established connection
-            Ok(Ok(raw)) => Ok(Some(Floating::new_live(raw, guard))),
+            Ok(Ok(mut raw)) => {
+                *raw.query_logger() = self.query_logger.clone();
+
+                if let Some(after_connect) = &self.after_connect {
+                    let timeout = super::deadline_as_timeout::<C::Database>(deadline)?;
+
+                    // an error from the callback fails this connection; it is not retried
+                    let res = match crate::runtime::timeout(timeout, after_connect.call(&mut raw))
+                        .await
+                    {
+                        Ok(res) => res,
+                        Err(e) => Err(crate::Error::<C::Database>::PoolTimedOut(Some(Box::new(e)))),
+                    };
+
+                    if let Err(e) = res {
+                        let _ = raw.close().await;
+                        return Err(e);
+                    }
+                }
+
+                Ok(Some(Floating::new_live(raw, guard)))
+            }
 
             // an IO error while connecting is assumed to be the system starting up
             Ok(Err(crate::Error::<C::Database>::Io(_))) => Ok(None),
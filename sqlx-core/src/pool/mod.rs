@@ -6,12 +6,12 @@ use std::{
     time::{Duration, Instant},
 };
 
-use crate::connection::Connect;
+use crate::connection::{Connect, QueryLogger};
 use crate::database::Database;
 use crate::transaction::Transaction;
 
 use self::inner::SharedPool;
-use self::options::Options;
+use self::options::{AfterConnect, Options};
 
 mod connection;
 mod executor;
@@ -39,8 +39,13 @@ where
         Self::builder().build(url).await
     }
 
-    async fn with_options(url: &str, options: Options) -> crate::Result<C::Database, Self> {
-        let inner = SharedPool::<C>::new_arc(url, options).await?;
+    async fn with_options(
+        url: &str,
+        options: Options,
+        after_connect: Option<Box<dyn AfterConnect<C>>>,
+        query_logger: QueryLogger,
+    ) -> crate::Result<C::Database, Self> {
+        let inner = SharedPool::<C>::new_arc(url, options, after_connect, query_logger).await?;
 
         Ok(Pool(inner))
     }
@@ -1,13 +1,36 @@
+use std::sync::Arc;
 use std::{marker::PhantomData, time::Duration};
 
+use futures_core::future::BoxFuture;
+
 use super::Pool;
-use crate::connection::Connect;
+use crate::connection::{Connect, QueryLog, QueryLogger};
 use crate::database::Database;
 
+// Called with each new connection before it is used by the pool; a trait instead of a `dyn Fn`
+// so that only the call needs `C: Connect` to name the error type and not `Pool` or `Builder`
+pub(crate) trait AfterConnect<C>: Send + Sync + 'static {
+    fn call<'c>(&self, conn: &'c mut C) -> BoxFuture<'c, crate::Result<C::Database, ()>>
+    where
+        C: Connect;
+}
+
+impl<C, F> AfterConnect<C> for F
+where
+    C: Connect,
+    F: Fn(&mut C) -> BoxFuture<'_, crate::Result<C::Database, ()>> + Send + Sync + 'static,
+{
+    fn call<'c>(&self, conn: &'c mut C) -> BoxFuture<'c, crate::Result<C::Database, ()>> {
+        self(conn)
+    }
+}
+
 /// Builder for [Pool].
 pub struct Builder<C> {
     phantom: PhantomData<C>,
     options: Options,
+    after_connect: Option<Box<dyn AfterConnect<C>>>,
+    query_logger: QueryLogger,
 }
 
 impl<C> Builder<C>
@@ -36,6 +59,8 @@ where
                 // If true, test the health of a connection on acquire
                 test_on_acquire: true,
             },
+            after_connect: None,
+            query_logger: QueryLogger::default(),
         }
     }
 
@@ -107,6 +132,67 @@ where
         self
     }
 
+    /// Set a callback that is run on every new connection before it is used by the pool,
+    /// e.g., to set session parameters.
+    ///
+    /// The callback is run once for each connection that is opened and not when a connection
+    /// is reused. If it returns an error, the connection is closed and the error is returned
+    /// to the caller that is acquiring a connection.
+    ///
+    /// ```rust,ignore
+    /// let pool = PgPool::builder()
+    ///     .after_connect(|conn| {
+    ///         Box::pin(async move {
+    ///             conn.execute("SET search_path = app, public").await?;
+    ///
+    ///             Ok(())
+    ///         })
+    ///     })
+    ///     .build(&url)
+    ///     .await?;
+    /// ```
+    pub fn after_connect<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut C) -> BoxFuture<'_, crate::Result<C::Database, ()>> + Send + Sync + 'static,
+    {
+        self.after_connect = Some(Box::new(callback));
+        self
+    }
+
+    /// Set a hook that is called with each statement run by a connection of the pool.
+    ///
+    /// The hook is set on every new connection before it is used by the pool; see
+    /// [`Connection::on_query`] for when it is called.
+    ///
+    /// ```rust,ignore
+    /// let pool = PgPool::builder()
+    ///     .on_query(|query| println!("{} took {:?}", query.sql(), query.elapsed()))
+    ///     .build(&url)
+    ///     .await?;
+    /// ```
+    ///
+    /// [`Connection::on_query`]: crate::connection::Connection::on_query
+    pub fn on_query<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&QueryLog<'_>) + Send + Sync + 'static,
+    {
+        self.query_logger.hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Emit a warning for each statement run by a connection of the pool that takes at least
+    /// `threshold`.
+    ///
+    /// See [`Connection::log_slow_queries`] for how the statements are logged.
+    ///
+    /// [`Connection::log_slow_queries`]: crate::connection::Connection::log_slow_queries
+    #[cfg(feature = "tracing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+    pub fn log_slow_queries(mut self, threshold: Duration) -> Self {
+        self.query_logger.slow_threshold = Some(threshold);
+        self
+    }
+
     /// Spin up the connection pool.
     ///
     /// If [`min_size`] was set to a non-zero value, that many connections will be immediately
@@ -117,7 +203,7 @@ where
     where
         C: Connect,
     {
-        Pool::<C>::with_options(url, self.options).await
+        Pool::<C>::with_options(url, self.options, self.after_connect, self.query_logger).await
     }
 }
 
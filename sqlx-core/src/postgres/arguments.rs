@@ -1,3 +1,6 @@
+use std::cell::RefCell;
+use std::mem;
+
 use byteorder::{ByteOrder, NetworkEndian};
 
 use crate::arguments::Arguments;
@@ -6,7 +9,18 @@ use crate::io::BufMut;
 use crate::postgres::Postgres;
 use crate::types::Type;
 
-#[derive(Default)]
+#[cfg(feature = "any")]
+use crate::types::AnyValue;
+
+// The buffers of arguments that have been sent are kept to encode the arguments of the
+// next queries on the same thread, as long as they did not grow past this many bytes
+const MAX_RETAINED_CAPACITY: usize = 64 * 1024;
+const MAX_RETAINED_BUFFERS: usize = 4;
+
+thread_local! {
+    static BUFFERS: RefCell<Vec<(Vec<u32>, Vec<u8>)>> = RefCell::new(Vec::new());
+}
+
 pub struct PgArguments {
     // OIDs of the bind parameters
     pub(super) types: Vec<u32>,
@@ -15,6 +29,43 @@ pub struct PgArguments {
     pub(super) values: Vec<u8>,
 }
 
+impl Default for PgArguments {
+    fn default() -> Self {
+        let (types, values) = BUFFERS
+            .try_with(|buffers| buffers.borrow_mut().pop())
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+
+        Self { types, values }
+    }
+}
+
+impl Drop for PgArguments {
+    fn drop(&mut self) {
+        let capacity = self.types.capacity() * mem::size_of::<u32>() + self.values.capacity();
+
+        if capacity == 0 || capacity > MAX_RETAINED_CAPACITY {
+            return;
+        }
+
+        let mut types = mem::take(&mut self.types);
+        let mut values = mem::take(&mut self.values);
+
+        types.clear();
+        values.clear();
+
+        // the thread-local is gone if this is dropped while the thread exits
+        let _ = BUFFERS.try_with(|buffers| {
+            let mut buffers = buffers.borrow_mut();
+
+            if buffers.len() < MAX_RETAINED_BUFFERS {
+                buffers.push((types, values));
+            }
+        });
+    }
+}
+
 impl Arguments for PgArguments {
     type Database = super::Postgres;
 
@@ -23,6 +74,10 @@ impl Arguments for PgArguments {
         self.values.reserve(size);
     }
 
+    fn len(&self) -> usize {
+        self.types.len()
+    }
+
     fn add<T>(&mut self, value: T)
     where
         T: Type<Self::Database>,
@@ -50,3 +105,72 @@ impl Arguments for PgArguments {
         NetworkEndian::write_i32(&mut self.values[pos..], len as i32);
     }
 }
+
+#[cfg(feature = "any")]
+impl PgArguments {
+    // A value of the `Any` database is sent as the type it is decoded from
+    pub(crate) fn add_any(&mut self, value: AnyValue) {
+        match value {
+            // The type of a NULL is left for Postgres to infer
+            AnyValue::Null => {
+                self.types.push(0);
+                self.values.put_i32::<NetworkEndian>(-1);
+            }
+
+            AnyValue::Bool(value) => self.add(value),
+            AnyValue::Int(value) => self.add(value),
+            AnyValue::Float(value) => self.add(value),
+            AnyValue::Text(value) => self.add(value),
+            AnyValue::Bytes(value) => self.add(value),
+
+            #[cfg(feature = "json")]
+            AnyValue::Json(value) => self.add(value),
+
+            #[cfg(feature = "uuid")]
+            AnyValue::Uuid(value) => self.add(value),
+
+            AnyValue::Other { oid, bytes } => {
+                self.types.push(oid);
+                self.values.put_i32::<NetworkEndian>(bytes.len() as i32);
+                self.values.extend_from_slice(&bytes);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_reuses_buffers() {
+    let mut args = PgArguments::default();
+    args.add(1_i32);
+    args.add("hello");
+
+    assert_eq!(args.types, [23, 25]);
+    assert_eq!(args.values, b"\0\0\0\x04\0\0\0\x01\0\0\0\x05hello");
+
+    let (types, values) = (args.types.as_ptr(), args.values.as_ptr());
+
+    drop(args);
+
+    // the next arguments are encoded into the same, cleared buffers
+    let mut args = PgArguments::default();
+
+    assert!(args.types.is_empty());
+    assert!(args.values.is_empty());
+    assert_eq!(args.types.as_ptr(), types);
+    assert_eq!(args.values.as_ptr(), values);
+
+    args.add(2_i64);
+
+    assert_eq!(args.types, [20]);
+    assert_eq!(args.values, b"\0\0\0\x08\0\0\0\0\0\0\0\x02");
+}
+
+#[test]
+fn test_drops_large_buffers() {
+    let mut args = PgArguments::default();
+    args.add(vec![0_u8; MAX_RETAINED_CAPACITY]);
+
+    drop(args);
+
+    assert_eq!(PgArguments::default().values.capacity(), 0);
+}
@@ -1,20 +1,28 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::convert::TryInto;
+use std::error::Error as StdError;
+use std::fmt::{self, Display};
+use std::io;
 use std::ops::Range;
 use std::sync::Arc;
 
 use futures_core::future::BoxFuture;
 use futures_util::TryFutureExt;
 
-use crate::connection::{Connect, Connection};
+use crate::cache::StatementCache;
+use crate::connection::{Connect, Connection, QueryLogger};
 use crate::executor::Executor;
 use crate::postgres::database::Postgres;
+use crate::postgres::pgpass;
 use crate::postgres::protocol::{
     Authentication, AuthenticationMd5, AuthenticationSasl, BackendKeyData, Message,
-    PasswordMessage, StartupMessage, StatementId, Terminate, TypeFormat,
+    PasswordMessage, StartupMessage, StatementId, Terminate, TypeId,
 };
 use crate::postgres::stream::PgStream;
-use crate::postgres::{sasl, tls};
+use crate::postgres::types::SharedStr;
+use crate::postgres::{sasl, tls, PgColumn, PgRow, PgTypeInfo};
+use crate::row::Row;
 use crate::url::Url;
 
 /// An asynchronous connection to a [Postgres](struct.Postgres.html) database.
@@ -23,6 +31,67 @@ use crate::url::Url;
 /// string, as documented at
 /// <https://www.postgresql.org/docs/12/libpq-connect.html#LIBPQ-CONNSTRING>
 ///
+/// ### Runtime Parameters
+/// These query parameters are sent to the server to set the parameter of the same name
+/// for the session:
+///
+/// * `application_name`: the name of the application that is shown for the connection in
+///   `pg_stat_activity`, e.g., `?application_name=my-service`.
+///
+/// * `statement_timeout`: how long a statement may run before it is canceled; in milliseconds
+///   or with a unit, e.g., `?statement_timeout=5s`. A canceled statement returns an error with
+///   the code `57014` ([`PgSqlState::QueryCanceled`]).
+///
+/// [`PgSqlState::QueryCanceled`]: crate::postgres::PgSqlState::QueryCanceled
+///
+/// ### Statement Cache
+/// Queries with arguments are prepared as named statements that are reused by later
+/// executions of the same query. The `statement_cache_capacity` query parameter sets how many
+/// statements are kept, e.g., `?statement_cache_capacity=50`; the default is `100`. When the
+/// cache is full, the least recently used statement is closed on the server to make room.
+/// A capacity of `0` disables the cache and every query is prepared again.
+///
+/// ### Cancellation
+/// A running statement can be canceled from another task with the handle returned by
+/// [`cancel_token`](PgConnection::cancel_token).
+///
+/// ### Unix Domain Sockets
+/// On Unix, a host that is an absolute path is the directory of the server's Unix domain socket.
+/// It can be given in the `host` query parameter or percent-encoded in place of the host:
+///
+/// ```text
+/// postgresql:///my_database?host=/var/run/postgresql
+/// postgresql://%2Fvar%2Frun%2Fpostgresql/my_database
+/// ```
+///
+/// ### TCP Options
+/// `TCP_NODELAY` is set on the socket so that a query is sent without delay; it can be turned
+/// off with `tcp_nodelay=false`. TCP keepalive is enabled by `keepalives_idle`, the number of
+/// seconds a connection is idle before the first keepalive probe is sent, as in `libpq`, e.g.,
+/// `?keepalives_idle=60`. Both are set before the startup handshake.
+///
+/// ### Multiple Hosts
+/// As with `libpq`, a comma-separated list of hosts can be given in place of the host or in the
+/// `host` query parameter. The hosts are tried in order until a connection is established:
+///
+/// ```text
+/// postgresql://host1:5432,host2:5433/my_database
+/// postgresql:///my_database?host=host1,host2&port=5432,5433
+/// ```
+///
+/// With `target_session_attrs=read-write`, a server that only accepts read-only sessions,
+/// e.g., a standby, is skipped as well. If no host could be connected to, the error of each host
+/// is returned in a [`PgHostsError`].
+///
+/// ### Passwords
+/// A password is only sent if the server asks for one. If the URL has no password, it is looked up
+/// in the password file as `libpq` does: the `passfile` query parameter, the `PGPASSFILE`
+/// environment variable, or `~/.pgpass` (`%APPDATA%\postgresql\pgpass.conf` on Windows).
+/// Each line is `hostname:port:database:username:password`, where any of the first four fields
+/// can be `*`; the first line that matches the connection is used. On Unix, the file is ignored
+/// if it is readable by the group or others.
+/// See <https://www.postgresql.org/docs/12/libpq-pgpass.html>.
+///
 /// ### TLS Support (requires `tls` feature)
 /// This connection type supports the same `sslmode` query parameter that `libpq` does in
 /// connection strings: <https://www.postgresql.org/docs/12/libpq-ssl.html>
@@ -82,20 +151,28 @@ pub struct PgConnection {
     pub(super) next_statement_id: u32,
     pub(super) is_ready: bool,
 
-    pub(super) cache_statement: HashMap<Box<str>, StatementId>,
+    // Whether a portal is being fetched in chunks; the query is not finished by a [Sync] until
+    // the last row has been fetched
+    pub(super) needs_sync: bool,
+
+    pub(super) cache_statement: StatementCache<StatementId>,
     pub(super) cache_statement_columns: HashMap<StatementId, Arc<HashMap<Box<str>, usize>>>,
-    pub(super) cache_statement_formats: HashMap<StatementId, Arc<[TypeFormat]>>,
+    pub(super) cache_statement_fields: HashMap<StatementId, Arc<[PgColumn]>>,
+
+    // Names of the types that were looked up in `pg_type`, by object identifier
+    pub(super) cache_type_name: HashMap<u32, SharedStr>,
 
     // Work buffer for the value ranges of the current row
     // This is used as the backing memory for each Row's value indexes
     pub(super) current_row_values: Vec<Option<Range<u32>>>,
 
-    // TODO: Find a use for these values. Perhaps in a debug impl of PgConnection?
-    #[allow(dead_code)]
-    process_id: u32,
+    // The URL and the key data that identify the connection in a cancel request
+    pub(super) url: Url,
+    pub(super) process_id: u32,
+    pub(super) secret_key: u32,
 
-    #[allow(dead_code)]
-    secret_key: u32,
+    // How the statements that are run are logged, e.g., by [Connection::on_query]
+    pub(super) query_logger: QueryLogger,
 }
 
 // https://www.postgresql.org/docs/12/protocol-flow.html#id-1.10.5.7.3
@@ -104,9 +181,15 @@ async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<Postgres, Ba
     let username = url.username().unwrap_or("postgres");
     let database = url.database().unwrap_or("postgres");
 
+    // Runtime parameters that are passed through from the URL
+    let url_params: Vec<_> = ["application_name", "statement_timeout"]
+        .iter()
+        .filter_map(|&name| Some((name, url.param(name)?)))
+        .collect();
+
     // See this doc for more runtime parameters
     // https://www.postgresql.org/docs/12/runtime-config-client.html
-    let params = &[
+    let mut params = vec![
         ("user", username),
         ("database", database),
         // Sets the display format for date and time values,
@@ -121,7 +204,17 @@ async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<Postgres, Ba
         ("client_encoding", "UTF-8"),
     ];
 
-    stream.write(StartupMessage { params });
+    for (name, value) in &url_params {
+        if value.contains('\0') {
+            return Err(crate::Error::Configuration(
+                format!("{} must not contain a NUL byte", name).into(),
+            ));
+        }
+
+        params.push((name, value));
+    }
+
+    stream.write(StartupMessage { params: &params });
     stream.flush().await?;
 
     let mut key_data = BackendKeyData {
@@ -133,13 +226,14 @@ async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<Postgres, Ba
         match stream.receive().await? {
             Message::Authentication => match Authentication::read(stream.buffer())? {
                 Authentication::Ok => {
-                    // do nothing. no password is needed to continue.
+                    // do nothing. the server trusts the connection or it already authenticated
+                    // with a password; no password is needed to continue.
                 }
 
                 Authentication::CleartextPassword => {
-                    stream.write(PasswordMessage::ClearText(
-                        &url.password().unwrap_or_default(),
-                    ));
+                    let password = password(url, username, database);
+
+                    stream.write(PasswordMessage::ClearText(&password));
 
                     stream.flush().await?;
                 }
@@ -150,7 +244,7 @@ async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<Postgres, Ba
                     let data = AuthenticationMd5::read(&stream.buffer()[4..])?;
 
                     stream.write(PasswordMessage::Md5 {
-                        password: &url.password().unwrap_or_default(),
+                        password: &password(url, username, database),
                         user: username,
                         salt: data.salt,
                     });
@@ -184,8 +278,9 @@ async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<Postgres, Ba
 
                     if has_sasl || has_sasl_plus {
                         // TODO: Handle -PLUS differently if we're in a TLS stream
-                        sasl::authenticate(stream, username, &url.password().unwrap_or_default())
-                            .await?;
+                        let password = password(url, username, database);
+
+                        sasl::authenticate(stream, username, &password).await?;
                     } else {
                         return Err(protocol_err!(
                             "unsupported SASL auth mechanisms: {:?}",
@@ -203,14 +298,10 @@ async fn startup(stream: &mut PgStream, url: &Url) -> crate::Result<Postgres, Ba
             },
 
             Message::BackendKeyData => {
-                // do nothing. we do not care about the server values here.
+                // kept to cancel a running statement
                 key_data = BackendKeyData::read(stream.buffer())?;
             }
 
-            Message::ParameterStatus => {
-                // do nothing. we do not care about the server values here.
-            }
-
             Message::ReadyForQuery => {
                 // done. connection is now fully established and can accept
                 // queries for execution.
@@ -240,25 +331,323 @@ impl PgConnection {
         url: std::result::Result<Url, url::ParseError>,
     ) -> crate::Result<Postgres, Self> {
         let url = url?;
+        let statement_cache_capacity = statement_cache_capacity(&url)?;
+        let read_write = target_session_attrs(&url)?;
+
+        let hosts = match hosts(&url)? {
+            Some(hosts) => hosts,
+            None => return Self::establish(url, statement_cache_capacity, read_write).await,
+        };
+
+        let mut errors = Vec::with_capacity(hosts.len());
+
+        for (host, port) in hosts {
+            let url = url.with_host(&host, port)?;
+
+            match Self::establish(url, statement_cache_capacity, read_write).await {
+                Ok(conn) => return Ok(conn),
+
+                Err(error) => {
+                    log::debug!("failed to connect to {}:{}: {}", host, port, error);
+                    errors.push((host, port, error));
+                }
+            }
+        }
+
+        Err(all_hosts_failed(errors))
+    }
+
+    async fn establish(
+        url: Url,
+        statement_cache_capacity: usize,
+        read_write: bool,
+    ) -> crate::Result<Postgres, Self> {
         let mut stream = PgStream::new(&url).await?;
 
         tls::request_if_needed(&mut stream, &url).await?;
         let key_data = startup(&mut stream, &url).await?;
 
-        Ok(Self {
+        let mut conn = Self {
             stream,
             current_row_values: Vec::with_capacity(10),
             next_statement_id: 1,
             is_ready: true,
-            cache_statement: HashMap::new(),
+            needs_sync: false,
+            cache_statement: StatementCache::new(statement_cache_capacity),
             cache_statement_columns: HashMap::new(),
-            cache_statement_formats: HashMap::new(),
+            cache_statement_fields: HashMap::new(),
+            cache_type_name: HashMap::new(),
+            url,
             process_id: key_data.process_id,
             secret_key: key_data.secret_key,
+            query_logger: QueryLogger::default(),
+        };
+
+        // https://www.postgresql.org/docs/12/libpq-connect.html#LIBPQ-CONNECT-TARGET-SESSION-ATTRS
+        if read_write {
+            let read_only = crate::query::query("SHOW transaction_read_only")
+                .try_map(|row: PgRow| row.try_get::<String, _>(0))
+                .fetch_one(&mut conn)
+                .await?;
+
+            if read_only == "on" {
+                let _ = conn.close().await;
+
+                return Err(crate::Error::Io(io::Error::new(
+                    io::ErrorKind::Other,
+                    "session is read-only",
+                )));
+            }
+        }
+
+        Ok(conn)
+    }
+
+    /// The number of prepared statements that are kept for reuse.
+    ///
+    /// This is at most the `statement_cache_capacity` the connection was opened with.
+    pub fn cached_statements_size(&self) -> usize {
+        self.cache_statement.len()
+    }
+
+    /// The current value of a parameter that is reported by the server, e.g., `server_version`,
+    /// `TimeZone` or `integer_datetimes`.
+    ///
+    /// The values are received at startup and updated whenever they change, e.g., after `SET`.
+    /// See <https://www.postgresql.org/docs/12/protocol-flow.html#PROTOCOL-ASYNC> for
+    /// the parameters that are reported.
+    pub fn parameter_status(&self, name: &str) -> Option<&str> {
+        self.stream.parameters.get(name).map(|value| &**value)
+    }
+
+    /// The major and minor version of the server, e.g., `(12, 2)` for `12.2` or `(9, 6)`
+    /// for `9.6.17`.
+    pub fn server_version(&self) -> Option<(u16, u16)> {
+        parse_server_version(self.parameter_status("server_version")?)
+    }
+
+    /// Look up the name of a type that is only known by its object identifier, e.g., one
+    /// from [`PgTypeInfo::with_oid`] or a column of a type created by an extension.
+    ///
+    /// The name is queried from `pg_type` the first time a type is resolved on this connection
+    /// and is then remembered. A type that already has a name, or whose object identifier is
+    /// not found in `pg_type`, is returned unchanged.
+    ///
+    /// ```rust,ignore
+    /// let type_info = conn.resolve_type(&PgTypeInfo::with_oid(oid)).await?;
+    ///
+    /// assert_eq!(type_info.to_string(), "HSTORE");
+    /// ```
+    ///
+    /// [`PgTypeInfo::with_oid`]: crate::postgres::PgTypeInfo::with_oid
+    pub async fn resolve_type(
+        &mut self,
+        type_info: &PgTypeInfo,
+    ) -> crate::Result<Postgres, PgTypeInfo> {
+        if type_info.name.is_some() || type_info.id == TypeId::UNSPECIFIED {
+            return Ok(type_info.clone());
+        }
+
+        let mut type_names = self.get_type_names(Some(type_info.id)).await?;
+
+        Ok(PgTypeInfo {
+            id: type_info.id,
+            name: type_names.remove(&type_info.id.0),
+        })
+    }
+}
+
+// The password from the URL or else the password file; without either, an empty password is sent
+// and the server rejects it
+fn password<'u>(url: &'u Url, username: &str, database: &str) -> Cow<'u, str> {
+    url.password()
+        .or_else(|| pgpass::password(url, username, database).map(Cow::Owned))
+        .unwrap_or_default()
+}
+
+fn statement_cache_capacity(url: &Url) -> crate::Result<Postgres, usize> {
+    match url.param("statement_cache_capacity") {
+        Some(capacity) => capacity.parse().map_err(|_| {
+            crate::Error::Configuration(
+                format!(
+                    "invalid `statement_cache_capacity` value: {:?}; expected a number of statements",
+                    capacity
+                )
+                .into(),
+            )
+        }),
+
+        None => Ok(100),
+    }
+}
+
+// `target_session_attrs=read-write` skips a server that only accepts read-only sessions,
+// e.g., a standby
+fn target_session_attrs(url: &Url) -> crate::Result<Postgres, bool> {
+    match url.param("target_session_attrs").as_deref() {
+        None | Some("any") => Ok(false),
+        Some("read-write") => Ok(true),
+
+        Some(value) => Err(crate::Error::Configuration(
+            format!(
+                "invalid `target_session_attrs` value: {:?}; expected `any` or `read-write`",
+                value
+            )
+            .into(),
+        )),
+    }
+}
+
+// The hosts to try in order, from a comma-separated `host` query parameter that may give a port
+// for each host, e.g., `?host=host1:5432,host2:5433`; the `port` query parameter is the port of
+// the hosts without one, either one for all or one for each
+//
+// `None` if there is a single host, which is connected to as is
+fn hosts(url: &Url) -> crate::Result<Postgres, Option<Vec<(String, u16)>>> {
+    let hosts = match url.param("host") {
+        Some(hosts) if hosts.contains(',') => hosts,
+        _ => return Ok(None),
+    };
+
+    let hosts: Vec<&str> = hosts.split(',').map(str::trim).collect();
+
+    let ports = match url.param("port") {
+        Some(ports) => ports
+            .split(',')
+            .map(|port| parse_port(port.trim()))
+            .collect::<crate::Result<Postgres, Vec<u16>>>()?,
+
+        None => Vec::new(),
+    };
+
+    if ports.len() > 1 && ports.len() != hosts.len() {
+        return Err(crate::Error::Configuration(
+            format!(
+                "expected 1 or {} values for `port`, one for each host, got {}",
+                hosts.len(),
+                ports.len()
+            )
+            .into(),
+        ));
+    }
+
+    hosts
+        .into_iter()
+        .enumerate()
+        .map(|(i, host)| {
+            let (host, port) = split_port(host);
+
+            let port = match port {
+                Some(port) => parse_port(port)?,
+                None => ports
+                    .get(i)
+                    .or_else(|| ports.first())
+                    .copied()
+                    .unwrap_or(5432),
+            };
+
+            Ok((host.to_owned(), port))
         })
+        .collect::<crate::Result<Postgres, _>>()
+        .map(Some)
+}
+
+// Splits a trailing `:<port>` off of a host; an IPv6 address is in brackets, e.g., `[::1]:5432`
+fn split_port(host: &str) -> (&str, Option<&str>) {
+    let port_start = if host.starts_with('[') {
+        host.find("]:").map(|i| i + 1)
+    } else if host.starts_with('/') || host.matches(':').count() != 1 {
+        None
+    } else {
+        host.find(':')
+    };
+
+    match port_start {
+        Some(i) => (&host[..i], Some(&host[i + 1..])),
+        None => (host, None),
+    }
+}
+
+fn parse_port(port: &str) -> crate::Result<Postgres, u16> {
+    port.parse()
+        .map_err(|_| crate::Error::Configuration(format!("invalid port: {:?}", port).into()))
+}
+
+/// The error of each of the hosts of a connection URL when none of them could be connected to.
+///
+/// It is returned as the inner error of an [`Error::Io`]; the kind of the IO error is that of the
+/// last host if none of the hosts could be reached and [`io::ErrorKind::Other`] otherwise.
+///
+/// ```rust,ignore
+/// if let Err(sqlx::Error::Io(error)) = PgConnection::connect(url).await {
+///     if let Some(hosts) = error.get_ref().and_then(|e| e.downcast_ref::<PgHostsError>()) {
+///         for (host, port, error) in hosts.errors() {
+///             eprintln!("{}:{}: {}", host, port, error);
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`Error::Io`]: crate::Error::Io
+#[derive(Debug)]
+pub struct PgHostsError {
+    errors: Vec<(String, u16, crate::Error<Postgres>)>,
+}
+
+impl PgHostsError {
+    /// The host, port and error of each host, in the order they were tried.
+    pub fn errors(&self) -> &[(String, u16, crate::Error<Postgres>)] {
+        &self.errors
     }
 }
 
+impl Display for PgHostsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("could not connect to any of the hosts")?;
+
+        for (host, port, error) in &self.errors {
+            write!(f, "; {}:{}: {}", host, port, error)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl StdError for PgHostsError {}
+
+fn all_hosts_failed(errors: Vec<(String, u16, crate::Error<Postgres>)>) -> crate::Error<Postgres> {
+    let reached = errors
+        .iter()
+        .any(|(_, _, error)| !matches!(error, crate::Error::Io(_)));
+
+    let kind = match errors.last() {
+        Some((_, _, crate::Error::Io(error))) if !reached => error.kind(),
+        _ => io::ErrorKind::Other,
+    };
+
+    crate::Error::Io(io::Error::new(kind, PgHostsError { errors }))
+}
+
+// The version may be followed by anything, e.g., `12.2 (Debian 12.2-2.pgdg100+1)` or `13beta1`
+fn parse_server_version(s: &str) -> Option<(u16, u16)> {
+    fn leading_number(s: &str) -> &str {
+        &s[..s
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or_else(|| s.len())]
+    }
+
+    let major = leading_number(s);
+    let rest = &s[major.len()..];
+
+    let minor = if rest.starts_with('.') {
+        leading_number(&rest[1..]).parse().ok()?
+    } else {
+        0
+    };
+
+    Some((major.parse().ok()?, minor))
+}
+
 impl Connect for PgConnection {
     fn connect<T>(url: T) -> BoxFuture<'static, crate::Result<Postgres, PgConnection>>
     where
@@ -277,4 +666,107 @@ impl Connection for PgConnection {
     fn ping(&mut self) -> BoxFuture<crate::Result<Postgres, ()>> {
         Box::pin(Executor::execute(self, "SELECT 1").map_ok(|_| ()))
     }
+
+    #[doc(hidden)]
+    fn query_logger(&mut self) -> &mut QueryLogger {
+        &mut self.query_logger
+    }
+}
+
+#[test]
+fn test_parse_server_version() {
+    assert_eq!(parse_server_version("12.2"), Some((12, 2)));
+    assert_eq!(parse_server_version("9.6.17"), Some((9, 6)));
+    assert_eq!(
+        parse_server_version("12.2 (Debian 12.2-2.pgdg100+1)"),
+        Some((12, 2))
+    );
+    assert_eq!(parse_server_version("13beta1"), Some((13, 0)));
+
+    assert_eq!(parse_server_version(""), None);
+    assert_eq!(parse_server_version("12."), None);
+    assert_eq!(parse_server_version("devel"), None);
+}
+
+#[test]
+fn test_hosts() {
+    use std::convert::TryFrom;
+
+    let parse = |url: &str| hosts(&Url::try_from(url).unwrap());
+
+    assert_eq!(
+        parse("postgres://host1:5433,host2,[::1]:5434/db").unwrap(),
+        Some(vec![
+            ("host1".to_owned(), 5433),
+            ("host2".to_owned(), 5432),
+            ("[::1]".to_owned(), 5434)
+        ])
+    );
+
+    assert_eq!(
+        parse("postgres:///db?host=host1,host2&port=5433,5434").unwrap(),
+        Some(vec![("host1".to_owned(), 5433), ("host2".to_owned(), 5434)])
+    );
+
+    assert_eq!(
+        parse("postgres:///db?host=host1,/tmp&port=5433").unwrap(),
+        Some(vec![("host1".to_owned(), 5433), ("/tmp".to_owned(), 5433)])
+    );
+
+    assert_eq!(parse("postgres://host1:5433/db").unwrap(), None);
+    assert_eq!(
+        parse("postgres:///db?host=/var/run/postgresql").unwrap(),
+        None
+    );
+
+    assert!(parse("postgres:///db?host=a,b,c&port=1,2").is_err());
+}
+
+#[test]
+fn test_all_hosts_failed() {
+    let error = all_hosts_failed(vec![
+        (
+            "host1".to_owned(),
+            5432,
+            io::Error::from(io::ErrorKind::ConnectionRefused).into(),
+        ),
+        (
+            "host2".to_owned(),
+            5433,
+            crate::Error::Protocol("authentication failed".into()),
+        ),
+    ]);
+
+    let error = match error {
+        crate::Error::Io(error) => error,
+        error => panic!("expected an IO error; got {:?}", error),
+    };
+
+    // a host was reached so it is not only a problem with the network
+    assert_eq!(error.kind(), io::ErrorKind::Other);
+
+    let hosts = error
+        .get_ref()
+        .and_then(|error| error.downcast_ref::<PgHostsError>())
+        .unwrap();
+
+    assert_eq!(hosts.errors().len(), 2);
+    assert_eq!(hosts.errors()[0].0, "host1");
+    assert_eq!(hosts.errors()[1].1, 5433);
+
+    assert_eq!(
+        error.to_string(),
+        "could not connect to any of the hosts; host1:5432: connection refused; \
+         host2:5433: authentication failed"
+    );
+
+    let error = all_hosts_failed(vec![(
+        "host1".to_owned(),
+        5432,
+        io::Error::from(io::ErrorKind::ConnectionRefused).into(),
+    )]);
+
+    assert!(
+        matches!(error, crate::Error::Io(ref error) if error.kind() == io::ErrorKind::ConnectionRefused)
+    );
 }
@@ -0,0 +1,197 @@
+use async_stream::try_stream;
+use bytes::Bytes;
+use futures_core::Stream;
+
+use crate::postgres::protocol::{CommandComplete, CopyData, CopyDone, CopyFail, Message};
+use crate::postgres::{PgConnection, Postgres};
+
+// CopyData is buffered up to this many bytes before it is sent
+const WRITE_THRESHOLD: usize = 64 * 1024;
+
+impl PgConnection {
+    /// Starts a `COPY ... FROM STDIN` to bulk load data with the returned [`PgCopyIn`].
+    ///
+    /// The data is sent as is and must be in the format given by the statement, e.g., the
+    /// default text format with a row per line and columns separated by tabs.
+    ///
+    /// ```rust,ignore
+    /// let mut copy = conn.copy_in("COPY users (id, name) FROM STDIN").await?;
+    ///
+    /// copy.send(b"1\tAlice\n2\tBob\n").await?;
+    ///
+    /// let rows = copy.finish().await?;
+    /// ```
+    ///
+    /// <https://www.postgresql.org/docs/12/sql-copy.html>
+    pub async fn copy_in(&mut self, statement: &str) -> crate::Result<Postgres, PgCopyIn<'_>> {
+        self.run(statement, None).await?;
+
+        match self.stream.receive().await? {
+            Message::CopyInResponse => Ok(PgCopyIn {
+                conn: self,
+                finished: false,
+            }),
+
+            message => Err(protocol_err!(
+                "copy_in: expected CopyInResponse for a COPY FROM STDIN; received {:?}",
+                message
+            )
+            .into()),
+        }
+    }
+
+    /// Runs a `COPY ... TO STDOUT` to bulk export data as a stream of [`Bytes`] chunks.
+    ///
+    /// The data is in the format given by the statement. The server sends a row per chunk in
+    /// the text and CSV formats.
+    ///
+    /// ```rust,ignore
+    /// let mut copy = conn.copy_out("COPY users (id, name) TO STDOUT");
+    ///
+    /// while let Some(chunk) = copy.try_next().await? {
+    ///     output.write_all(&chunk)?;
+    /// }
+    /// ```
+    ///
+    /// If the stream is dropped before it is done, the rest of the data is discarded by the
+    /// next use of the connection.
+    ///
+    /// <https://www.postgresql.org/docs/12/sql-copy.html>
+    ///
+    /// [`Bytes`]: https://docs.rs/bytes/0.5/bytes/struct.Bytes.html
+    pub fn copy_out<'c>(
+        &'c mut self,
+        statement: &'c str,
+    ) -> impl Stream<Item = crate::Result<Postgres, Bytes>> + Unpin + 'c {
+        Box::pin(try_stream! {
+            start_copy_out(self, statement).await?;
+
+            while let Some(data) = next_copy_data(self).await? {
+                yield data;
+            }
+        })
+    }
+}
+
+async fn start_copy_out(conn: &mut PgConnection, statement: &str) -> crate::Result<Postgres, ()> {
+    conn.run(statement, None).await?;
+
+    match conn.stream.receive().await? {
+        Message::CopyOutResponse => Ok(()),
+
+        Message::CopyInResponse => reject_copy_in(conn).await,
+
+        message => Err(protocol_err!(
+            "copy_out: expected CopyOutResponse for a COPY TO STDOUT; received {:?}",
+            message
+        )
+        .into()),
+    }
+}
+
+// An error is returned as soon as it is received; the rest of the response
+// is discarded by the next use of the connection
+async fn next_copy_data(conn: &mut PgConnection) -> crate::Result<Postgres, Option<Bytes>> {
+    loop {
+        match conn.stream.receive().await? {
+            Message::CopyData => return Ok(Some(Bytes::copy_from_slice(conn.stream.buffer()))),
+
+            Message::CopyDone | Message::CommandComplete => {}
+
+            Message::ReadyForQuery => {
+                conn.is_ready = true;
+                return Ok(None);
+            }
+
+            message => {
+                return Err(protocol_err!("copy_out: unexpected message: {:?}", message).into());
+            }
+        }
+    }
+}
+
+/// A `COPY FROM STDIN` in progress, started by [`PgConnection::copy_in`].
+///
+/// The copy must be completed with [`finish`]. It is aborted if this is dropped first.
+///
+/// [`finish`]: #method.finish
+pub struct PgCopyIn<'c> {
+    conn: &'c mut PgConnection,
+    finished: bool,
+}
+
+impl PgCopyIn<'_> {
+    /// Sends a chunk of data; a chunk does not need to line up with rows.
+    ///
+    /// Errors in the data are only reported by [`finish`].
+    ///
+    /// [`finish`]: #method.finish
+    pub async fn send(&mut self, data: impl AsRef<[u8]>) -> crate::Result<Postgres, ()> {
+        self.conn.stream.write(CopyData(data.as_ref()));
+
+        if self.conn.stream.stream.buffer_mut().len() >= WRITE_THRESHOLD {
+            self.conn.stream.flush().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Completes the copy and returns the number of rows copied.
+    pub async fn finish(mut self) -> crate::Result<Postgres, u64> {
+        self.finished = true;
+
+        self.conn.stream.write(CopyDone);
+        self.conn.stream.flush().await?;
+
+        let mut rows = 0;
+
+        loop {
+            match self.conn.stream.receive().await? {
+                Message::CommandComplete => {
+                    rows = CommandComplete::read(self.conn.stream.buffer())?.affected_rows;
+                }
+
+                Message::ReadyForQuery => {
+                    self.conn.is_ready = true;
+                    break;
+                }
+
+                message => {
+                    return Err(protocol_err!("copy_in: unexpected message: {:?}", message).into());
+                }
+            }
+        }
+
+        Ok(rows)
+    }
+
+    /// Aborts the copy; nothing that was sent is kept.
+    pub async fn abort(mut self, message: &str) -> crate::Result<Postgres, ()> {
+        self.finished = true;
+
+        self.conn.stream.write(CopyFail(message));
+        self.conn.stream.flush().await?;
+
+        // the server responds with the error we asked for
+        self.conn.wait_until_ready().await
+    }
+}
+
+impl Drop for PgCopyIn<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            // sent with the next message to the server
+            self.conn.stream.write(CopyFail("PgCopyIn was dropped"));
+        }
+    }
+}
+
+// A COPY FROM STDIN through the query API; end it so the connection can be used again
+pub(super) async fn reject_copy_in(conn: &mut PgConnection) -> crate::Result<Postgres, ()> {
+    conn.stream
+        .write(CopyFail("COPY FROM STDIN is only supported by `copy_in`"));
+
+    conn.stream.flush().await?;
+
+    Err(protocol_err!("COPY FROM STDIN must be run with `PgConnection::copy_in`").into())
+}
@@ -3,24 +3,63 @@ use std::sync::Arc;
 
 use futures_core::future::BoxFuture;
 
-use crate::connection::ConnectionSource;
+use crate::arguments::Arguments;
+use crate::connection::{ConnectionSource, QueryTimer};
 use crate::cursor::Cursor;
 use crate::executor::Execute;
 use crate::pool::Pool;
-use crate::postgres::protocol::{
-    DataRow, Message, ReadyForQuery, RowDescription, StatementId, TypeFormat,
-};
-use crate::postgres::{PgArguments, PgConnection, PgRow, Postgres};
+use crate::postgres::copy::reject_copy_in;
+use crate::postgres::protocol::{DataRow, Message, ReadyForQuery, RowDescription, StatementId};
+use crate::postgres::{PgArguments, PgColumn, PgConnection, PgRow, PgTypeInfo, Postgres};
 
 pub struct PgCursor<'c, 'q> {
     source: ConnectionSource<'c, PgConnection>,
     query: Option<(&'q str, Option<PgArguments>)>,
+
+    // Kept after the query is run to be attached to errors
+    sql: &'q str,
     columns: Arc<HashMap<Box<str>, usize>>,
-    formats: Arc<[TypeFormat]>,
+    fields: Arc<[PgColumn]>,
+
+    // The number of rows that are requested from the server at a time, if limited
+    chunk_size: Option<i32>,
+
+    // Times the query for the hook of the connection until the last row has been fetched
+    timer: Option<QueryTimer>,
 }
 
 impl crate::cursor::private::Sealed for PgCursor<'_, '_> {}
 
+impl PgCursor<'_, '_> {
+    /// Fetch the rows from the server at most `rows` at a time, e.g., to iterate over a large
+    /// result set without the server running ahead of the rows that have been processed.
+    ///
+    /// The query is run in a portal that is suspended after each chunk of rows until the
+    /// rows of that chunk have been returned by [`next`]. A chunk size of `0` fetches all rows
+    /// at once, which is the default. This has no effect once the first row has been fetched.
+    ///
+    /// Dropping the cursor before the last row closes the portal; the rows that have not been
+    /// requested yet are never sent.
+    ///
+    /// ```rust,ignore
+    /// let mut cursor = sqlx::query("SELECT * FROM events").fetch(&mut conn).chunk_size(1000);
+    ///
+    /// while let Some(row) = cursor.next().await? {
+    ///     // ..
+    /// }
+    /// ```
+    ///
+    /// [`next`]: crate::cursor::Cursor::next
+    pub fn chunk_size(mut self, rows: u32) -> Self {
+        self.chunk_size = match rows {
+            0 => None,
+            rows => Some(rows.min(i32::MAX as u32) as i32),
+        };
+
+        self
+    }
+}
+
 impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
     type Database = Postgres;
 
@@ -30,11 +69,16 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
         Self: Sized,
         E: Execute<'q, Postgres>,
     {
+        let (sql, arguments) = query.into_parts();
+
         Self {
             source: ConnectionSource::Pool(pool.clone()),
             columns: Arc::default(),
-            formats: Arc::new([] as [TypeFormat; 0]),
-            query: Some(query.into_parts()),
+            fields: Arc::new([] as [PgColumn; 0]),
+            query: Some((sql, arguments)),
+            sql,
+            chunk_size: None,
+            timer: None,
         }
     }
 
@@ -44,45 +88,57 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
         Self: Sized,
         E: Execute<'q, Postgres>,
     {
+        let (sql, arguments) = query.into_parts();
+
         Self {
             source: ConnectionSource::ConnectionRef(conn),
             columns: Arc::default(),
-            formats: Arc::new([] as [TypeFormat; 0]),
-            query: Some(query.into_parts()),
+            fields: Arc::new([] as [PgColumn; 0]),
+            query: Some((sql, arguments)),
+            sql,
+            chunk_size: None,
+            timer: None,
         }
     }
 
     fn next(&mut self) -> BoxFuture<crate::Result<Postgres, Option<PgRow<'_>>>> {
-        Box::pin(next(self))
+        let sql = self.sql;
+
+        Box::pin(async move { next(self).await.map_err(|err| err.pg_err_attach_query(sql)) })
     }
 }
 
-fn parse_row_description(rd: RowDescription) -> (HashMap<Box<str>, usize>, Vec<TypeFormat>) {
+fn parse_row_description(rd: RowDescription) -> (HashMap<Box<str>, usize>, Vec<PgColumn>) {
     let mut columns = HashMap::new();
-    let mut formats = Vec::new();
+    let mut fields = Vec::new();
 
     columns.reserve(rd.fields.len());
-    formats.reserve(rd.fields.len());
+    fields.reserve(rd.fields.len());
 
-    for (index, field) in rd.fields.iter().enumerate() {
+    for (index, field) in rd.fields.into_vec().into_iter().enumerate() {
         if let Some(name) = &field.name {
             columns.insert(name.clone(), index);
         }
 
-        formats.push(field.type_format);
+        fields.push(PgColumn {
+            name: field.name,
+            ordinal: index,
+            type_info: PgTypeInfo::with_oid(field.type_id.0),
+            format: field.type_format,
+        });
     }
 
-    (columns, formats)
+    (columns, fields)
 }
 
 // Used to describe the incoming results
 // We store the column map in an Arc and share it among all rows
 async fn expect_desc(
     conn: &mut PgConnection,
-) -> crate::Result<Postgres, (HashMap<Box<str>, usize>, Vec<TypeFormat>)> {
+) -> crate::Result<Postgres, (HashMap<Box<str>, usize>, Vec<PgColumn>)> {
     let description: Option<_> = loop {
         match conn.stream.receive().await? {
-            Message::ParseComplete | Message::BindComplete => {}
+            Message::CloseComplete | Message::ParseComplete | Message::BindComplete => {}
 
             Message::RowDescription => {
                 break Some(RowDescription::read(conn.stream.buffer())?);
@@ -107,22 +163,22 @@ async fn expect_desc(
 async fn get_or_describe(
     conn: &mut PgConnection,
     statement: StatementId,
-) -> crate::Result<Postgres, (Arc<HashMap<Box<str>, usize>>, Arc<[TypeFormat]>)> {
+) -> crate::Result<Postgres, (Arc<HashMap<Box<str>, usize>>, Arc<[PgColumn]>)> {
     if !conn.cache_statement_columns.contains_key(&statement)
-        || !conn.cache_statement_formats.contains_key(&statement)
+        || !conn.cache_statement_fields.contains_key(&statement)
     {
-        let (columns, formats) = expect_desc(conn).await?;
+        let (columns, fields) = expect_desc(conn).await?;
 
         conn.cache_statement_columns
             .insert(statement, Arc::new(columns));
 
-        conn.cache_statement_formats
-            .insert(statement, Arc::from(formats));
+        conn.cache_statement_fields
+            .insert(statement, Arc::from(fields));
     }
 
     Ok((
         Arc::clone(&conn.cache_statement_columns[&statement]),
-        Arc::clone(&conn.cache_statement_formats[&statement]),
+        Arc::clone(&conn.cache_statement_fields[&statement]),
     ))
 }
 
@@ -135,16 +191,27 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
     // contained query. We guard against this happening on _all_ next calls
     // by using [Option::take] which replaces the potential value in the Option with `None
     if let Some((query, arguments)) = cursor.query.take() {
-        let statement = conn.run(query, arguments).await?;
+        let num_arguments = arguments.as_ref().map_or(0, Arguments::len);
+        cursor.timer = QueryTimer::start(&conn.query_logger, query, num_arguments);
+
+        let statement = match cursor.chunk_size {
+            // a portal can only be suspended in the extended query flow
+            Some(chunk_size) => Some(
+                conn.run_in_chunks(query, arguments.unwrap_or_default(), chunk_size)
+                    .await?,
+            ),
+
+            None => conn.run(query, arguments).await?,
+        };
 
         // If there is a statement ID, this is a non-simple or prepared query
         if let Some(statement) = statement {
             // A prepared statement will re-use the previous column map if
             // this query has been executed before
-            let (columns, formats) = get_or_describe(&mut *conn, statement).await?;
+            let (columns, fields) = get_or_describe(&mut *conn, statement).await?;
 
             cursor.columns = columns;
-            cursor.formats = formats;
+            cursor.fields = fields;
         }
 
         // A non-prepared query must be described each time
@@ -155,10 +222,32 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
         match conn.stream.receive().await? {
             // Indicates that a phase of the extended query flow has completed
             // We as SQLx don't generally care as long as it is happening
-            Message::ParseComplete | Message::BindComplete => {}
+            Message::CloseComplete | Message::ParseComplete | Message::BindComplete => {}
 
             // Indicates that _a_ query has finished executing
-            Message::CommandComplete => {}
+            Message::CommandComplete => {
+                // the last chunk of a portal; [Sync] finishes the query
+                if conn.needs_sync {
+                    conn.write_sync();
+                    conn.needs_sync = false;
+
+                    conn.stream.flush().await?;
+                }
+            }
+
+            // Indicates that a chunk of rows has been returned and the portal is suspended
+            Message::PortalSuspended => {
+                let chunk_size = cursor.chunk_size.unwrap_or(0);
+
+                conn.write_execute("", chunk_size);
+                conn.write_flush();
+
+                conn.stream.flush().await?;
+            }
+
+            Message::CopyInResponse => {
+                reject_copy_in(conn).await?;
+            }
 
             // Indicates that all queries have finished executing
             Message::ReadyForQuery => {
@@ -166,15 +255,17 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                 let _ready = ReadyForQuery::read(conn.stream.buffer())?;
 
                 conn.is_ready = true;
+                cursor.timer = None;
+
                 break;
             }
 
             Message::RowDescription => {
                 let rd = RowDescription::read(conn.stream.buffer())?;
-                let (columns, formats) = parse_row_description(rd);
+                let (columns, fields) = parse_row_description(rd);
 
                 cursor.columns = Arc::new(columns);
-                cursor.formats = Arc::from(formats);
+                cursor.fields = Arc::from(fields);
             }
 
             Message::DataRow => {
@@ -182,7 +273,7 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
 
                 return Ok(Some(PgRow {
                     columns: Arc::clone(&cursor.columns),
-                    formats: Arc::clone(&cursor.formats),
+                    fields: Arc::clone(&cursor.fields),
                     data,
                 }));
             }
@@ -3,6 +3,7 @@
 use crate::database::{Database, HasCursor, HasRawValue, HasRow};
 use crate::postgres::error::PgError;
 use crate::postgres::row::PgValue;
+use crate::transaction::Options as TransactionOptions;
 
 /// **Postgres** database driver.
 #[derive(Debug)]
@@ -20,6 +21,23 @@ impl Database for Postgres {
     type RawBuffer = Vec<u8>;
 
     type Error = PgError;
+
+    fn begin_transaction(
+        options: &TransactionOptions,
+    ) -> crate::Result<Self, (String, Option<&'static str>)> {
+        let mut begin = String::from("BEGIN");
+
+        if let Some(level) = options.isolation_level {
+            begin.push_str(" ISOLATION LEVEL ");
+            begin.push_str(level.as_sql());
+        }
+
+        if options.read_only {
+            begin.push_str(" READ ONLY");
+        }
+
+        Ok((begin, None))
+    }
 }
 
 impl<'a> HasRow<'a> for Postgres {
@@ -3,44 +3,108 @@ use std::fmt::{self, Display};
 
 use crate::error::DatabaseError;
 use crate::postgres::protocol::Response;
-use crate::postgres::Postgres;
+use crate::postgres::{PgSeverity, PgSqlState, Postgres};
 
 #[derive(Debug)]
-pub struct PgError(pub(super) Response);
+pub struct PgError {
+    pub(super) response: Response,
+
+    // The query that failed, if known
+    pub(super) query: Option<Box<str>>,
+}
+
+impl PgError {
+    pub(super) fn new(response: Response) -> Self {
+        Self {
+            response,
+            query: None,
+        }
+    }
+
+    /// The severity; this is [`PgSeverity::Error`] or worse for an error.
+    pub fn severity(&self) -> PgSeverity {
+        self.response.severity
+    }
+
+    /// The error code parsed into a [`PgSqlState`], e.g., to match on a unique violation.
+    pub fn sql_state(&self) -> Option<PgSqlState> {
+        self.code().map(PgSqlState::from)
+    }
+
+    /// The text of the query that failed, if it is known.
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+}
 
 impl DatabaseError for PgError {
     fn message(&self) -> &str {
-        &self.0.message
+        &self.response.message
     }
 
     fn code(&self) -> Option<&str> {
-        Some(&self.0.code)
+        Some(&self.response.code)
     }
 
     fn details(&self) -> Option<&str> {
-        self.0.detail.as_ref().map(|s| &**s)
+        self.response.detail.as_ref().map(|s| &**s)
     }
 
     fn hint(&self) -> Option<&str> {
-        self.0.hint.as_ref().map(|s| &**s)
+        self.response.hint.as_ref().map(|s| &**s)
     }
 
     fn table_name(&self) -> Option<&str> {
-        self.0.table.as_ref().map(|s| &**s)
+        self.response.table.as_ref().map(|s| &**s)
     }
 
     fn column_name(&self) -> Option<&str> {
-        self.0.column.as_ref().map(|s| &**s)
+        self.response.column.as_ref().map(|s| &**s)
     }
 
     fn constraint_name(&self) -> Option<&str> {
-        self.0.constraint.as_ref().map(|s| &**s)
+        self.response.constraint.as_ref().map(|s| &**s)
+    }
+
+    // a serialization failure, a deadlock or any connection exception (class 08)
+    fn is_retryable(&self) -> bool {
+        match PgSqlState::from(&*self.response.code) {
+            PgSqlState::SerializationFailure | PgSqlState::DeadlockDetected => true,
+            _ => self.response.code.starts_with("08"),
+        }
     }
 }
 
+// e.g., `ERROR 42710: extension "uuid-ossp" already exists (extension.c:1656)`
 impl Display for PgError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.pad(self.message())
+        write!(
+            f,
+            "{} {}: {}",
+            self.response.severity.as_str(),
+            self.response.code,
+            self.response.message
+        )?;
+
+        if let Some(file) = &self.response.file {
+            write!(f, " ({}", file)?;
+
+            if let Some(line) = self.response.line {
+                write!(f, ":{}", line)?;
+            }
+
+            f.write_str(")")?;
+        }
+
+        if let Some(hint) = &self.response.hint {
+            write!(f, "; hint: {}", hint)?;
+        }
+
+        if let Some(query) = &self.query {
+            write!(f, "; query: {}", query)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -51,3 +115,96 @@ impl From<PgError> for crate::Error<Postgres> {
         crate::Error::Database(Box::new(err))
     }
 }
+
+impl crate::Error<Postgres> {
+    // Attaches the text of the failed query to an error from the database;
+    // an error that already has a query keeps it
+    pub(super) fn pg_err_attach_query(self, query: &str) -> Self {
+        match self {
+            crate::Error::Database(mut err) => {
+                if err.query.is_none() {
+                    err.query = Some(query.into());
+                }
+
+                crate::Error::Database(err)
+            }
+
+            err => err,
+        }
+    }
+}
+
+#[test]
+fn test_error_code() {
+    let response = Response::read(
+        b"SERROR\0VERROR\0C42710\0Mextension \"uuid-ossp\" already exists\0\
+          Fextension.c\0L1656\0RCreateExtension\0\0",
+    )
+    .unwrap();
+
+    let error = PgError::new(response);
+
+    assert_eq!(error.code(), Some("42710"));
+    assert_eq!(error.severity(), PgSeverity::Error);
+    assert_eq!(
+        error.sql_state(),
+        Some(PgSqlState::Other("42710".to_owned()))
+    );
+    assert_eq!(error.message(), "extension \"uuid-ossp\" already exists");
+}
+
+#[test]
+fn test_display_error() {
+    let response = Response::read(
+        b"SERROR\0VERROR\0C42710\0Mextension \"uuid-ossp\" already exists\0\
+          Fextension.c\0L1656\0RCreateExtension\0\0",
+    )
+    .unwrap();
+
+    assert_eq!(
+        PgError::new(response).to_string(),
+        "ERROR 42710: extension \"uuid-ossp\" already exists (extension.c:1656)"
+    );
+
+    let response =
+        Response::read(b"SERROR\0VERROR\0C42601\0Msyntax error\0Htry again\0\0").unwrap();
+
+    assert_eq!(
+        PgError::new(response).to_string(),
+        "ERROR 42601: syntax error; hint: try again"
+    );
+}
+
+#[test]
+fn test_attach_query() {
+    let response = Response::read(b"SERROR\0VERROR\0C42601\0Msyntax error\0\0").unwrap();
+    let error = crate::Error::from(PgError::new(response)).pg_err_attach_query("SELEC 1");
+
+    assert_eq!(
+        error.to_string(),
+        "ERROR 42601: syntax error; query: SELEC 1"
+    );
+
+    match error.pg_err_attach_query("SELECT 2") {
+        crate::Error::Database(err) => assert_eq!(err.query(), Some("SELEC 1")),
+        err => panic!("expected a database error; got {:?}", err),
+    }
+
+    let error = crate::Error::<Postgres>::RowNotFound.pg_err_attach_query("SELECT 1");
+    matches::assert_matches!(error, crate::Error::RowNotFound);
+}
+
+#[test]
+fn test_retryable_error() {
+    let error = |code: &str| {
+        let response = format!("SERROR\0VERROR\0C{}\0Mfailed\0\0", code);
+
+        PgError::new(Response::read(response.as_bytes()).unwrap())
+    };
+
+    assert!(error("40P01").is_retryable());
+    assert!(error("40001").is_retryable());
+    assert!(error("08006").is_retryable());
+    assert!(!error("23505").is_retryable());
+    assert!(!error("42601").is_retryable());
+}
@@ -5,9 +5,11 @@ use futures_core::future::BoxFuture;
 use futures_util::{stream, StreamExt, TryStreamExt};
 
 use crate::arguments::Arguments;
+use crate::connection::QueryTimer;
 use crate::cursor::Cursor;
 use crate::describe::{Column, Describe};
 use crate::executor::{Execute, Executor, RefExecutor};
+use crate::postgres::copy::reject_copy_in;
 use crate::postgres::protocol::{
     self, CommandComplete, Field, Message, ParameterDescription, ReadyForQuery, RowDescription,
     StatementId, TypeFormat, TypeId,
@@ -22,23 +24,42 @@ impl PgConnection {
     }
 
     pub(crate) fn write_prepare(&mut self, query: &str, args: &PgArguments) -> StatementId {
-        if let Some(&id) = self.cache_statement.get(query) {
-            id
+        if let Some(&mut id) = self.cache_statement.get_mut(query) {
+            return id;
+        }
+
+        let id = if self.cache_statement.capacity() == 0 {
+            // Without a cache the unnamed statement is used, which the next [Parse] replaces;
+            // its description is for a different query
+            self.forget_statement(StatementId(0));
+
+            StatementId(0)
         } else {
             let id = StatementId(self.next_statement_id);
 
             self.next_statement_id += 1;
 
-            self.stream.write(protocol::Parse {
-                statement: id,
-                query,
-                param_types: &*args.types,
-            });
-
-            self.cache_statement.insert(query.into(), id);
+            if let Some(evicted) = self.cache_statement.insert(query, id) {
+                // The least recently used statement made room; [Close] frees it on the server
+                self.stream.write(protocol::Close::Statement(evicted));
+                self.forget_statement(evicted);
+            }
 
             id
-        }
+        };
+
+        self.stream.write(protocol::Parse {
+            statement: id,
+            query,
+            param_types: &*args.types,
+        });
+
+        id
+    }
+
+    pub(super) fn forget_statement(&mut self, statement: StatementId) {
+        self.cache_statement_columns.remove(&statement);
+        self.cache_statement_fields.remove(&statement);
     }
 
     pub(crate) fn write_describe(&mut self, d: protocol::Describe) {
@@ -64,7 +85,11 @@ impl PgConnection {
         self.stream.write(protocol::Sync);
     }
 
-    async fn wait_until_ready(&mut self) -> crate::Result<Postgres, ()> {
+    pub(crate) fn write_flush(&mut self) {
+        self.stream.write(protocol::Flush);
+    }
+
+    pub(super) async fn wait_until_ready(&mut self) -> crate::Result<Postgres, ()> {
         // depending on how the previous query finished we may need to continue
         // pulling messages from the stream until we receive a [ReadyForQuery] message
 
@@ -72,11 +97,29 @@ impl PgConnection {
         // the previous query
 
         if !self.is_ready {
+            // a portal that was fetched in chunks is only closed by [Sync], e.g., if its cursor
+            // was dropped before the last row
+            if self.needs_sync {
+                self.write_sync();
+                self.needs_sync = false;
+            }
+
+            // a message to finish the previous query may still be buffered, e.g., the
+            // [CopyFail] of a dropped [PgCopyIn]
+            self.stream.flush().await?;
+
             loop {
-                if let Message::ReadyForQuery = self.stream.receive().await? {
+                match self.stream.receive().await {
                     // we are now ready to go
-                    self.is_ready = true;
-                    break;
+                    Ok(Message::ReadyForQuery) => {
+                        self.is_ready = true;
+                        break;
+                    }
+
+                    // the error belongs to the previous query, which was abandoned
+                    Ok(_) | Err(crate::Error::Database(_)) => {}
+
+                    Err(error) => return Err(error),
                 }
             }
         }
@@ -95,6 +138,10 @@ impl PgConnection {
         query: &str,
         arguments: Option<PgArguments>,
     ) -> crate::Result<Postgres, Option<StatementId>> {
+        // the previous query is finished before anything of this one is written as it may
+        // need a [Sync] first
+        self.wait_until_ready().await?;
+
         let statement = if let Some(arguments) = arguments {
             // Check the statement cache for a statement ID that matches the given query
             // If it doesn't exist, we generate a new statement ID and write out [Parse] to the
@@ -128,10 +175,37 @@ impl PgConnection {
             None
         };
 
+        self.stream.flush().await?;
+        self.is_ready = false;
+
+        Ok(statement)
+    }
+
+    // Like [run] but [Execute] returns at most `chunk_size` rows; the portal is suspended after
+    // each chunk until the next is requested by [PgCursor::next]. [Flush] is used in place of
+    // [Sync] as that would close the portal; [Sync] is sent once the last row is returned.
+    pub(crate) async fn run_in_chunks(
+        &mut self,
+        query: &str,
+        arguments: PgArguments,
+        chunk_size: i32,
+    ) -> crate::Result<Postgres, StatementId> {
         self.wait_until_ready().await?;
 
+        let statement = self.write_prepare(query, &arguments);
+
+        self.write_bind("", statement, &arguments);
+
+        if !self.cache_statement_columns.contains_key(&statement) {
+            self.write_describe(protocol::Describe::Portal(""));
+        }
+
+        self.write_execute("", chunk_size);
+        self.write_flush();
+
         self.stream.flush().await?;
         self.is_ready = false;
+        self.needs_sync = true;
 
         Ok(statement)
     }
@@ -140,6 +214,26 @@ impl PgConnection {
         &'e mut self,
         query: &'q str,
     ) -> crate::Result<Postgres, Describe<Postgres>> {
+        let prepared = self.cache_statement.get_mut(query).is_some();
+        let result = self.describe_statement(query).await;
+
+        if result.is_err() && !prepared {
+            // A statement that failed to parse does not exist on the server; it must not
+            // be reused from the cache
+            if let Some(statement) = self.cache_statement.remove(query) {
+                self.forget_statement(statement);
+            }
+        }
+
+        result
+    }
+
+    async fn describe_statement(
+        &mut self,
+        query: &str,
+    ) -> crate::Result<Postgres, Describe<Postgres>> {
+        self.wait_until_ready().await?;
+
         self.is_ready = false;
 
         let statement = self.write_prepare(query, &Default::default());
@@ -151,7 +245,7 @@ impl PgConnection {
 
         let params = loop {
             match self.stream.receive().await? {
-                Message::ParseComplete => {}
+                Message::CloseComplete | Message::ParseComplete => {}
 
                 Message::ParameterDescription => {
                     break ParameterDescription::read(self.stream.buffer())?;
@@ -209,16 +303,35 @@ impl PgConnection {
         })
     }
 
-    async fn get_type_names(
+    // Type names are cached on the connection so each type is only looked up once
+    pub(super) async fn get_type_names(
         &mut self,
         ids: impl IntoIterator<Item = TypeId>,
     ) -> crate::Result<Postgres, HashMap<u32, SharedStr>> {
         let type_ids: HashSet<u32> = ids.into_iter().map(|id| id.0).collect::<HashSet<u32>>();
 
-        if type_ids.is_empty() {
-            return Ok(HashMap::new());
+        let missing: Vec<u32> = type_ids
+            .iter()
+            .copied()
+            .filter(|id| !self.cache_type_name.contains_key(id))
+            .collect();
+
+        if !missing.is_empty() {
+            let found = self.query_type_names(&missing).await?;
+
+            self.cache_type_name.extend(found);
         }
 
+        Ok(type_ids
+            .into_iter()
+            .filter_map(|id| Some((id, self.cache_type_name.get(&id)?.clone())))
+            .collect())
+    }
+
+    async fn query_type_names(
+        &mut self,
+        type_ids: &[u32],
+    ) -> crate::Result<Postgres, Vec<(u32, SharedStr)>> {
         // uppercase type names are easier to visually identify
         let mut query = "select types.type_id, UPPER(pg_type.typname) from (VALUES ".to_string();
         let mut args = PgArguments::default();
@@ -335,7 +448,8 @@ impl PgConnection {
 
         loop {
             match self.stream.receive().await? {
-                Message::ParseComplete
+                Message::CloseComplete
+                | Message::ParseComplete
                 | Message::BindComplete
                 | Message::NoData
                 | Message::EmptyQueryResponse
@@ -350,6 +464,14 @@ impl PgConnection {
                     rows += CommandComplete::read(self.stream.buffer())?.affected_rows;
                 }
 
+                Message::CopyInResponse => {
+                    reject_copy_in(self).await?;
+                }
+
+                Message::CopyOutResponse | Message::CopyData | Message::CopyDone => {
+                    // the output of a COPY TO STDOUT is discarded like rows are
+                }
+
                 Message::ReadyForQuery => {
                     // TODO: How should we handle an ERROR status form ReadyForQuery
                     let _ready = ReadyForQuery::read(self.stream.buffer())?;
@@ -382,9 +504,15 @@ impl Executor for super::PgConnection {
     {
         Box::pin(async move {
             let (query, arguments) = query.into_parts();
+            let num_arguments = arguments.as_ref().map_or(0, Arguments::len);
+            let _timer = QueryTimer::start(&self.query_logger, query, num_arguments);
+
+            let rows = async {
+                self.run(query, arguments).await?;
+                self.affected_rows().await
+            };
 
-            self.run(query, arguments).await?;
-            self.affected_rows().await
+            rows.await.map_err(|err| err.pg_err_attach_query(query))
         })
     }
 
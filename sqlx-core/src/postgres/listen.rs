@@ -62,8 +62,10 @@ impl PgListener {
 
     /// Starts listening for notifications on a channel.
     pub async fn listen(&mut self, channel: &str) -> crate::Result<Postgres, ()> {
+        self.connect_if_needed().await?;
+
         self.connection()
-            .execute(&*format!("LISTEN {}", ident(channel)))
+            .execute(&*build_listen_all_query(&[channel]))
             .await?;
 
         self.channels.push(channel.to_owned());
@@ -76,22 +78,29 @@ impl PgListener {
         &mut self,
         channels: impl IntoIterator<Item = &str>,
     ) -> crate::Result<Postgres, ()> {
-        let beg = self.channels.len();
-        self.channels.extend(channels.into_iter().map(|s| s.into()));
+        let channels: Vec<String> = channels.into_iter().map(|s| s.into()).collect();
+
+        if channels.is_empty() {
+            return Ok(());
+        }
+
+        self.connect_if_needed().await?;
 
-        self.connection
-            .as_mut()
-            .unwrap()
-            .execute(&*build_listen_all_query(&self.channels[beg..]))
+        self.connection()
+            .execute(&*build_listen_all_query(&channels))
             .await?;
 
+        self.channels.extend(channels);
+
         Ok(())
     }
 
     /// Stops listening for notifications on a channel.
     pub async fn unlisten(&mut self, channel: &str) -> crate::Result<Postgres, ()> {
+        self.connect_if_needed().await?;
+
         self.connection()
-            .execute(&*format!("UNLISTEN {}", ident(channel)))
+            .execute(&*format!(r#"UNLISTEN "{}""#, ident(channel)))
             .await?;
 
         if let Some(pos) = self.channels.iter().position(|s| s == channel) {
@@ -103,6 +112,8 @@ impl PgListener {
 
     /// Stops listening for notifications on all channels.
     pub async fn unlisten_all(&mut self) -> crate::Result<Postgres, ()> {
+        self.connect_if_needed().await?;
+
         self.connection().execute("UNLISTEN *").await?;
 
         self.channels.clear();
@@ -114,11 +125,16 @@ impl PgListener {
     async fn connect_if_needed(&mut self) -> crate::Result<Postgres, ()> {
         if let None = self.connection {
             let mut connection = self.pool.acquire().await?;
-            connection.stream.notifications = self.buffer_tx.take();
 
-            connection
-                .execute(&*build_listen_all_query(&self.channels))
-                .await?;
+            // Re-subscribe to the channels of the lost connection
+            if !self.channels.is_empty() {
+                connection
+                    .execute(&*build_listen_all_query(&self.channels))
+                    .await?;
+            }
+
+            // Only now so the buffer is kept for the next attempt if the above fails
+            connection.stream.notifications = self.buffer_tx.take();
 
             self.connection = Some(connection);
         }
@@ -162,7 +178,7 @@ impl PgListener {
 
                 // The connection is dead, ensure that it is dropped,
                 // update self state, and loop to try again.
-                Err(crate::Error::Io(err)) if err.kind() == io::ErrorKind::ConnectionAborted => {
+                Err(crate::Error::Io(err)) if is_connection_lost(&err) => {
                     self.buffer_tx = self.connection().stream.notifications.take();
                     self.connection = None;
                 }
@@ -267,6 +283,17 @@ impl Debug for PgNotification<'_> {
     }
 }
 
+fn is_connection_lost(err: &io::Error) -> bool {
+    match err.kind() {
+        io::ErrorKind::ConnectionAborted
+        | io::ErrorKind::ConnectionReset
+        | io::ErrorKind::BrokenPipe
+        | io::ErrorKind::UnexpectedEof => true,
+
+        _ => false,
+    }
+}
+
 fn ident(mut name: &str) -> String {
     // If the input string contains a NUL byte, we should truncate the
     // identifier.
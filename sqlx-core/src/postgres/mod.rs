@@ -1,24 +1,36 @@
 //! **Postgres** database and connection types.
 
 pub use arguments::PgArguments;
-pub use connection::PgConnection;
+pub use cancel::PgCancelToken;
+pub use connection::{PgConnection, PgHostsError};
+pub use copy::PgCopyIn;
 pub use cursor::PgCursor;
 pub use database::Postgres;
 pub use error::PgError;
 pub use listen::{PgListener, PgNotification};
-pub use row::{PgRow, PgValue};
+pub use notice::PgNotice;
+pub use pipeline::PgPipeline;
+pub use protocol::PgSeverity;
+pub use row::{PgColumn, PgRow, PgValue};
+pub use sql_state::PgSqlState;
 pub use types::PgTypeInfo;
 
 mod arguments;
+mod cancel;
 mod connection;
+mod copy;
 mod cursor;
 mod database;
 mod error;
 mod executor;
 mod listen;
+mod notice;
+mod pgpass;
+mod pipeline;
 mod protocol;
 mod row;
 mod sasl;
+mod sql_state;
 mod stream;
 mod tls;
 pub mod types;
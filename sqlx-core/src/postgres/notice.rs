@@ -0,0 +1,70 @@
+use std::fmt::{self, Debug};
+
+use crate::postgres::protocol::Response;
+use crate::postgres::{PgConnection, PgSeverity};
+
+/// A notice or warning from Postgres, e.g., from `RAISE NOTICE` in a function.
+///
+/// Received with a handler set by [`PgConnection::on_notice`].
+pub struct PgNotice(pub(super) Response);
+
+impl PgConnection {
+    /// Sets a handler that is called with each notice or warning received by this connection,
+    /// replacing any previous handler.
+    ///
+    /// Notices are discarded when there is no handler.
+    ///
+    /// ```rust,ignore
+    /// conn.on_notice(|notice| println!("{}: {}", notice.severity(), notice.message()));
+    /// ```
+    pub fn on_notice<F>(&mut self, handler: F)
+    where
+        F: Fn(&PgNotice) + Send + Sync + 'static,
+    {
+        self.stream.notice_handler = Some(Box::new(handler));
+    }
+}
+
+impl PgNotice {
+    /// The severity, e.g., [`PgSeverity::Notice`] or [`PgSeverity::Warning`].
+    #[inline]
+    pub fn severity(&self) -> PgSeverity {
+        self.0.severity
+    }
+
+    /// The SQLSTATE code, e.g., `01000` for a warning.
+    #[inline]
+    pub fn code(&self) -> &str {
+        &self.0.code
+    }
+
+    /// The primary message.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.0.message
+    }
+
+    /// An optional secondary message with more detail.
+    #[inline]
+    pub fn detail(&self) -> Option<&str> {
+        self.0.detail.as_deref()
+    }
+
+    /// An optional suggestion of what to do about it.
+    #[inline]
+    pub fn hint(&self) -> Option<&str> {
+        self.0.hint.as_deref()
+    }
+}
+
+impl Debug for PgNotice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgNotice")
+            .field("severity", &self.severity())
+            .field("code", &self.code())
+            .field("message", &self.message())
+            .field("detail", &self.detail())
+            .field("hint", &self.hint())
+            .finish()
+    }
+}
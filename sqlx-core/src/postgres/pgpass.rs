@@ -0,0 +1,236 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::url::Url;
+
+// Looks up the password of a connection without one in the URL in the password file, as
+// `libpq` does; the file is `passfile` from the URL, `PGPASSFILE` or `~/.pgpass`
+//
+// https://www.postgresql.org/docs/12/libpq-pgpass.html
+pub(super) fn password(url: &Url, username: &str, database: &str) -> Option<String> {
+    let path = path(url)?;
+    let contents = read(&path)?;
+
+    // a connection over a Unix domain socket matches `localhost`
+    let host = if super::stream::socket_path(url).is_some() {
+        "localhost"
+    } else {
+        url.host()
+    };
+
+    find(&contents, host, url.port(5432), database, username)
+}
+
+fn path(url: &Url) -> Option<PathBuf> {
+    if let Some(path) = url
+        .param("passfile")
+        .or_else(|| env::var("PGPASSFILE").ok().map(Into::into))
+    {
+        return Some(PathBuf::from(&*path));
+    }
+
+    if cfg!(windows) {
+        let app_data = env::var("APPDATA").ok()?;
+
+        Some(Path::new(&app_data).join("postgresql").join("pgpass.conf"))
+    } else {
+        let home = env::var("HOME").ok()?;
+
+        Some(Path::new(&home).join(".pgpass"))
+    }
+}
+
+// A file that is missing or cannot be read is ignored, as is one that others can read on Unix
+fn read(path: &Path) -> Option<String> {
+    let metadata = fs::metadata(path).ok()?;
+
+    if !metadata.is_file() {
+        log::warn!("password file {:?} is not a plain file", path);
+        return None;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        if metadata.permissions().mode() & 0o077 != 0 {
+            log::warn!(
+                "password file {:?} has group or world access; permissions should be u=rw (0600) or less",
+                path
+            );
+
+            return None;
+        }
+    }
+
+    fs::read_to_string(path).ok()
+}
+
+// The password of the first line that matches; see `parse_line`
+fn find(contents: &str, host: &str, port: u16, database: &str, username: &str) -> Option<String> {
+    let port = port.to_string();
+
+    contents.lines().find_map(|line| {
+        let [line_host, line_port, line_database, line_username, password] = parse_line(line)?;
+
+        let matches = line_host.matches(host)
+            && line_port.matches(&port)
+            && line_database.matches(database)
+            && line_username.matches(username);
+
+        if matches {
+            Some(password.value)
+        } else {
+            None
+        }
+    })
+}
+
+struct Field {
+    value: String,
+    wildcard: bool,
+}
+
+impl Field {
+    fn matches(&self, value: &str) -> bool {
+        self.wildcard || self.value == value
+    }
+}
+
+// `hostname:port:database:username:password`; any of the first four can be `*` to match anything
+// and `\` escapes a `:` or `\` in a field. An empty line, a comment starting with `#` or a line
+// with fewer fields is skipped; anything after the password is ignored.
+fn parse_line(line: &str) -> Option<[Field; 5]> {
+    let line = line.trim_end_matches('\r');
+
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let mut fields = Vec::with_capacity(5);
+    let mut chars = line.chars();
+
+    while fields.len() < 5 {
+        let mut value = String::new();
+        let mut escaped = false;
+        let mut end = true;
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => {
+                    escaped = true;
+                    value.extend(chars.next());
+                }
+
+                ':' => {
+                    end = false;
+                    break;
+                }
+
+                ch => value.push(ch),
+            }
+        }
+
+        let wildcard = !escaped && value == "*";
+
+        fields.push(Field { value, wildcard });
+
+        if end {
+            break;
+        }
+    }
+
+    if fields.len() < 5 {
+        return None;
+    }
+
+    let mut fields = fields.into_iter();
+
+    Some([
+        fields.next()?,
+        fields.next()?,
+        fields.next()?,
+        fields.next()?,
+        fields.next()?,
+    ])
+}
+
+#[test]
+fn test_find() {
+    let contents = "\
+# hostname:port:database:username:password
+db.example.com:5432:app:alice:first
+db.example.com:5432:app:*:second
+*:5433:*:bob:third
+*:*:*:carol:fourth
+too:few:fields
+";
+
+    let find = |host, port, database, username| find(contents, host, port, database, username);
+
+    assert_eq!(
+        find("db.example.com", 5432, "app", "alice").as_deref(),
+        Some("first")
+    );
+
+    // the first line that matches is used
+    assert_eq!(
+        find("db.example.com", 5432, "app", "bob").as_deref(),
+        Some("second")
+    );
+
+    assert_eq!(
+        find("localhost", 5433, "other", "bob").as_deref(),
+        Some("third")
+    );
+
+    assert_eq!(
+        find("localhost", 1, "postgres", "carol").as_deref(),
+        Some("fourth")
+    );
+
+    assert_eq!(find("db.example.com", 5433, "app", "alice"), None);
+    assert_eq!(find("localhost", 5432, "other", "bob"), None);
+    assert_eq!(find("too", 0, "few", "fields"), None);
+}
+
+#[test]
+fn test_find_with_escapes() {
+    let contents = "\
+\\*:5432:app:alice:not a wildcard
+[\\:\\:1]:5432:app:alice:ipv6
+localhost:5432:app:alice:pass\\:word\\\\:ignored\r
+";
+
+    let find = |host, username| find(contents, host, 5432, "app", username);
+
+    assert_eq!(find("*", "alice").as_deref(), Some("not a wildcard"));
+    assert_eq!(find("[::1]", "alice").as_deref(), Some("ipv6"));
+    assert_eq!(find("localhost", "alice").as_deref(), Some("pass:word\\"));
+    assert_eq!(find("127.0.0.1", "alice"), None);
+}
+
+#[test]
+fn test_read() {
+    let path = env::temp_dir().join(format!("sqlx-pgpass-{}", std::process::id()));
+
+    fs::write(&path, "*:*:*:*:secret\n").unwrap();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        // others can read the file
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        assert_eq!(read(&path), None);
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+    }
+
+    assert_eq!(read(&path).as_deref(), Some("*:*:*:*:secret\n"));
+
+    let _ = fs::remove_file(&path);
+
+    assert_eq!(read(&path), None);
+}
@@ -0,0 +1,150 @@
+use crate::executor::Execute;
+use crate::postgres::copy::reject_copy_in;
+use crate::postgres::protocol::{CommandComplete, Message, ReadyForQuery};
+use crate::postgres::{PgArguments, PgConnection, Postgres};
+
+impl PgConnection {
+    /// Starts a batch of queries that are sent to the server together with [`PgPipeline`].
+    ///
+    /// ```rust,ignore
+    /// let rows = conn
+    ///     .pipeline()
+    ///     .push(sqlx::query("INSERT INTO users (name) VALUES ($1)").bind("Alice"))
+    ///     .push(sqlx::query("INSERT INTO users (name) VALUES ($1)").bind("Bob"))
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// assert_eq!(rows, [1, 1]);
+    /// ```
+    pub fn pipeline<'q>(&mut self) -> PgPipeline<'_, 'q> {
+        PgPipeline {
+            conn: self,
+            queries: Vec::new(),
+        }
+    }
+}
+
+/// A batch of queries that is sent to the server in a single round-trip, started by
+/// [`PgConnection::pipeline`].
+///
+/// The queries run in order in an implicit transaction. If one of them fails, the rest are
+/// skipped by the server and the changes of those before it are rolled back.
+pub struct PgPipeline<'c, 'q> {
+    conn: &'c mut PgConnection,
+    queries: Vec<(&'q str, PgArguments)>,
+}
+
+impl<'q> PgPipeline<'_, 'q> {
+    /// Adds a query to the end of the batch.
+    ///
+    /// The rows that are returned by the query are discarded.
+    pub fn push<E>(&mut self, query: E) -> &mut Self
+    where
+        E: Execute<'q, Postgres>,
+    {
+        let (query, arguments) = query.into_parts();
+
+        self.queries.push((query, arguments.unwrap_or_default()));
+        self
+    }
+
+    /// Sends the queries and returns the number of rows affected by each, in order.
+    ///
+    /// The error of the first query that fails is returned.
+    pub async fn execute(&mut self) -> crate::Result<Postgres, Vec<u64>> {
+        let conn = &mut *self.conn;
+        let queries = std::mem::take(&mut self.queries);
+
+        conn.wait_until_ready().await?;
+
+        // Whether each query prepares a new statement
+        let mut prepares = Vec::with_capacity(queries.len());
+
+        // https://www.postgresql.org/docs/12/protocol-flow.html#PROTOCOL-FLOW-EXT-QUERY
+        for (query, arguments) in &queries {
+            prepares.push(conn.cache_statement.get_mut(query).is_none());
+
+            let statement = conn.write_prepare(query, arguments);
+
+            conn.write_bind("", statement, arguments);
+            conn.write_execute("", 0);
+        }
+
+        // A single [Sync] for all of the queries; the server processes the messages in order
+        // and responds to all of them in the same round-trip
+        conn.write_sync();
+
+        conn.stream.flush().await?;
+        conn.is_ready = false;
+
+        let mut rows = Vec::with_capacity(queries.len());
+        let mut parsed = false;
+
+        loop {
+            let result = match conn.stream.receive().await {
+                Ok(Message::ParseComplete) => {
+                    parsed = true;
+                    Ok(())
+                }
+
+                Ok(Message::CloseComplete)
+                | Ok(Message::BindComplete)
+                | Ok(Message::DataRow)
+                | Ok(Message::CopyOutResponse)
+                | Ok(Message::CopyData)
+                | Ok(Message::CopyDone) => Ok(()),
+
+                Ok(Message::CommandComplete) => {
+                    CommandComplete::read(conn.stream.buffer()).map(|complete| {
+                        rows.push(complete.affected_rows);
+                        parsed = false;
+                    })
+                }
+
+                Ok(Message::EmptyQueryResponse) => {
+                    rows.push(0);
+                    parsed = false;
+                    Ok(())
+                }
+
+                Ok(Message::CopyInResponse) => reject_copy_in(conn).await,
+
+                Ok(Message::ReadyForQuery) => {
+                    // a query that failed was already returned by its [ErrorResponse], which
+                    // comes before this; the transaction status has nothing more to report
+                    ReadyForQuery::read(conn.stream.buffer())?;
+
+                    conn.is_ready = true;
+                    break;
+                }
+
+                Ok(message) => {
+                    Err(protocol_err!("pipeline: unexpected message: {:?}", message).into())
+                }
+
+                Err(error) => Err(error),
+            };
+
+            if let Err(error) = result {
+                let failed = rows.len();
+
+                // The server skips the rest of the messages until [Sync]; statements that
+                // were not prepared must not be used from the cache
+                for (index, ((query, _), &prepare)) in queries.iter().zip(&prepares).enumerate() {
+                    if prepare && (index > failed || (index == failed && !parsed)) {
+                        if let Some(statement) = conn.cache_statement.remove(query) {
+                            conn.forget_statement(statement);
+                        }
+                    }
+                }
+
+                return Err(match queries.get(failed) {
+                    Some((query, _)) => error.pg_err_attach_query(query),
+                    None => error,
+                });
+            }
+        }
+
+        Ok(rows)
+    }
+}
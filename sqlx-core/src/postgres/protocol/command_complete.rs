@@ -30,7 +30,9 @@ mod tests {
     use super::CommandComplete;
 
     const COMMAND_COMPLETE_INSERT: &[u8] = b"INSERT 0 1\0";
+    const COMMAND_COMPLETE_INSERT_MANY: &[u8] = b"INSERT 0 5\0";
     const COMMAND_COMPLETE_UPDATE: &[u8] = b"UPDATE 512\0";
+    const COMMAND_COMPLETE_DELETE: &[u8] = b"DELETE 3\0";
     const COMMAND_COMPLETE_CREATE_TABLE: &[u8] = b"CREATE TABLE\0";
     const COMMAND_COMPLETE_BEGIN: &[u8] = b"BEGIN\0";
 
@@ -41,6 +43,13 @@ mod tests {
         assert_eq!(message.affected_rows, 1);
     }
 
+    #[test]
+    fn it_reads_command_complete_for_insert_of_many_rows() {
+        let message = CommandComplete::read(COMMAND_COMPLETE_INSERT_MANY).unwrap();
+
+        assert_eq!(message.affected_rows, 5);
+    }
+
     #[test]
     fn it_reads_command_complete_for_update() {
         let message = CommandComplete::read(COMMAND_COMPLETE_UPDATE).unwrap();
@@ -48,6 +57,13 @@ mod tests {
         assert_eq!(message.affected_rows, 512);
     }
 
+    #[test]
+    fn it_reads_command_complete_for_delete() {
+        let message = CommandComplete::read(COMMAND_COMPLETE_DELETE).unwrap();
+
+        assert_eq!(message.affected_rows, 3);
+    }
+
     #[test]
     fn it_reads_command_complete_for_begin() {
         let message = CommandComplete::read(COMMAND_COMPLETE_BEGIN).unwrap();
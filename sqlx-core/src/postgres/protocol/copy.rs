@@ -0,0 +1,58 @@
+use crate::io::BufMut;
+use crate::postgres::protocol::Write;
+use byteorder::NetworkEndian;
+
+// https://www.postgresql.org/docs/12/protocol-flow.html#PROTOCOL-COPY
+
+/// A chunk of the data of a `COPY`; it need not line up with rows.
+pub struct CopyData<'a>(pub &'a [u8]);
+
+impl Write for CopyData<'_> {
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(b'd');
+
+        // len + data
+        buf.put_i32::<NetworkEndian>((4 + self.0.len()) as i32);
+        buf.extend_from_slice(self.0);
+    }
+}
+
+/// The data of a `COPY FROM STDIN` is complete.
+pub struct CopyDone;
+
+impl Write for CopyDone {
+    #[inline]
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(b'c');
+        buf.put_i32::<NetworkEndian>(4);
+    }
+}
+
+/// Aborts a `COPY FROM STDIN` with an error message.
+pub struct CopyFail<'a>(pub &'a str);
+
+impl Write for CopyFail<'_> {
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(b'f');
+
+        // len + message + nul
+        buf.put_i32::<NetworkEndian>((4 + self.0.len() + 1) as i32);
+        buf.put_str_nul(self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CopyData, CopyDone, CopyFail, Write};
+
+    #[test]
+    fn it_writes_copy_messages() {
+        let mut buf = Vec::new();
+
+        CopyData(b"1\tfoo\n").write(&mut buf);
+        CopyDone.write(&mut buf);
+        CopyFail("no").write(&mut buf);
+
+        assert_eq!(buf, &b"d\0\0\0\x0a1\tfoo\nc\0\0\0\x04f\0\0\0\x07no\0"[..]);
+    }
+}
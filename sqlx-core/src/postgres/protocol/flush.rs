@@ -0,0 +1,15 @@
+use crate::io::BufMut;
+use crate::postgres::protocol::Write;
+use byteorder::NetworkEndian;
+
+// Asks the server to send the responses to the messages so far without ending the
+// implicit transaction as [Sync] does
+pub struct Flush;
+
+impl Write for Flush {
+    #[inline]
+    fn write(&self, buf: &mut Vec<u8>) {
+        buf.push(b'H');
+        buf.put_i32::<NetworkEndian>(4);
+    }
+}
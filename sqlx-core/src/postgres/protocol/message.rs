@@ -9,6 +9,11 @@ pub enum Message {
     BindComplete,
     CloseComplete,
     CommandComplete,
+    CopyBothResponse,
+    CopyData,
+    CopyDone,
+    CopyInResponse,
+    CopyOutResponse,
     DataRow,
     EmptyQueryResponse,
     ErrorResponse,
@@ -46,6 +51,11 @@ impl TryFrom<u8> for Message {
             b't' => Message::ParameterDescription,
             b'T' => Message::RowDescription,
             b'I' => Message::EmptyQueryResponse,
+            b'G' => Message::CopyInResponse,
+            b'H' => Message::CopyOutResponse,
+            b'W' => Message::CopyBothResponse,
+            b'd' => Message::CopyData,
+            b'c' => Message::CopyDone,
 
             id => {
                 return Err(protocol_err!("unknown message: {:?}", id as char).into());
@@ -5,13 +5,18 @@
 mod type_format;
 mod type_id;
 
+pub use response::PgSeverity;
 pub use type_format::TypeFormat;
 pub use type_id::TypeId;
 
 // REQUESTS
 mod bind;
+mod cancel_request;
+mod close;
+mod copy;
 mod describe;
 mod execute;
+mod flush;
 mod parse;
 mod password_message;
 mod query;
@@ -24,8 +29,12 @@ mod sync;
 mod terminate;
 
 pub(crate) use bind::Bind;
+pub(crate) use cancel_request::CancelRequest;
+pub(crate) use close::Close;
+pub(crate) use copy::{CopyData, CopyDone, CopyFail};
 pub(crate) use describe::Describe;
 pub(crate) use execute::Execute;
+pub(crate) use flush::Flush;
 pub(crate) use parse::Parse;
 pub(crate) use password_message::PasswordMessage;
 pub(crate) use query::Query;
@@ -44,6 +53,7 @@ mod command_complete;
 mod data_row;
 mod notification_response;
 mod parameter_description;
+mod parameter_status;
 mod ready_for_query;
 mod response;
 mod row_description;
@@ -59,6 +69,7 @@ pub(crate) use data_row::DataRow;
 pub(crate) use message::Message;
 pub(crate) use notification_response::NotificationResponse;
 pub(crate) use parameter_description::ParameterDescription;
+pub(crate) use parameter_status::ParameterStatus;
 pub(crate) use ready_for_query::ReadyForQuery;
 pub(crate) use response::Response;
 pub(crate) use row_description::{Field, RowDescription};
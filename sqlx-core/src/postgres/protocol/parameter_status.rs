@@ -0,0 +1,32 @@
+use crate::io::Buf;
+use crate::postgres::database::Postgres;
+
+#[derive(Debug)]
+pub(crate) struct ParameterStatus<'c> {
+    pub(crate) name: &'c str,
+    pub(crate) value: &'c str,
+}
+
+impl<'c> ParameterStatus<'c> {
+    pub(crate) fn read(mut buf: &'c [u8]) -> crate::Result<Postgres, Self> {
+        let name = buf.get_str_nul()?;
+        let value = buf.get_str_nul()?;
+
+        Ok(Self { name, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParameterStatus;
+
+    const PARAMETER_STATUS: &[u8] = b"server_version\012.2\0";
+
+    #[test]
+    fn it_decodes_parameter_status() {
+        let message = ParameterStatus::read(PARAMETER_STATUS).unwrap();
+
+        assert_eq!(message.name, "server_version");
+        assert_eq!(message.value, "12.2");
+    }
+}
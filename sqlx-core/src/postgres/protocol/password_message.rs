@@ -58,6 +58,7 @@ mod tests {
 
     const PASSWORD_CLEAR: &[u8] = b"p\0\0\0\rpassword\0";
     const PASSWORD_MD5: &[u8] = b"p\0\0\0(md53e2c9d99d49b201ef867a36f3f9ed62c\0";
+    const PASSWORD_MD5_POSTGRES: &[u8] = b"p\0\0\0(md5c263677bc939c77f72f3bed0c47f9f18\0";
 
     #[test]
     fn it_writes_password_clear() {
@@ -82,4 +83,18 @@ mod tests {
 
         assert_eq!(buf, PASSWORD_MD5);
     }
+
+    #[test]
+    fn it_writes_password_md5_with_a_salt_of_any_bytes() {
+        let mut buf = Vec::new();
+        let m = PasswordMessage::Md5 {
+            password: "pencil",
+            user: "postgres",
+            salt: [0x2a, 0x01, 0xff, 0x00],
+        };
+
+        m.write(&mut buf);
+
+        assert_eq!(buf, PASSWORD_MD5_POSTGRES);
+    }
 }
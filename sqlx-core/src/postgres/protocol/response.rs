@@ -1,10 +1,15 @@
 use crate::io::Buf;
+use std::fmt::{self, Display};
 use std::str::{self, FromStr};
 
 use crate::postgres::database::Postgres;
 
-#[derive(Debug, Copy, Clone)]
-pub(crate) enum Severity {
+/// The severity of an error or notice from **Postgres**.
+///
+/// This is read from the field that is never localized if the server sends it (9.6 and later)
+/// and otherwise from the localized one.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PgSeverity {
     Panic,
     Fatal,
     Error,
@@ -15,28 +20,49 @@ pub(crate) enum Severity {
     Log,
 }
 
-impl Severity {
-    pub(crate) fn is_error(self) -> bool {
+impl PgSeverity {
+    /// Returns `true` for `PANIC`, `FATAL` and `ERROR`, which abort the query.
+    pub fn is_error(self) -> bool {
         match self {
-            Severity::Panic | Severity::Fatal | Severity::Error => true,
+            PgSeverity::Panic | PgSeverity::Fatal | PgSeverity::Error => true,
             _ => false,
         }
     }
+
+    /// The name of the severity as sent by the server, e.g., `WARNING`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PgSeverity::Panic => "PANIC",
+            PgSeverity::Fatal => "FATAL",
+            PgSeverity::Error => "ERROR",
+            PgSeverity::Warning => "WARNING",
+            PgSeverity::Notice => "NOTICE",
+            PgSeverity::Debug => "DEBUG",
+            PgSeverity::Info => "INFO",
+            PgSeverity::Log => "LOG",
+        }
+    }
 }
 
-impl FromStr for Severity {
+impl Display for PgSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl FromStr for PgSeverity {
     type Err = crate::Error<Postgres>;
 
     fn from_str(s: &str) -> crate::Result<Postgres, Self> {
         Ok(match s {
-            "PANIC" => Severity::Panic,
-            "FATAL" => Severity::Fatal,
-            "ERROR" => Severity::Error,
-            "WARNING" => Severity::Warning,
-            "NOTICE" => Severity::Notice,
-            "DEBUG" => Severity::Debug,
-            "INFO" => Severity::Info,
-            "LOG" => Severity::Log,
+            "PANIC" => PgSeverity::Panic,
+            "FATAL" => PgSeverity::Fatal,
+            "ERROR" => PgSeverity::Error,
+            "WARNING" => PgSeverity::Warning,
+            "NOTICE" => PgSeverity::Notice,
+            "DEBUG" => PgSeverity::Debug,
+            "INFO" => PgSeverity::Info,
+            "LOG" => PgSeverity::Log,
 
             _ => {
                 return Err(protocol_err!("unexpected response severity: {}", s).into());
@@ -47,7 +73,7 @@ impl FromStr for Severity {
 
 #[derive(Debug)]
 pub(crate) struct Response {
-    pub(crate) severity: Severity,
+    pub(crate) severity: PgSeverity,
     pub(crate) code: Box<str>,
     pub(crate) message: Box<str>,
     pub(crate) detail: Option<Box<str>>,
@@ -71,7 +97,7 @@ impl Response {
         let mut code = None::<Box<str>>;
         let mut message = None::<Box<str>>;
         let mut severity = None::<Box<str>>;
-        let mut severity_non_local = None::<Severity>;
+        let mut severity_non_local = None::<PgSeverity>;
         let mut detail = None::<Box<str>>;
         let mut hint = None::<Box<str>>;
         let mut position = None::<usize>;
@@ -141,7 +167,7 @@ impl Response {
                     internal_query = Some(field_value.into());
                 }
 
-                b'w' => {
+                b'W' => {
                     where_ = Some(field_value.into());
                 }
 
@@ -181,14 +207,8 @@ impl Response {
                     routine = Some(field_value.into());
                 }
 
-                _ => {
-                    // TODO: Should we return these somehow, like in a map?
-                    return Err(protocol_err!(
-                        "received unknown field in Response: {}",
-                        field_type
-                    )
-                    .into());
-                }
+                // frontends should silently ignore fields of unrecognized type
+                _ => {}
             }
         }
 
@@ -227,17 +247,40 @@ impl Response {
 
 #[cfg(test)]
 mod tests {
-    use super::{Response, Severity};
+    use super::{PgSeverity, Response};
     use matches::assert_matches;
 
     const RESPONSE: &[u8] = b"SNOTICE\0VNOTICE\0C42710\0Mextension \"uuid-ossp\" already exists, \
           skipping\0Fextension.c\0L1656\0RCreateExtension\0\0";
 
+    #[test]
+    fn it_decodes_where_and_ignores_unknown_fields() {
+        let message =
+            Response::read(b"SERROR\0C22P02\0Minvalid input\0WCOPY t, line 1\0Zfuture\0\0")
+                .unwrap();
+
+        assert_eq!(&*message.code, "22P02");
+        assert_eq!(message.where_.as_deref(), Some("COPY t, line 1"));
+    }
+
+    #[test]
+    fn it_prefers_the_non_localized_severity() {
+        let message = Response::read(b"SREMARQUE\0VNOTICE\0C00000\0Mbonjour\0\0").unwrap();
+        assert_eq!(message.severity, PgSeverity::Notice);
+
+        // before 9.6 there is only the localized field
+        let message = Response::read(b"SWARNING\0C01000\0Mcareful\0\0").unwrap();
+        assert_eq!(message.severity, PgSeverity::Warning);
+        assert!(!message.severity.is_error());
+
+        assert!(Response::read(b"SREMARQUE\0C00000\0Mbonjour\0\0").is_err());
+    }
+
     #[test]
     fn it_decodes_response() {
         let message = Response::read(RESPONSE).unwrap();
 
-        assert_matches!(message.severity, Severity::Notice);
+        assert_matches!(message.severity, PgSeverity::Notice);
         assert_eq!(&*message.code, "42710");
         assert_eq!(&*message.file.unwrap(), "extension.c");
         assert_eq!(message.line, Some(1656));
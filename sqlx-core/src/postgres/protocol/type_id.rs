@@ -9,25 +9,38 @@ pub struct TypeId(pub(crate) u32);
 
 #[allow(dead_code)]
 impl TypeId {
+    // Sent as the type of a bind parameter to have Postgres infer it
+    pub(crate) const UNSPECIFIED: TypeId = TypeId(0);
+
     // Scalar
 
     pub(crate) const BOOL: TypeId = TypeId(16);
 
+    pub(crate) const CHAR: TypeId = TypeId(18);
+
     pub(crate) const INT2: TypeId = TypeId(21);
     pub(crate) const INT4: TypeId = TypeId(23);
     pub(crate) const INT8: TypeId = TypeId(20);
 
+    pub(crate) const OID: TypeId = TypeId(26);
+
     pub(crate) const FLOAT4: TypeId = TypeId(700);
     pub(crate) const FLOAT8: TypeId = TypeId(701);
 
     pub(crate) const NUMERIC: TypeId = TypeId(1700);
+    pub(crate) const MONEY: TypeId = TypeId(790);
 
     pub(crate) const TEXT: TypeId = TypeId(25);
+    pub(crate) const VARCHAR: TypeId = TypeId(1043);
+    pub(crate) const BPCHAR: TypeId = TypeId(1042);
+    pub(crate) const NAME: TypeId = TypeId(19);
+    pub(crate) const XML: TypeId = TypeId(142);
 
     pub(crate) const DATE: TypeId = TypeId(1082);
     pub(crate) const TIME: TypeId = TypeId(1083);
     pub(crate) const TIMESTAMP: TypeId = TypeId(1114);
     pub(crate) const TIMESTAMPTZ: TypeId = TypeId(1184);
+    pub(crate) const INTERVAL: TypeId = TypeId(1186);
 
     pub(crate) const BYTEA: TypeId = TypeId(17);
 
@@ -36,25 +49,51 @@ impl TypeId {
     pub(crate) const CIDR: TypeId = TypeId(650);
     pub(crate) const INET: TypeId = TypeId(869);
 
+    pub(crate) const MACADDR: TypeId = TypeId(829);
+    pub(crate) const MACADDR8: TypeId = TypeId(774);
+
+    pub(crate) const BIT: TypeId = TypeId(1560);
+    pub(crate) const VARBIT: TypeId = TypeId(1562);
+
+    pub(crate) const PG_LSN: TypeId = TypeId(3220);
+
+    pub(crate) const TSVECTOR: TypeId = TypeId(3614);
+    pub(crate) const TSQUERY: TypeId = TypeId(3615);
+
+    pub(crate) const POINT: TypeId = TypeId(600);
+
+    pub(crate) const INT4RANGE: TypeId = TypeId(3904);
+    pub(crate) const INT8RANGE: TypeId = TypeId(3926);
+
     // Arrays
 
     pub(crate) const ARRAY_BOOL: TypeId = TypeId(1000);
 
+    pub(crate) const ARRAY_CHAR: TypeId = TypeId(1002);
+
     pub(crate) const ARRAY_INT2: TypeId = TypeId(1005);
     pub(crate) const ARRAY_INT4: TypeId = TypeId(1007);
     pub(crate) const ARRAY_INT8: TypeId = TypeId(1016);
 
+    pub(crate) const ARRAY_OID: TypeId = TypeId(1028);
+
     pub(crate) const ARRAY_FLOAT4: TypeId = TypeId(1021);
     pub(crate) const ARRAY_FLOAT8: TypeId = TypeId(1022);
 
     pub(crate) const ARRAY_TEXT: TypeId = TypeId(1009);
+    pub(crate) const ARRAY_VARCHAR: TypeId = TypeId(1015);
+    pub(crate) const ARRAY_BPCHAR: TypeId = TypeId(1014);
+    pub(crate) const ARRAY_NAME: TypeId = TypeId(1003);
+    pub(crate) const ARRAY_XML: TypeId = TypeId(143);
 
-    pub(crate) const ARRAY_NUMERIC: TypeId = TypeId(1700);
+    pub(crate) const ARRAY_NUMERIC: TypeId = TypeId(1231);
+    pub(crate) const ARRAY_MONEY: TypeId = TypeId(791);
 
     pub(crate) const ARRAY_DATE: TypeId = TypeId(1182);
     pub(crate) const ARRAY_TIME: TypeId = TypeId(1183);
     pub(crate) const ARRAY_TIMESTAMP: TypeId = TypeId(1115);
     pub(crate) const ARRAY_TIMESTAMPTZ: TypeId = TypeId(1185);
+    pub(crate) const ARRAY_INTERVAL: TypeId = TypeId(1187);
 
     pub(crate) const ARRAY_BYTEA: TypeId = TypeId(1001);
 
@@ -63,8 +102,97 @@ impl TypeId {
     pub(crate) const ARRAY_CIDR: TypeId = TypeId(651);
     pub(crate) const ARRAY_INET: TypeId = TypeId(1041);
 
+    pub(crate) const ARRAY_MACADDR: TypeId = TypeId(1040);
+    pub(crate) const ARRAY_MACADDR8: TypeId = TypeId(775);
+
+    pub(crate) const ARRAY_BIT: TypeId = TypeId(1561);
+    pub(crate) const ARRAY_VARBIT: TypeId = TypeId(1563);
+
+    pub(crate) const ARRAY_PG_LSN: TypeId = TypeId(3221);
+
+    pub(crate) const ARRAY_TSVECTOR: TypeId = TypeId(3643);
+    pub(crate) const ARRAY_TSQUERY: TypeId = TypeId(3645);
+
+    pub(crate) const ARRAY_POINT: TypeId = TypeId(1017);
+
     // JSON
 
     pub(crate) const JSON: TypeId = TypeId(114);
     pub(crate) const JSONB: TypeId = TypeId(3802);
+
+    pub(crate) const ARRAY_JSON: TypeId = TypeId(199);
+    pub(crate) const ARRAY_JSONB: TypeId = TypeId(3807);
+}
+
+// The built-in array types and the types of their elements
+const ARRAY_ELEMENTS: &[(TypeId, TypeId)] = &[
+    (TypeId::ARRAY_BOOL, TypeId::BOOL),
+    (TypeId::ARRAY_CHAR, TypeId::CHAR),
+    (TypeId::ARRAY_INT2, TypeId::INT2),
+    (TypeId::ARRAY_INT4, TypeId::INT4),
+    (TypeId::ARRAY_INT8, TypeId::INT8),
+    (TypeId::ARRAY_OID, TypeId::OID),
+    (TypeId::ARRAY_FLOAT4, TypeId::FLOAT4),
+    (TypeId::ARRAY_FLOAT8, TypeId::FLOAT8),
+    (TypeId::ARRAY_TEXT, TypeId::TEXT),
+    (TypeId::ARRAY_VARCHAR, TypeId::VARCHAR),
+    (TypeId::ARRAY_BPCHAR, TypeId::BPCHAR),
+    (TypeId::ARRAY_NAME, TypeId::NAME),
+    (TypeId::ARRAY_XML, TypeId::XML),
+    (TypeId::ARRAY_NUMERIC, TypeId::NUMERIC),
+    (TypeId::ARRAY_MONEY, TypeId::MONEY),
+    (TypeId::ARRAY_DATE, TypeId::DATE),
+    (TypeId::ARRAY_TIME, TypeId::TIME),
+    (TypeId::ARRAY_TIMESTAMP, TypeId::TIMESTAMP),
+    (TypeId::ARRAY_TIMESTAMPTZ, TypeId::TIMESTAMPTZ),
+    (TypeId::ARRAY_INTERVAL, TypeId::INTERVAL),
+    (TypeId::ARRAY_BYTEA, TypeId::BYTEA),
+    (TypeId::ARRAY_UUID, TypeId::UUID),
+    (TypeId::ARRAY_CIDR, TypeId::CIDR),
+    (TypeId::ARRAY_INET, TypeId::INET),
+    (TypeId::ARRAY_MACADDR, TypeId::MACADDR),
+    (TypeId::ARRAY_MACADDR8, TypeId::MACADDR8),
+    (TypeId::ARRAY_BIT, TypeId::BIT),
+    (TypeId::ARRAY_VARBIT, TypeId::VARBIT),
+    (TypeId::ARRAY_PG_LSN, TypeId::PG_LSN),
+    (TypeId::ARRAY_TSVECTOR, TypeId::TSVECTOR),
+    (TypeId::ARRAY_TSQUERY, TypeId::TSQUERY),
+    (TypeId::ARRAY_POINT, TypeId::POINT),
+    (TypeId::ARRAY_JSON, TypeId::JSON),
+    (TypeId::ARRAY_JSONB, TypeId::JSONB),
+];
+
+#[allow(dead_code)]
+impl TypeId {
+    // The type of a one-dimensional array of `elem`, if both are built-in types
+    pub(crate) fn array_of(elem: TypeId) -> Option<TypeId> {
+        ARRAY_ELEMENTS
+            .iter()
+            .find(|&&(_, element)| element == elem)
+            .map(|&(array, _)| array)
+    }
+
+    // The type of the elements of `array`, if both are built-in types
+    pub(crate) fn element_of(array: TypeId) -> Option<TypeId> {
+        ARRAY_ELEMENTS
+            .iter()
+            .find(|&&(other, _)| other == array)
+            .map(|&(_, element)| element)
+    }
+}
+
+#[test]
+fn test_array_element_type_ids() {
+    assert_eq!(TypeId::array_of(TypeId::INT4), Some(TypeId::ARRAY_INT4));
+    assert_eq!(TypeId::element_of(TypeId::ARRAY_INT4), Some(TypeId::INT4));
+
+    assert_eq!(TypeId::array_of(TypeId::TEXT), Some(TypeId::ARRAY_TEXT));
+    assert_eq!(TypeId::element_of(TypeId::ARRAY_TEXT), Some(TypeId::TEXT));
+
+    // an array is not an element of a built-in array type
+    assert_eq!(TypeId::array_of(TypeId::ARRAY_INT4), None);
+    assert_eq!(TypeId::element_of(TypeId::INT4), None);
+
+    assert_eq!(TypeId::array_of(TypeId::UNSPECIFIED), None);
+    assert_eq!(TypeId::element_of(TypeId(16385)), None);
 }
@@ -4,10 +4,13 @@ use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::sync::Arc;
 
+use crate::decode::Decode;
 use crate::error::UnexpectedNullError;
 use crate::postgres::protocol::{DataRow, TypeFormat};
-use crate::postgres::Postgres;
+use crate::postgres::types::decode_any;
+use crate::postgres::{PgTypeInfo, Postgres};
 use crate::row::{ColumnIndex, Row};
+use crate::types::{AnyValue, Type};
 
 /// A value from Postgres. This may be in a BINARY or TEXT format depending
 /// on the data type and if the query was prepared or not.
@@ -29,14 +32,68 @@ impl<'c> TryFrom<Option<PgValue<'c>>> for PgValue<'c> {
     }
 }
 
+/// The name, position and type of a column of a [`PgRow`], as described by the server.
+#[derive(Debug, Clone)]
+pub struct PgColumn {
+    pub(super) name: Option<Box<str>>,
+    pub(super) ordinal: usize,
+    pub(super) type_info: PgTypeInfo,
+    pub(super) format: TypeFormat,
+}
+
+impl PgColumn {
+    /// The name of the column; `None` if Postgres does not name it, e.g., for `SELECT 1 + 1`.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The position of the column in the row, starting at `0`.
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// The type of the column.
+    pub fn type_info(&self) -> &PgTypeInfo {
+        &self.type_info
+    }
+
+    /// The object identifier (OID) of the type of the column.
+    pub fn type_oid(&self) -> u32 {
+        self.type_info.id.0
+    }
+}
+
 pub struct PgRow<'c> {
     pub(super) data: DataRow<'c>,
     pub(super) columns: Arc<HashMap<Box<str>, usize>>,
-    pub(super) formats: Arc<[TypeFormat]>,
+    pub(super) fields: Arc<[PgColumn]>,
 }
 
 impl crate::row::private_row::Sealed for PgRow<'_> {}
 
+impl<'c> PgRow<'c> {
+    /// The columns of the row, in order.
+    ///
+    /// The columns are the same for every row of a result and are known without decoding
+    /// any values.
+    pub fn columns(&self) -> &[PgColumn] {
+        &self.fields
+    }
+
+    /// Index into the row and decode a value of any type, as told by the type of its column.
+    ///
+    /// A value of a type without a variant of its own in [`AnyValue`] is returned as
+    /// [`AnyValue::Other`] with the bytes it was received as.
+    pub fn try_get_any<I>(&self, index: I) -> crate::Result<Postgres, AnyValue>
+    where
+        I: ColumnIndex<'c, Self>,
+    {
+        let index = index.index(self)?;
+
+        decode_any(&self.fields[index].type_info, self.try_get_raw(index)?)
+    }
+}
+
 impl<'c> Row<'c> for PgRow<'c> {
     type Database = Postgres;
 
@@ -44,6 +101,19 @@ impl<'c> Row<'c> for PgRow<'c> {
         self.data.len()
     }
 
+    // The type of the column is passed on to the decoder, e.g., to tell a `NUMERIC` apart from
+    // a `FLOAT8` when decoding an `f64`
+    fn try_get<T, I>(&self, index: I) -> crate::Result<Postgres, T>
+    where
+        T: Type<Postgres>,
+        I: ColumnIndex<'c, Self>,
+        T: Decode<'c, Postgres>,
+    {
+        let index = index.index(self)?;
+
+        T::decode_with_type(&self.fields[index].type_info, self.try_get_raw(index)?)
+    }
+
     #[doc(hidden)]
     fn try_get_raw<I>(&self, index: I) -> crate::Result<Postgres, Option<PgValue<'c>>>
     where
@@ -53,7 +123,7 @@ impl<'c> Row<'c> for PgRow<'c> {
         let buffer = self.data.get(index);
 
         buffer
-            .map(|buf| match self.formats[index] {
+            .map(|buf| match self.fields[index].format {
                 TypeFormat::Binary => Ok(PgValue::Binary(buf)),
                 TypeFormat::Text => Ok(PgValue::Text(from_utf8(buf)?)),
             })
@@ -23,7 +23,7 @@ fn nonce() -> String {
     // ;; Printable ASCII except ",".
     // ;; Note that any "printable" is also
     // ;; a valid "value".
-    let nonce: String = std::iter::repeat(())
+    std::iter::repeat(())
         .map(|()| {
             let mut c = rng.gen_range(0x21, 0x7F) as u8;
 
@@ -35,10 +35,135 @@ fn nonce() -> String {
         })
         .take(count)
         .map(|c| c as char)
+        .collect()
+}
+
+// client-first-message-bare = [reserved-mext ","] username "," nonce ["," extensions]
+fn client_first_message_bare(username: &str, nonce: &str) -> String {
+    // "n=" saslname ;; Usernames are prepared using SASLprep.
+    // nonce = "r=" c-nonce [s-nonce] ;; Second part provided by server.
+    format!(
+        "{username_attr}={username},{nonce_attr}={nonce}",
+        username_attr = USERNAME_ATTR,
+        username = username,
+        nonce_attr = NONCE_ATTR,
+        nonce = nonce
+    )
+}
+
+// Computes the client-final-message that answers the server-first-message, along with the
+// signature that the server must send back in its server-final-message
+fn client_final_message(
+    password: &str,
+    nonce: &str,
+    client_first_message_bare: &str,
+    sasl: &AuthenticationSaslContinue,
+) -> crate::Result<Postgres, (String, Vec<u8>)> {
+    // the nonce of the server is the nonce of the client followed by its own
+    if !sasl.nonce.starts_with(nonce.as_bytes()) {
+        return Err(
+            protocol_err!("SASL nonce of the server does not start with the client nonce").into(),
+        );
+    }
+
+    let server_first_message = &sasl.data;
+
+    // SaltedPassword := Hi(Normalize(password), salt, i)
+    let salted_password = hi(password, &sasl.salt, sasl.iter_count)?;
+
+    // ClientKey := HMAC(SaltedPassword, "Client Key")
+    let mut mac = Hmac::<Sha256>::new_varkey(&salted_password)
+        .map_err(|_| protocol_err!("HMAC can take key of any size"))?;
+    mac.input(b"Client Key");
+    let client_key = mac.result().code();
+
+    // StoredKey := H(ClientKey)
+    let mut hasher = Sha256::new();
+    hasher.input(client_key);
+    let stored_key = hasher.result();
+
+    // channel-binding = "c=" base64
+    let channel_binding = format!("{}={}", CHANNEL_ATTR, base64::encode(GS2_HEADER));
+
+    // String::from_utf8_lossy should never fail because Postgres requires
+    // the nonce to be all printable characters except ','
+    let client_final_message_wo_proof = format!(
+        "{channel_binding},{nonce_attr}={nonce}",
+        channel_binding = channel_binding,
+        nonce_attr = NONCE_ATTR,
+        nonce = String::from_utf8_lossy(&sasl.nonce)
+    );
+
+    // AuthMessage := client-first-message-bare + "," + server-first-message + "," + client-final-message-without-proof
+    let auth_message = format!(
+        "{client_first_message_bare},{server_first_message},{client_final_message_wo_proof}",
+        client_first_message_bare = client_first_message_bare,
+        server_first_message = server_first_message,
+        client_final_message_wo_proof = client_final_message_wo_proof
+    );
+
+    // ClientSignature := HMAC(StoredKey, AuthMessage)
+    let mut mac = Hmac::<Sha256>::new_varkey(&stored_key).expect("HMAC can take key of any size");
+    mac.input(&auth_message.as_bytes());
+    let client_signature = mac.result().code();
+
+    // ClientProof := ClientKey XOR ClientSignature
+    let client_proof: Vec<u8> = client_key
+        .iter()
+        .zip(client_signature.iter())
+        .map(|(&a, &b)| a ^ b)
         .collect();
 
-    rng.gen_range(32, 128);
-    format!("{}={}", NONCE_ATTR, nonce)
+    // ServerKey := HMAC(SaltedPassword, "Server Key")
+    let mut mac = Hmac::<Sha256>::new_varkey(&salted_password)
+        .map_err(|_| protocol_err!("HMAC can take key of any size"))?;
+    mac.input(b"Server Key");
+    let server_key = mac.result().code();
+
+    // ServerSignature := HMAC(ServerKey, AuthMessage)
+    let mut mac = Hmac::<Sha256>::new_varkey(&server_key).expect("HMAC can take key of any size");
+    mac.input(&auth_message.as_bytes());
+    let server_signature = mac.result().code();
+
+    // client-final-message = client-final-message-without-proof "," proof
+    let client_final_message = format!(
+        "{client_final_message_wo_proof},{client_proof_attr}={client_proof}",
+        client_final_message_wo_proof = client_final_message_wo_proof,
+        client_proof_attr = CLIENT_PROOF_ATTR,
+        client_proof = base64::encode(&client_proof)
+    );
+
+    Ok((client_final_message, server_signature.to_vec()))
+}
+
+// Checks that the server-final-message proves that the server knows the password too
+fn verify_server_final_message(
+    server_final_message: &[u8],
+    server_signature: &[u8],
+) -> crate::Result<Postgres, ()> {
+    // server-final-message = (server-error / verifier) ["," extensions]
+    let verifier = server_final_message
+        .split(|&byte| byte == b',')
+        .next()
+        .unwrap_or_default();
+
+    // verifier = "v=" base64
+    if !verifier.starts_with(b"v=") {
+        return Err(protocol_err!(
+            "expected a SASL server signature; received {:?}",
+            String::from_utf8_lossy(server_final_message)
+        )
+        .into());
+    }
+
+    let signature = base64::decode(&verifier[2..])
+        .map_err(|_| protocol_err!("SASL server signature was not base64 encoded"))?;
+
+    if signature != server_signature {
+        return Err(protocol_err!("SASL server signature does not match").into());
+    }
+
+    Ok(())
 }
 
 // Performs authenticiton using Simple Authentication Security Layer (SASL) which is what
@@ -48,15 +173,10 @@ pub(super) async fn authenticate<T: AsRef<str>>(
     username: T,
     password: T,
 ) -> crate::Result<Postgres, ()> {
-    // channel-binding = "c=" base64
-    let channel_binding = format!("{}={}", CHANNEL_ATTR, base64::encode(GS2_HEADER));
-    // "n=" saslname ;; Usernames are prepared using SASLprep.
-    let username = format!("{}={}", USERNAME_ATTR, username.as_ref());
-    // nonce = "r=" c-nonce [s-nonce] ;; Second part provided by server.
     let nonce = nonce();
-    let client_first_message_bare =
-        format!("{username},{nonce}", username = username, nonce = nonce);
-    // client-first-message-bare = [reserved-mext ","] username "," nonce ["," extensions]
+    let client_first_message_bare = client_first_message_bare(username.as_ref(), &nonce);
+
+    // client-first-message = gs2-header client-first-message-bare
     let client_first_message = format!(
         "{gs2_header}{client_first_message_bare}",
         gs2_header = GS2_HEADER,
@@ -75,76 +195,30 @@ pub(super) async fn authenticate<T: AsRef<str>>(
             // todo: better way to indicate that we consumed just these 4 bytes?
             let sasl = AuthenticationSaslContinue::read(&stream.buffer()[4..])?;
 
-            let server_first_message = sasl.data;
-
-            // SaltedPassword := Hi(Normalize(password), salt, i)
-            let salted_password = hi(password.as_ref(), &sasl.salt, sasl.iter_count)?;
-
-            // ClientKey := HMAC(SaltedPassword, "Client Key")
-            let mut mac = Hmac::<Sha256>::new_varkey(&salted_password)
-                .map_err(|_| protocol_err!("HMAC can take key of any size"))?;
-            mac.input(b"Client Key");
-            let client_key = mac.result().code();
-
-            // StoredKey := H(ClientKey)
-            let mut hasher = Sha256::new();
-            hasher.input(client_key);
-            let stored_key = hasher.result();
-
-            // String::from_utf8_lossy should never fail because Postgres requires
-            // the nonce to be all printable characters except ','
-            let client_final_message_wo_proof = format!(
-                "{channel_binding},r={nonce}",
-                channel_binding = channel_binding,
-                nonce = String::from_utf8_lossy(&sasl.nonce)
-            );
-
-            // AuthMessage := client-first-message-bare + "," + server-first-message + "," + client-final-message-without-proof
-            let auth_message = format!("{client_first_message_bare},{server_first_message},{client_final_message_wo_proof}",
-                                       client_first_message_bare = client_first_message_bare,
-                                       server_first_message = server_first_message,
-                                       client_final_message_wo_proof = client_final_message_wo_proof);
-
-            // ClientSignature := HMAC(StoredKey, AuthMessage)
-            let mut mac =
-                Hmac::<Sha256>::new_varkey(&stored_key).expect("HMAC can take key of any size");
-            mac.input(&auth_message.as_bytes());
-            let client_signature = mac.result().code();
-
-            // ClientProof := ClientKey XOR ClientSignature
-            let client_proof: Vec<u8> = client_key
-                .iter()
-                .zip(client_signature.iter())
-                .map(|(&a, &b)| a ^ b)
-                .collect();
-
-            // ServerKey := HMAC(SaltedPassword, "Server Key")
-            let mut mac = Hmac::<Sha256>::new_varkey(&salted_password)
-                .map_err(|_| protocol_err!("HMAC can take key of any size"))?;
-            mac.input(b"Server Key");
-            let server_key = mac.result().code();
-
-            // ServerSignature := HMAC(ServerKey, AuthMessage)
-            let mut mac =
-                Hmac::<Sha256>::new_varkey(&server_key).expect("HMAC can take key of any size");
-            mac.input(&auth_message.as_bytes());
-            let _server_signature = mac.result().code();
-
-            // client-final-message = client-final-message-without-proof "," proof
-            let client_final_message = format!(
-                "{client_final_message_wo_proof},{client_proof_attr}={client_proof}",
-                client_final_message_wo_proof = client_final_message_wo_proof,
-                client_proof_attr = CLIENT_PROOF_ATTR,
-                client_proof = base64::encode(&client_proof)
-            );
+            let (client_final_message, server_signature) =
+                client_final_message(password.as_ref(), &nonce, &client_first_message_bare, &sasl)?;
 
             stream.write(SaslResponse(&client_final_message));
             stream.flush().await?;
 
-            let _server_final_response = stream.receive().await?;
-            // todo: assert that this was SaslFinal?
+            // a wrong password is reported by the server as an error instead
+            match stream.receive().await? {
+                Message::Authentication => match Authentication::read(stream.buffer())? {
+                    Authentication::SaslFinal => {
+                        verify_server_final_message(&stream.buffer()[4..], &server_signature)
+                    }
 
-            Ok(())
+                    auth => Err(protocol_err!(
+                        "Expected Authentication::SaslFinal, but received {:?}",
+                        auth
+                    ))?,
+                },
+
+                message => Err(protocol_err!(
+                    "Expected Message::Authentication, but received {:?}",
+                    message
+                ))?,
+            }
         } else {
             Err(protocol_err!(
                 "Expected Authentication::SaslContinue, but received {:?}",
@@ -158,3 +232,67 @@ pub(super) async fn authenticate<T: AsRef<str>>(
         ))?
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The SCRAM-SHA-256 exchange from RFC 7677, section 3
+    const USERNAME: &str = "user";
+    const PASSWORD: &str = "pencil";
+    const CLIENT_NONCE: &str = "rOprNGfwEbeRWgbNEkqO";
+    const SERVER_FIRST_MESSAGE: &[u8] =
+        b"r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,s=W22ZaJ0SNY7soEsUEjb6gQ==,i=4096";
+    const CLIENT_FINAL_MESSAGE: &str = "c=biws,r=rOprNGfwEbeRWgbNEkqO%hvYDpWUa2RaTCAfuxFIlj)hNlF$k0,p=dHzbZapWIk4jUhN+Ute9ytag9zjfMHgsqmmiz7AndVQ=";
+    const SERVER_FINAL_MESSAGE: &[u8] = b"v=6rriTRBi23WpRR/wtup+mMhUZUn/dB5nLTJRsjl95G4=";
+
+    #[test]
+    fn it_writes_the_client_first_message() {
+        assert_eq!(
+            client_first_message_bare(USERNAME, CLIENT_NONCE),
+            "n=user,r=rOprNGfwEbeRWgbNEkqO"
+        );
+    }
+
+    #[test]
+    fn it_completes_a_scram_exchange() {
+        let sasl = AuthenticationSaslContinue::read(SERVER_FIRST_MESSAGE).unwrap();
+        let client_first_message_bare = client_first_message_bare(USERNAME, CLIENT_NONCE);
+
+        let (client_final_message, server_signature) =
+            client_final_message(PASSWORD, CLIENT_NONCE, &client_first_message_bare, &sasl)
+                .unwrap();
+
+        assert_eq!(client_final_message, CLIENT_FINAL_MESSAGE);
+
+        verify_server_final_message(SERVER_FINAL_MESSAGE, &server_signature).unwrap();
+    }
+
+    #[test]
+    fn it_rejects_a_wrong_server_signature() {
+        let sasl = AuthenticationSaslContinue::read(SERVER_FIRST_MESSAGE).unwrap();
+        let client_first_message_bare = client_first_message_bare(USERNAME, CLIENT_NONCE);
+
+        let (_, server_signature) =
+            client_final_message("pencils", CLIENT_NONCE, &client_first_message_bare, &sasl)
+                .unwrap();
+
+        assert!(verify_server_final_message(SERVER_FINAL_MESSAGE, &server_signature).is_err());
+        assert!(verify_server_final_message(b"e=invalid-proof", &server_signature).is_err());
+    }
+
+    #[test]
+    fn it_rejects_a_server_nonce_of_another_client() {
+        let sasl = AuthenticationSaslContinue::read(SERVER_FIRST_MESSAGE).unwrap();
+        let client_first_message_bare =
+            client_first_message_bare(USERNAME, "fyko+d2lbbFgONRv9qkxdawL");
+
+        assert!(client_final_message(
+            PASSWORD,
+            "fyko+d2lbbFgONRv9qkxdawL",
+            &client_first_message_bare,
+            &sasl
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt::{self, Display};
+
+macro_rules! sql_states {
+    ($($(#[$meta:meta])* $name:ident = $code:literal,)*) => {
+        /// A **Postgres** error code (SQLSTATE) with a variant for each of the common codes.
+        ///
+        /// <https://www.postgresql.org/docs/12/errcodes-appendix.html>
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum PgSqlState {
+            $($(#[$meta])* $name,)*
+
+            /// Any other code.
+            Other(String),
+        }
+
+        impl PgSqlState {
+            /// The five-character code, e.g., `23505`.
+            pub fn code(&self) -> &str {
+                match self {
+                    $(PgSqlState::$name => $code,)*
+                    PgSqlState::Other(code) => code,
+                }
+            }
+        }
+
+        impl From<&'_ str> for PgSqlState {
+            fn from(code: &str) -> Self {
+                match code {
+                    $($code => PgSqlState::$name,)*
+                    _ => PgSqlState::Other(code.to_owned()),
+                }
+            }
+        }
+    };
+}
+
+sql_states! {
+    /// `08000`
+    ConnectionException = "08000",
+    /// `08003`
+    ConnectionDoesNotExist = "08003",
+    /// `08006`
+    ConnectionFailure = "08006",
+    /// `22001`
+    StringDataRightTruncation = "22001",
+    /// `22003`
+    NumericValueOutOfRange = "22003",
+    /// `22012`
+    DivisionByZero = "22012",
+    /// `22P02`
+    InvalidTextRepresentation = "22P02",
+    /// `23000`
+    IntegrityConstraintViolation = "23000",
+    /// `23001`
+    RestrictViolation = "23001",
+    /// `23502`
+    NotNullViolation = "23502",
+    /// `23503`
+    ForeignKeyViolation = "23503",
+    /// `23505`
+    UniqueViolation = "23505",
+    /// `23514`
+    CheckViolation = "23514",
+    /// `23P01`
+    ExclusionViolation = "23P01",
+    /// `25006`
+    ReadOnlySqlTransaction = "25006",
+    /// `25P02`
+    InFailedSqlTransaction = "25P02",
+    /// `40000`
+    TransactionRollback = "40000",
+    /// `40001`
+    SerializationFailure = "40001",
+    /// `40P01`
+    DeadlockDetected = "40P01",
+    /// `42501`
+    InsufficientPrivilege = "42501",
+    /// `42601`
+    SyntaxError = "42601",
+    /// `42703`
+    UndefinedColumn = "42703",
+    /// `42P01`
+    UndefinedTable = "42P01",
+    /// `55P03`
+    LockNotAvailable = "55P03",
+    /// `57014`
+    QueryCanceled = "57014",
+}
+
+impl Display for PgSqlState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.code())
+    }
+}
+
+#[test]
+fn test_parse_sql_state() {
+    let states = [
+        ("23505", PgSqlState::UniqueViolation),
+        ("23503", PgSqlState::ForeignKeyViolation),
+        ("23502", PgSqlState::NotNullViolation),
+        ("23514", PgSqlState::CheckViolation),
+        ("23P01", PgSqlState::ExclusionViolation),
+        ("40001", PgSqlState::SerializationFailure),
+        ("40P01", PgSqlState::DeadlockDetected),
+        ("08006", PgSqlState::ConnectionFailure),
+        ("42P01", PgSqlState::UndefinedTable),
+        ("57014", PgSqlState::QueryCanceled),
+    ];
+
+    for (code, state) in states.iter() {
+        assert_eq!(PgSqlState::from(*code), *state);
+        assert_eq!(state.code(), *code);
+    }
+
+    let other = PgSqlState::from("XX000");
+
+    assert_eq!(other, PgSqlState::Other("XX000".to_owned()));
+    assert_eq!(other.to_string(), "XX000");
+}
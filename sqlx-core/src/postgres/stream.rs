@@ -1,19 +1,27 @@
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::net::Shutdown;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use byteorder::NetworkEndian;
 use futures_channel::mpsc::UnboundedSender;
 
 use crate::io::{Buf, BufStream, MaybeTlsStream};
-use crate::postgres::protocol::{Message, NotificationResponse, Response, Write};
-use crate::postgres::PgError;
+use crate::postgres::protocol::{Message, NotificationResponse, ParameterStatus, Response, Write};
 use crate::postgres::Postgres;
+use crate::postgres::{PgError, PgNotice};
 use crate::url::Url;
 use futures_util::SinkExt;
+use percent_encoding::percent_decode_str;
 
 pub struct PgStream {
     pub(super) stream: BufStream<MaybeTlsStream>,
     pub(super) notifications: Option<UnboundedSender<NotificationResponse<'static>>>,
+    pub(super) notice_handler: Option<Box<dyn Fn(&PgNotice) + Send + Sync>>,
+
+    // The current value of each parameter reported by the server
+    pub(super) parameters: HashMap<Box<str>, Box<str>>,
 
     // Most recently received message
     // Is referenced by our buffered stream
@@ -23,10 +31,33 @@ pub struct PgStream {
 
 impl PgStream {
     pub(super) async fn new(url: &Url) -> crate::Result<Postgres, Self> {
-        let stream = MaybeTlsStream::connect(&url, 5432).await?;
+        let stream = match socket_path(url) {
+            #[cfg(unix)]
+            Some(path) => MaybeTlsStream::connect_uds(&path).await?,
+
+            #[cfg(not(unix))]
+            Some(_) => {
+                return Err(crate::Error::Configuration(
+                    "Unix domain sockets are not supported on this platform".into(),
+                ))
+            }
+
+            None => {
+                let nodelay = tcp_nodelay(url)?;
+                let keepalive = keepalives_idle(url)?;
+
+                let stream = MaybeTlsStream::connect(&url, 5432, keepalive).await?;
+
+                stream.set_nodelay(nodelay)?;
+
+                stream
+            }
+        };
 
         Ok(Self {
             notifications: None,
+            notice_handler: None,
+            parameters: HashMap::new(),
             stream: BufStream::new(stream),
             message: (Message::ReadyForQuery, 0),
         })
@@ -87,11 +118,22 @@ impl PgStream {
 
                     if response.severity.is_error() {
                         // This is an error, bubble up as one immediately
-                        return Err(crate::Error::Database(Box::new(PgError(response))));
+                        return Err(crate::Error::Database(Box::new(PgError::new(response))));
+                    }
+
+                    if let Some(handler) = &self.notice_handler {
+                        handler(&PgNotice(response));
                     }
 
-                    // TODO: Provide some way of receiving these non-critical
-                    //       notices from postgres
+                    continue;
+                }
+
+                // Sent at startup and whenever a reported parameter changes, e.g., after `SET`
+                Message::ParameterStatus => {
+                    let status = ParameterStatus::read(self.stream.buffer())?;
+
+                    self.parameters
+                        .insert(status.name.into(), status.value.into());
                     continue;
                 }
 
@@ -120,3 +162,102 @@ impl PgStream {
         &self.stream.buffer()[..(self.message.1 as usize)]
     }
 }
+
+// A host that is a directory, e.g., `postgres:///db?host=/var/run/postgresql` or
+// `postgres://%2Fvar%2Frun%2Fpostgresql/db`, is where the Unix domain socket of the server is
+pub(super) fn socket_path(url: &Url) -> Option<PathBuf> {
+    let host = match url.param("host") {
+        Some(host) => host,
+        None => percent_decode_str(url.host()).decode_utf8().ok()?,
+    };
+
+    if !host.starts_with('/') {
+        return None;
+    }
+
+    Some(Path::new(&*host).join(format!(".s.PGSQL.{}", url.port(5432))))
+}
+
+// `TCP_NODELAY` is on unless `tcp_nodelay=false`; a message is written as a whole so there is
+// nothing to gain from delaying it
+fn tcp_nodelay(url: &Url) -> crate::Result<Postgres, bool> {
+    match url.param("tcp_nodelay").as_deref() {
+        None | Some("true") | Some("1") => Ok(true),
+        Some("false") | Some("0") => Ok(false),
+
+        Some(value) => Err(crate::Error::Configuration(
+            format!(
+                "invalid `tcp_nodelay` value: {:?}; expected `true` or `false`",
+                value
+            )
+            .into(),
+        )),
+    }
+}
+
+// As in libpq, `keepalives_idle` is the number of seconds of inactivity after which TCP keepalive
+// probes are sent; TCP keepalive is off if it is not set
+fn keepalives_idle(url: &Url) -> crate::Result<Postgres, Option<Duration>> {
+    match url.param("keepalives_idle") {
+        Some(secs) => match secs.parse() {
+            Ok(secs) if secs > 0 => Ok(Some(Duration::from_secs(secs))),
+
+            _ => Err(crate::Error::Configuration(
+                format!(
+                    "invalid `keepalives_idle` value: {:?}; expected a number of seconds",
+                    secs
+                )
+                .into(),
+            )),
+        },
+
+        None => Ok(None),
+    }
+}
+
+#[test]
+#[cfg(feature = "runtime-async-std")]
+fn test_tcp_options() {
+    use std::convert::TryFrom;
+    use std::net::TcpListener;
+
+    // connections are queued by the listener without being accepted
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let connect = |params: &str| {
+        let url =
+            Url::try_from(format!("postgres://127.0.0.1:{}/postgres{}", port, params)).unwrap();
+
+        async_std::task::block_on(PgStream::new(&url))
+    };
+
+    let nodelay = |stream: PgStream| stream.stream.stream.nodelay().unwrap();
+
+    assert!(nodelay(connect("").unwrap()));
+    assert!(nodelay(connect("?keepalives_idle=60").unwrap()));
+    assert!(!nodelay(connect("?tcp_nodelay=false").unwrap()));
+
+    assert!(connect("?tcp_nodelay=maybe").is_err());
+    assert!(connect("?keepalives_idle=0").is_err());
+}
+
+#[test]
+fn test_socket_path() {
+    use std::convert::TryFrom;
+
+    let path = |url: &str| socket_path(&Url::try_from(url).unwrap());
+
+    assert_eq!(
+        path("postgres:///postgres?host=/var/run/postgresql"),
+        Some(PathBuf::from("/var/run/postgresql/.s.PGSQL.5432"))
+    );
+
+    assert_eq!(
+        path("postgres://%2Ftmp:5433/postgres"),
+        Some(PathBuf::from("/tmp/.s.PGSQL.5433"))
+    );
+
+    assert_eq!(path("postgres://localhost/postgres"), None);
+    assert_eq!(path("postgres://localhost/postgres?host=db"), None);
+}
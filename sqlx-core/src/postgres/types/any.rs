@@ -0,0 +1,70 @@
+use crate::decode::Decode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgTypeInfo, PgValue, Postgres};
+use crate::types::AnyValue;
+
+// Decodes a value with the type of its column; any type without a variant of its own
+// is kept as it was received
+pub(crate) fn decode_any(
+    type_info: &PgTypeInfo,
+    value: Option<PgValue<'_>>,
+) -> crate::Result<Postgres, AnyValue> {
+    let value = match value {
+        Some(value) => value,
+        None => return Ok(AnyValue::Null),
+    };
+
+    Ok(match type_info.id {
+        TypeId::BOOL => AnyValue::Bool(Decode::<Postgres>::decode(Some(value))?),
+
+        TypeId::INT2 => AnyValue::Int(<i16 as Decode<Postgres>>::decode(Some(value))?.into()),
+        TypeId::INT4 => AnyValue::Int(<i32 as Decode<Postgres>>::decode(Some(value))?.into()),
+        TypeId::INT8 => AnyValue::Int(Decode::<Postgres>::decode(Some(value))?),
+
+        TypeId::FLOAT4 => AnyValue::Float(<f32 as Decode<Postgres>>::decode(Some(value))?.into()),
+        TypeId::FLOAT8 => AnyValue::Float(Decode::<Postgres>::decode(Some(value))?),
+
+        TypeId::TEXT | TypeId::VARCHAR | TypeId::BPCHAR | TypeId::NAME => {
+            AnyValue::Text(Decode::<Postgres>::decode(Some(value))?)
+        }
+
+        TypeId::BYTEA => AnyValue::Bytes(Decode::<Postgres>::decode(Some(value))?),
+
+        #[cfg(feature = "json")]
+        TypeId::JSON | TypeId::JSONB => AnyValue::Json(Decode::<Postgres>::decode(Some(value))?),
+
+        #[cfg(feature = "uuid")]
+        TypeId::UUID => AnyValue::Uuid(Decode::<Postgres>::decode(Some(value))?),
+
+        TypeId(oid) => AnyValue::Other {
+            oid,
+            bytes: match value {
+                PgValue::Binary(buf) => buf.to_vec(),
+                PgValue::Text(s) => s.as_bytes().to_vec(),
+            },
+        },
+    })
+}
+
+#[cfg(feature = "any")]
+impl From<&'_ PgTypeInfo> for crate::any::AnyTypeInfo {
+    fn from(type_info: &PgTypeInfo) -> Self {
+        use crate::any::AnyTypeKind;
+
+        crate::any::AnyTypeInfo(match type_info.id {
+            TypeId::BOOL => AnyTypeKind::Bool,
+            TypeId::INT2 | TypeId::INT4 | TypeId::INT8 => AnyTypeKind::Int,
+            TypeId::FLOAT4 | TypeId::FLOAT8 => AnyTypeKind::Float,
+            TypeId::TEXT | TypeId::VARCHAR | TypeId::BPCHAR | TypeId::NAME => AnyTypeKind::Text,
+            TypeId::BYTEA => AnyTypeKind::Bytes,
+
+            #[cfg(feature = "json")]
+            TypeId::JSON | TypeId::JSONB => AnyTypeKind::Json,
+
+            #[cfg(feature = "uuid")]
+            TypeId::UUID => AnyTypeKind::Uuid,
+
+            _ => AnyTypeKind::Other(type_info.type_name().to_owned()),
+        })
+    }
+}
@@ -5,7 +5,7 @@ use crate::decode::{Decode, DecodeOwned};
 use crate::encode::Encode;
 use crate::postgres::database::Postgres;
 use crate::postgres::types::raw::{PgArrayDecoder, PgArrayEncoder};
-use crate::postgres::PgValue;
+use crate::postgres::{PgTypeInfo, PgValue};
 use crate::types::Type;
 
 impl<T> Encode<Postgres> for [T]
@@ -67,3 +67,27 @@ where
         <[T] as Type<DB>>::type_info()
     }
 }
+
+// Postgres does not distinguish arrays by their number of dimensions so a nested `Vec` is the same
+// type as the array of its elements
+impl<T> Type<Postgres> for [Vec<T>]
+where
+    T: Type<Postgres>,
+    [T]: Type<Postgres>,
+{
+    #[inline]
+    fn type_info() -> PgTypeInfo {
+        <[T] as Type<Postgres>>::type_info()
+    }
+}
+
+impl<T> Type<Postgres> for Vec<Vec<T>>
+where
+    T: Type<Postgres>,
+    [T]: Type<Postgres>,
+{
+    #[inline]
+    fn type_info() -> PgTypeInfo {
+        <[T] as Type<Postgres>>::type_info()
+    }
+}
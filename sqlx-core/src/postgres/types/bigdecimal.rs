@@ -9,7 +9,7 @@ use crate::encode::Encode;
 use crate::postgres::{PgTypeInfo, PgValue, Postgres};
 use crate::types::Type;
 
-use super::raw::{PgNumeric, PgNumericSign};
+use super::{PgNumeric, PgNumericSign};
 
 impl Type<Postgres> for BigDecimal {
     fn type_info() -> PgTypeInfo {
@@ -17,6 +17,18 @@ impl Type<Postgres> for BigDecimal {
     }
 }
 
+impl Type<Postgres> for [BigDecimal] {
+    fn type_info() -> PgTypeInfo {
+        <[PgNumeric] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Type<Postgres> for Vec<BigDecimal> {
+    fn type_info() -> PgTypeInfo {
+        <[PgNumeric] as Type<Postgres>>::type_info()
+    }
+}
+
 impl TryFrom<BigDecimal> for PgNumeric {
     type Error = std::num::TryFromIntError;
 
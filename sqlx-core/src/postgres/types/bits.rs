@@ -0,0 +1,237 @@
+use std::convert::TryInto;
+use std::fmt::{self, Display};
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::{Buf, BufMut};
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+/// The **Postgres** `BIT` and `VARBIT` types; a string of bits.
+///
+/// The bits are packed most significant bit first, as they are sent over the wire, and any
+/// padding bits in the last byte are always zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgBits {
+    len: usize,
+    bytes: Vec<u8>,
+}
+
+impl PgBits {
+    /// Creates an empty bit string.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a bit string of the first `len` bits of `bytes`, most significant bit first.
+    ///
+    /// ### Panics
+    /// If `bytes` has fewer than `len` bits.
+    pub fn from_bytes(bytes: &[u8], len: usize) -> Self {
+        assert!(
+            len <= bytes.len() * 8,
+            "{} bytes is not enough for {} bits",
+            bytes.len(),
+            len
+        );
+
+        let mut bits = PgBits {
+            len,
+            bytes: bytes[..((len + 7) / 8)].to_vec(),
+        };
+
+        bits.clear_padding();
+        bits
+    }
+
+    /// The number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit at `index` or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index >= self.len {
+            return None;
+        }
+
+        Some(self.bytes[index / 8] & (0x80 >> (index % 8)) != 0)
+    }
+
+    /// Appends a bit to the end.
+    pub fn push(&mut self, bit: bool) {
+        if self.len % 8 == 0 {
+            self.bytes.push(0);
+        }
+
+        if bit {
+            self.bytes[self.len / 8] |= 0x80 >> (self.len % 8);
+        }
+
+        self.len += 1;
+    }
+
+    /// Returns an iterator over the bits.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(move |index| self.bytes[index / 8] & (0x80 >> (index % 8)) != 0)
+    }
+
+    /// The packed bits; the padding bits of the last byte are zero.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn clear_padding(&mut self) {
+        if self.len % 8 != 0 {
+            if let Some(last) = self.bytes.last_mut() {
+                *last &= 0xFF << (8 - self.len % 8);
+            }
+        }
+    }
+}
+
+impl FromIterator<bool> for PgBits {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = PgBits::new();
+
+        for bit in iter {
+            bits.push(bit);
+        }
+
+        bits
+    }
+}
+
+impl Display for PgBits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in self.iter() {
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a string of `0`s and `1`s, e.g., `101`.
+impl FromStr for PgBits {
+    type Err = crate::Error<Postgres>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .map(|ch| match ch {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(Error::Decode(format!("invalid BIT value: {:?}", s).into())),
+            })
+            .collect()
+    }
+}
+
+impl Type<Postgres> for PgBits {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::VARBIT, "VARBIT")
+    }
+}
+
+impl Type<Postgres> for [PgBits] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_VARBIT, "VARBIT[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgBits> {
+    fn type_info() -> PgTypeInfo {
+        <[PgBits] as Type<Postgres>>::type_info()
+    }
+}
+
+/// ### Panics
+/// If the number of bits overflows `i32`.
+impl Encode<Postgres> for PgBits {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let len: i32 = self
+            .len
+            .try_into()
+            .expect("PgBits.len() should not overflow i32");
+
+        buf.put_i32::<BigEndian>(len);
+        buf.extend_from_slice(&self.bytes);
+    }
+
+    fn size_hint(&self) -> usize {
+        4 + self.bytes.len()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgBits {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let len = buf.get_i32::<BigEndian>()?;
+
+                if len < 0 {
+                    return Err(decode_err!("negative BIT length: {}", len));
+                }
+
+                let len = len as usize;
+
+                if buf.len() * 8 < len {
+                    return Err(decode_err!(
+                        "BIT of {} bits is truncated; got {} bytes",
+                        len,
+                        buf.len()
+                    ));
+                }
+
+                Ok(PgBits::from_bytes(buf, len))
+            }
+
+            PgValue::Text(s) => s.parse(),
+        }
+    }
+}
+
+#[test]
+fn test_push_bits() {
+    let mut bits = PgBits::new();
+    assert!(bits.is_empty());
+
+    bits.push(true);
+    bits.push(false);
+    bits.push(true);
+
+    assert_eq!(bits.len(), 3);
+    assert_eq!(bits.as_bytes(), [0b1010_0000]);
+    assert_eq!(bits.get(0), Some(true));
+    assert_eq!(bits.get(1), Some(false));
+    assert_eq!(bits.get(3), None);
+    assert_eq!(bits.to_string(), "101");
+    assert_eq!("101".parse::<PgBits>().unwrap(), bits);
+    assert!("102".parse::<PgBits>().is_err());
+}
+
+#[test]
+fn test_decode_bits() {
+    // the padding bits sent by the server are ignored
+    let buf = [0, 0, 0, 3, 0b1011_1111];
+    let bits: PgBits = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
+
+    assert_eq!(bits.len(), 3);
+    assert_eq!(bits.as_bytes(), [0b1010_0000]);
+    assert_eq!(bits, "101".parse().unwrap());
+
+    let buf = [0, 0, 0, 9, 0xFF];
+    assert!(<PgBits as Decode<Postgres>>::decode(Some(PgValue::Binary(&buf))).is_err());
+}
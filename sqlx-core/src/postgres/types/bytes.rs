@@ -67,3 +67,28 @@ impl<'de> Decode<'de, Postgres> for &'de [u8] {
         }
     }
 }
+
+#[cfg(feature = "bytes")]
+impl Type<Postgres> for bytes::Bytes {
+    fn type_info() -> PgTypeInfo {
+        <[u8] as Type<Postgres>>::type_info()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Encode<Postgres> for bytes::Bytes {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <[u8] as Encode<Postgres>>::encode(self, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'de> Decode<'de, Postgres> for bytes::Bytes {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        <Vec<u8> as Decode<Postgres>>::decode(value).map(Into::into)
+    }
+}
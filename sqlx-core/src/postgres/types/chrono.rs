@@ -355,3 +355,41 @@ fn test_decode_date() {
     let date: NaiveDate = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
     assert_eq!(date.to_string(), "2019-12-11");
 }
+
+#[test]
+fn test_date_time_type_ids() {
+    assert_eq!(<NaiveDate as Type<Postgres>>::type_info().id, TypeId::DATE);
+    assert_eq!(<NaiveTime as Type<Postgres>>::type_info().id, TypeId::TIME);
+    assert_eq!(
+        <[NaiveDate] as Type<Postgres>>::type_info().id,
+        TypeId::ARRAY_DATE
+    );
+    assert_eq!(
+        <[NaiveTime] as Type<Postgres>>::type_info().id,
+        TypeId::ARRAY_TIME
+    );
+}
+
+#[test]
+fn test_round_trip_date() {
+    let mut buf = Vec::new();
+
+    let date = NaiveDate::from_ymd(1999, 12, 31);
+    Encode::<Postgres>::encode(&date, &mut buf);
+    assert_eq!(buf.len(), 4);
+
+    let decoded: NaiveDate = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
+    assert_eq!(decoded, date);
+}
+
+#[test]
+fn test_round_trip_time() {
+    let mut buf = Vec::new();
+
+    let time = NaiveTime::from_hms_micro(23, 59, 59, 999_999);
+    Encode::<Postgres>::encode(&time, &mut buf);
+    assert_eq!(buf.len(), 8);
+
+    let decoded: NaiveTime = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
+    assert_eq!(decoded, time);
+}
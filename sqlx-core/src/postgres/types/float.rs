@@ -7,7 +7,7 @@ use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::error::Error;
 use crate::postgres::protocol::TypeId;
-use crate::postgres::types::PgTypeInfo;
+use crate::postgres::types::{PgNumeric, PgTypeInfo};
 use crate::postgres::{PgValue, Postgres};
 use crate::types::Type;
 
@@ -70,6 +70,13 @@ impl Encode<Postgres> for f64 {
     }
 }
 
+/// ### Note
+/// A `NUMERIC` column can also be decoded as `f64`. This is lossy; the value is converted with
+/// [`PgNumeric::to_f64`], so it is rounded to the nearest `f64` and values too large for `f64`
+/// become infinite. Decode as [`PgNumeric`] to keep every digit.
+///
+/// [`PgNumeric`]: crate::postgres::types::PgNumeric
+/// [`PgNumeric::to_f64`]: crate::postgres::types::PgNumeric::to_f64
 impl<'de> Decode<'de, Postgres> for f64 {
     fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
         match value.try_into()? {
@@ -81,4 +88,73 @@ impl<'de> Decode<'de, Postgres> for f64 {
             PgValue::Text(s) => f64::from_str(s).map_err(Error::decode),
         }
     }
+
+    // the binary format of a `NUMERIC` is not that of a `FLOAT8`; the text format of both is
+    // parsed the same
+    fn decode_with_type(
+        type_info: &PgTypeInfo,
+        value: Option<PgValue<'de>>,
+    ) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(buf) if type_info.id == TypeId::NUMERIC => {
+                Ok(PgNumeric::from_bytes(buf)?.to_f64())
+            }
+
+            value => Self::decode(Some(value)),
+        }
+    }
+}
+
+#[test]
+fn test_decode_numeric_as_f64() {
+    let numeric = PgTypeInfo::with_oid(TypeId::NUMERIC.0);
+    let float8 = PgTypeInfo::with_oid(TypeId::FLOAT8.0);
+
+    let decode = |type_info, buf: &[u8]| {
+        <f64 as Decode<Postgres>>::decode_with_type(type_info, Some(PgValue::Binary(buf)))
+    };
+
+    assert_eq!(
+        decode(&float8, &1.5_f64.to_bits().to_be_bytes()).unwrap(),
+        1.5
+    );
+
+    // 1.5::NUMERIC; 2 digits, a weight of 0, positive, and a scale of 1
+    assert_eq!(
+        decode(
+            &numeric,
+            b"\x00\x02\x00\x00\x00\x00\x00\x01\x00\x01\x13\x88"
+        )
+        .unwrap(),
+        1.5
+    );
+
+    // 0.00::NUMERIC
+    assert_eq!(
+        decode(&numeric, b"\x00\x00\x00\x00\x00\x00\x00\x02").unwrap(),
+        0.0
+    );
+
+    // 'NaN'::NUMERIC
+    assert!(decode(&numeric, b"\x00\x00\x00\x00\xC0\x00\x00\x00")
+        .unwrap()
+        .is_nan());
+
+    // a truncated NUMERIC of 2 digits
+    assert!(decode(&numeric, b"\x00\x02\x00\x00\x00\x00\x00\x01\x00\x01").is_err());
+
+    // the 8 bytes of a FLOAT8 are never read as a NUMERIC, even where they could be one
+    let subnormal = f64::from_bits(0x1);
+    assert_eq!(decode(&float8, &0x1_u64.to_be_bytes()).unwrap(), subnormal);
+
+    let subnormal = f64::from_bits(0xC000_0000);
+    assert_eq!(
+        decode(&float8, &0xC000_0000_u64.to_be_bytes()).unwrap(),
+        subnormal
+    );
+    assert_eq!(
+        <f64 as Decode<Postgres>>::decode(Some(PgValue::Binary(&0xC000_0000_u64.to_be_bytes())))
+            .unwrap(),
+        subnormal
+    );
 }
@@ -0,0 +1,306 @@
+use std::convert::TryInto;
+use std::fmt::{self, Display, Write};
+
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::io::Buf;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+
+/// The text representation of a **Postgres** `TSVECTOR`, e.g., `'fox':3 'quick':2`.
+///
+/// This can only be decoded; constructing a `TSVECTOR` should be left to the server
+/// with, e.g., `to_tsvector($1)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PgTsVector(pub String);
+
+/// The text representation of a **Postgres** `TSQUERY`, e.g., `'fox' & !'dog'`.
+///
+/// This can only be decoded; constructing a `TSQUERY` should be left to the server
+/// with, e.g., `to_tsquery($1)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PgTsQuery(pub String);
+
+impl Display for PgTsVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Display for PgTsQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Type<Postgres> for PgTsVector {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TSVECTOR, "TSVECTOR")
+    }
+}
+
+impl Type<Postgres> for [PgTsVector] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TSVECTOR, "TSVECTOR[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgTsVector> {
+    fn type_info() -> PgTypeInfo {
+        <[PgTsVector] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Type<Postgres> for PgTsQuery {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TSQUERY, "TSQUERY")
+    }
+}
+
+impl Type<Postgres> for [PgTsQuery] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TSQUERY, "TSQUERY[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgTsQuery> {
+    fn type_info() -> PgTypeInfo {
+        <[PgTsQuery] as Type<Postgres>>::type_info()
+    }
+}
+
+/// ### Note
+/// The binary format is converted to the same text that Postgres would output.
+impl<'de> Decode<'de, Postgres> for PgTsVector {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(buf) => tsvector_to_text(buf).map(PgTsVector),
+            PgValue::Text(s) => Ok(PgTsVector(s.to_owned())),
+        }
+    }
+}
+
+/// ### Note
+/// The binary format is converted to the same text that Postgres would output.
+impl<'de> Decode<'de, Postgres> for PgTsQuery {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(buf) => tsquery_to_text(buf).map(PgTsQuery),
+            PgValue::Text(s) => Ok(PgTsQuery(s.to_owned())),
+        }
+    }
+}
+
+// https://github.com/postgres/postgres/blob/REL_12_2/src/backend/utils/adt/tsvector.c#L485-L530
+//
+// count: int32, then per lexeme:
+//   lexeme: NUL-terminated string; npos: uint16; positions: uint16 * npos
+//
+// the top 2 bits of a position are its weight (3 = A, ..., 0 = D)
+fn tsvector_to_text(mut buf: &[u8]) -> crate::Result<Postgres, String> {
+    let count = buf.get_i32::<BigEndian>()?;
+    let mut s = String::new();
+
+    for i in 0..count {
+        if i > 0 {
+            s.push(' ');
+        }
+
+        write_quoted(&mut s, buf.get_str_nul()?);
+
+        let positions = buf.get_u16::<BigEndian>()?;
+
+        for j in 0..positions {
+            let position = buf.get_u16::<BigEndian>()?;
+
+            s.push(if j == 0 { ':' } else { ',' });
+            let _ = write!(s, "{}", position & 0x3FFF);
+
+            match position >> 14 {
+                3 => s.push('A'),
+                2 => s.push('B'),
+                1 => s.push('C'),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(s)
+}
+
+const QI_VAL: i8 = 1;
+const QI_OPR: i8 = 2;
+
+const OP_NOT: i8 = 1;
+const OP_AND: i8 = 2;
+const OP_OR: i8 = 3;
+const OP_PHRASE: i8 = 4;
+
+// https://github.com/postgres/postgres/blob/REL_12_2/src/include/tsearch/ts_type.h#L185-L189
+fn priority(operator: i8) -> u8 {
+    match operator {
+        OP_NOT => 4,
+        OP_PHRASE => 3,
+        OP_AND => 2,
+        _ => 1,
+    }
+}
+
+// https://github.com/postgres/postgres/blob/REL_12_2/src/backend/utils/adt/tsquery.c#L1016-L1098
+//
+// count: int32, then per item in prefix order with the right operand of an operator first:
+//   operand:  type (1): int8; weight: int8; prefix: int8; value: NUL-terminated string
+//   operator: type (2): int8; operator: int8; distance: int16 (only for OP_PHRASE)
+fn tsquery_to_text(mut buf: &[u8]) -> crate::Result<Postgres, String> {
+    let count = buf.get_i32::<BigEndian>()?;
+    let mut s = String::new();
+
+    if count > 0 {
+        write_tsquery_item(&mut buf, &mut s, 0, false)?;
+    }
+
+    if !buf.is_empty() {
+        return Err(decode_err!(
+            "TSQUERY has {} unexpected trailing bytes",
+            buf.len()
+        ));
+    }
+
+    Ok(s)
+}
+
+// mirrors `infix()` in Postgres so the output is identical
+fn write_tsquery_item(
+    buf: &mut &[u8],
+    s: &mut String,
+    parent_priority: u8,
+    right_of_phrase: bool,
+) -> crate::Result<Postgres, ()> {
+    match buf.get_i8()? {
+        QI_VAL => {
+            let weight = buf.get_u8()?;
+            let prefix = buf.get_u8()? != 0;
+
+            write_quoted(s, buf.get_str_nul()?);
+
+            if weight != 0 || prefix {
+                s.push(':');
+
+                if prefix {
+                    s.push('*');
+                }
+
+                for (bit, letter) in [(3, 'A'), (2, 'B'), (1, 'C'), (0, 'D')].iter() {
+                    if weight & (1 << bit) != 0 {
+                        s.push(*letter);
+                    }
+                }
+            }
+        }
+
+        QI_OPR => {
+            let operator = buf.get_i8()?;
+            let priority = priority(operator);
+
+            let distance = if operator == OP_PHRASE {
+                buf.get_i16::<BigEndian>()?
+            } else {
+                0
+            };
+
+            let parenthesize =
+                priority < parent_priority || (operator == OP_PHRASE && right_of_phrase);
+
+            if parenthesize {
+                s.push_str("( ");
+            }
+
+            if operator == OP_NOT {
+                s.push('!');
+                write_tsquery_item(buf, s, priority, false)?;
+            } else {
+                // the right operand comes first
+                let mut right = String::new();
+                write_tsquery_item(buf, &mut right, priority, operator == OP_PHRASE)?;
+                write_tsquery_item(buf, s, priority, false)?;
+
+                match operator {
+                    OP_AND => s.push_str(" & "),
+                    OP_OR => s.push_str(" | "),
+                    OP_PHRASE if distance == 1 => s.push_str(" <-> "),
+                    OP_PHRASE => {
+                        let _ = write!(s, " <{}> ", distance);
+                    }
+
+                    _ => return Err(decode_err!("unknown TSQUERY operator: {}", operator)),
+                }
+
+                s.push_str(&right);
+            }
+
+            if parenthesize {
+                s.push_str(" )");
+            }
+        }
+
+        ty => return Err(decode_err!("unknown TSQUERY item type: {}", ty)),
+    }
+
+    Ok(())
+}
+
+// 'lexeme' with any `'` or `\` doubled
+fn write_quoted(s: &mut String, value: &str) {
+    s.push('\'');
+
+    for ch in value.chars() {
+        if ch == '\'' || ch == '\\' {
+            s.push(ch);
+        }
+
+        s.push(ch);
+    }
+
+    s.push('\'');
+}
+
+#[test]
+fn test_decode_tsvector() {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&2_i32.to_be_bytes());
+    buf.extend_from_slice(b"fox\0");
+    buf.extend_from_slice(&1_u16.to_be_bytes());
+    buf.extend_from_slice(&3_u16.to_be_bytes());
+    buf.extend_from_slice(b"it's\0");
+    buf.extend_from_slice(&2_u16.to_be_bytes());
+    buf.extend_from_slice(&(2_u16 | 3 << 14).to_be_bytes());
+    buf.extend_from_slice(&(5_u16 | 1 << 14).to_be_bytes());
+
+    let tsvector: PgTsVector = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
+    assert_eq!(tsvector.0, "'fox':3 'it''s':2A,5C");
+
+    let empty: PgTsVector = Decode::<Postgres>::decode(Some(PgValue::Binary(&[0; 4]))).unwrap();
+    assert_eq!(empty.0, "");
+}
+
+#[test]
+fn test_decode_tsquery() {
+    // 'fox' & !'dog', i.e., AND, NOT, 'dog', 'fox'
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&4_i32.to_be_bytes());
+    buf.extend_from_slice(&[QI_OPR as u8, OP_AND as u8]);
+    buf.extend_from_slice(&[QI_OPR as u8, OP_NOT as u8]);
+    buf.extend_from_slice(&[QI_VAL as u8, 0, 0]);
+    buf.extend_from_slice(b"dog\0");
+    buf.extend_from_slice(&[QI_VAL as u8, 0, 1]);
+    buf.extend_from_slice(b"fox\0");
+
+    let tsquery: PgTsQuery = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
+    assert_eq!(tsquery.0, "'fox':* & !'dog'");
+
+    let truncated = &buf[..(buf.len() - 4)];
+    assert!(<PgTsQuery as Decode<Postgres>>::decode(Some(PgValue::Binary(truncated))).is_err());
+}
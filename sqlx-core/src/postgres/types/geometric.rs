@@ -0,0 +1,103 @@
+use std::convert::TryInto;
+use std::str::FromStr;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+/// The **Postgres** `POINT` type; a point on a plane.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Type<Postgres> for PgPoint {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::POINT, "POINT")
+    }
+}
+
+impl Type<Postgres> for [PgPoint] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_POINT, "POINT[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgPoint> {
+    fn type_info() -> PgTypeInfo {
+        <[PgPoint] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for PgPoint {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        Encode::<Postgres>::encode(&self.x, buf);
+        Encode::<Postgres>::encode(&self.y, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgPoint {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let x = buf.read_f64::<NetworkEndian>().map_err(Error::decode)?;
+                let y = buf.read_f64::<NetworkEndian>().map_err(Error::decode)?;
+
+                Ok(PgPoint { x, y })
+            }
+
+            PgValue::Text(s) => parse_point(s)
+                .ok_or_else(|| Error::Decode(format!("invalid POINT value: {:?}", s).into())),
+        }
+    }
+}
+
+// (x,y)
+fn parse_point(s: &str) -> Option<PgPoint> {
+    let s = s.trim();
+
+    if !s.starts_with('(') || !s.ends_with(')') {
+        return None;
+    }
+
+    let mut parts = s[1..(s.len() - 1)].splitn(2, ',');
+
+    let x = f64::from_str(parts.next()?.trim()).ok()?;
+    let y = f64::from_str(parts.next()?.trim()).ok()?;
+
+    Some(PgPoint { x, y })
+}
+
+#[test]
+fn test_encode_point() {
+    let mut buf = Vec::new();
+
+    Encode::<Postgres>::encode(&PgPoint { x: 1.5, y: 2.5 }, &mut buf);
+
+    assert_eq!(&buf[..8], &1.5_f64.to_bits().to_be_bytes());
+    assert_eq!(&buf[8..], &2.5_f64.to_bits().to_be_bytes());
+}
+
+#[test]
+fn test_decode_point() {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&(-1.5_f64).to_bits().to_be_bytes());
+    buf.extend_from_slice(&3.0_f64.to_bits().to_be_bytes());
+
+    let point: PgPoint = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
+    assert_eq!(point, PgPoint { x: -1.5, y: 3.0 });
+
+    let point: PgPoint = Decode::<Postgres>::decode(Some(PgValue::Text("(1.5,2.5)"))).unwrap();
+    assert_eq!(point, PgPoint { x: 1.5, y: 2.5 });
+}
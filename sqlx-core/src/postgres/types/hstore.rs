@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::hash::BuildHasher;
+
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::{Buf, BufMut};
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+
+// The OID of `hstore` is assigned when the extension is created so we can't know it ahead of time
+impl<S> Type<Postgres> for HashMap<String, Option<String>, S> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("HSTORE")
+    }
+}
+
+impl<S> Encode<Postgres> for HashMap<String, Option<String>, S> {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.put_i32::<BigEndian>(self.len() as i32);
+
+        for (key, value) in self {
+            buf.put_i32::<BigEndian>(key.len() as i32);
+            buf.extend_from_slice(key.as_bytes());
+
+            if let Some(value) = value {
+                buf.put_i32::<BigEndian>(value.len() as i32);
+                buf.extend_from_slice(value.as_bytes());
+            } else {
+                buf.put_i32::<BigEndian>(-1);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        4 + self
+            .iter()
+            .map(|(key, value)| 8 + key.len() + value.as_ref().map_or(0, String::len))
+            .sum::<usize>()
+    }
+}
+
+impl<'de, S> Decode<'de, Postgres> for HashMap<String, Option<String>, S>
+where
+    S: BuildHasher + Default + 'de,
+{
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let count = buf.get_i32::<BigEndian>()?;
+                let mut map =
+                    HashMap::with_capacity_and_hasher(count.max(0) as usize, S::default());
+
+                for _ in 0..count {
+                    let key = decode_str(&mut buf)?
+                        .ok_or_else(|| crate::Error::Decode("HSTORE key cannot be NULL".into()))?;
+
+                    let value = decode_str(&mut buf)?;
+
+                    map.insert(key.to_owned(), value.map(str::to_owned));
+                }
+
+                Ok(map)
+            }
+
+            PgValue::Text(s) => parse_hstore(s),
+        }
+    }
+}
+
+fn decode_str<'de>(buf: &mut &'de [u8]) -> crate::Result<Postgres, Option<&'de str>> {
+    let len = buf.get_i32::<BigEndian>()?;
+
+    if len < 0 {
+        return Ok(None);
+    }
+
+    let len = len as usize;
+
+    if buf.len() < len {
+        return Err(decode_err!("HSTORE entry of {} bytes is truncated", len));
+    }
+
+    Ok(Some(buf.get_str(len)?))
+}
+
+// "key"=>"value", "other"=>NULL
+fn parse_hstore<S>(s: &str) -> crate::Result<Postgres, HashMap<String, Option<String>, S>>
+where
+    S: BuildHasher + Default,
+{
+    let invalid = || decode_err!("invalid HSTORE value: {:?}", s);
+
+    let mut map = HashMap::default();
+    let mut chars = s.chars().peekable();
+
+    loop {
+        while chars.peek().map_or(false, |ch| ch.is_whitespace()) {
+            chars.next();
+        }
+
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let key = parse_quoted(&mut chars).ok_or_else(invalid)?;
+
+        if chars.next() != Some('=') || chars.next() != Some('>') {
+            return Err(invalid());
+        }
+
+        let value = if chars.peek() == Some(&'"') {
+            Some(parse_quoted(&mut chars).ok_or_else(invalid)?)
+        } else {
+            let null: String = chars.by_ref().take(4).collect();
+
+            if null != "NULL" {
+                return Err(invalid());
+            }
+
+            None
+        };
+
+        map.insert(key, value);
+
+        match chars.next() {
+            Some(',') => {}
+            None => break,
+            Some(_) => return Err(invalid()),
+        }
+    }
+
+    Ok(map)
+}
+
+fn parse_quoted(chars: &mut impl Iterator<Item = char>) -> Option<String> {
+    if chars.next()? != '"' {
+        return None;
+    }
+
+    let mut value = String::new();
+
+    loop {
+        match chars.next()? {
+            '"' => return Some(value),
+            '\\' => value.push(chars.next()?),
+            ch => value.push(ch),
+        }
+    }
+}
+
+#[test]
+fn test_decode_hstore() {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&2_i32.to_be_bytes());
+    buf.extend_from_slice(&1_i32.to_be_bytes());
+    buf.extend_from_slice(b"a");
+    buf.extend_from_slice(&1_i32.to_be_bytes());
+    buf.extend_from_slice(b"1");
+    buf.extend_from_slice(&1_i32.to_be_bytes());
+    buf.extend_from_slice(b"b");
+    buf.extend_from_slice(&(-1_i32).to_be_bytes());
+
+    let map: HashMap<String, Option<String>> =
+        Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["a"], Some("1".to_owned()));
+    assert_eq!(map["b"], None);
+}
+
+#[test]
+fn test_parse_hstore() {
+    use std::collections::hash_map::RandomState;
+
+    let map: HashMap<_, _> = parse_hstore(r#""a"=>"1", "b"=>NULL, "c d"=>"x\"y""#).unwrap();
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map["a"], Some("1".to_owned()));
+    assert_eq!(map["b"], None);
+    assert_eq!(map["c d"], Some("x\"y".to_owned()));
+
+    assert!(parse_hstore::<RandomState>("").unwrap().is_empty());
+    assert!(parse_hstore::<RandomState>(r#""a"=>1"#).is_err());
+}
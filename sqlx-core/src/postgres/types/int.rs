@@ -1,4 +1,5 @@
 use std::convert::TryInto;
+use std::num::{NonZeroI16, NonZeroI32, NonZeroI64, NonZeroU16, NonZeroU32};
 use std::str::FromStr;
 
 use byteorder::{NetworkEndian, ReadBytesExt};
@@ -11,6 +12,62 @@ use crate::postgres::{PgValue, Postgres};
 use crate::types::Type;
 use crate::Error;
 
+// `"char"` is a single byte; it's sent as that byte in both formats except that the text format
+// escapes non-ASCII bytes as octal (e.g., `\377`)
+
+impl Type<Postgres> for i8 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::CHAR, "\"CHAR\"")
+    }
+}
+
+impl Type<Postgres> for [i8] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_CHAR, "\"CHAR\"[]")
+    }
+}
+impl Type<Postgres> for Vec<i8> {
+    fn type_info() -> PgTypeInfo {
+        <[i8] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for i8 {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(*self as u8);
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for i8 {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        decode_char(value).map(|byte| byte as i8)
+    }
+}
+
+fn decode_char(value: Option<PgValue<'_>>) -> crate::Result<Postgres, u8> {
+    match value.try_into()? {
+        PgValue::Binary(&[byte]) => Ok(byte),
+
+        PgValue::Binary(buf) => Err(Error::Decode(
+            format!("expected 1 byte for a \"char\", got {}", buf.len()).into(),
+        )),
+
+        PgValue::Text(s) => parse_char(s)
+            .ok_or_else(|| Error::Decode(format!("invalid \"char\" value: {:?}", s).into())),
+    }
+}
+
+fn parse_char(s: &str) -> Option<u8> {
+    match s.as_bytes() {
+        [] => Some(0),
+        [byte] => Some(*byte),
+        [b'\\', octal @ ..] if octal.len() == 3 => {
+            u8::from_str_radix(std::str::from_utf8(octal).ok()?, 8).ok()
+        }
+        _ => None,
+    }
+}
+
 impl Type<Postgres> for i16 {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::INT2, "INT2")
@@ -37,6 +94,7 @@ impl Encode<Postgres> for i16 {
 impl<'de> Decode<'de, Postgres> for i16 {
     fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
         match value.try_into()? {
+            PgValue::Binary(buf) if buf.len() != 2 => Err(other_width(2, buf.len(), "i16")),
             PgValue::Binary(mut buf) => buf.read_i16::<NetworkEndian>().map_err(Error::decode),
             PgValue::Text(s) => i16::from_str(s).map_err(Error::decode),
         }
@@ -69,6 +127,7 @@ impl Encode<Postgres> for i32 {
 impl<'de> Decode<'de, Postgres> for i32 {
     fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
         match value.try_into()? {
+            PgValue::Binary(buf) if buf.len() != 4 => Err(other_width(4, buf.len(), "i32")),
             PgValue::Binary(mut buf) => buf.read_i32::<NetworkEndian>().map_err(Error::decode),
             PgValue::Text(s) => i32::from_str(s).map_err(Error::decode),
         }
@@ -101,8 +160,354 @@ impl Encode<Postgres> for i64 {
 impl<'de> Decode<'de, Postgres> for i64 {
     fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
         match value.try_into()? {
+            PgValue::Binary(buf) if buf.len() != 8 => Err(other_width(8, buf.len(), "i64")),
             PgValue::Binary(mut buf) => buf.read_i64::<NetworkEndian>().map_err(Error::decode),
             PgValue::Text(s) => i64::from_str(s).map_err(Error::decode),
         }
     }
 }
+
+/// An `i16`, `i32` or `i64` that can be decoded from any of `INT2`, `INT4` and `INT8`.
+///
+/// The integers are only decoded from a column of their own width; `PgCheckedInt` opts in to
+/// decoding one from a narrower or wider column, e.g., of a view whose columns are wider than the
+/// model. A value that does not fit into the integer is a decode error.
+///
+/// ```rust,ignore
+/// let (id,): (PgCheckedInt<i32>,) = sqlx::query_as("SELECT 1::int8")
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// assert_eq!(id, PgCheckedInt(1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgCheckedInt<T>(pub T);
+
+macro_rules! impl_checked_int {
+    ($($int:ident),*) => {
+        $(
+            impl Type<Postgres> for PgCheckedInt<$int> {
+                fn type_info() -> PgTypeInfo {
+                    <$int as Type<Postgres>>::type_info()
+                }
+            }
+
+            impl Encode<Postgres> for PgCheckedInt<$int> {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    <$int as Encode<Postgres>>::encode(&self.0, buf)
+                }
+            }
+
+            impl<'de> Decode<'de, Postgres> for PgCheckedInt<$int> {
+                fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+                    let value = decode_signed(value)?;
+
+                    value
+                        .try_into()
+                        .map(PgCheckedInt)
+                        .map_err(|_| out_of_range(value, stringify!($int)))
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_int!(i16, i32, i64);
+
+// Postgres has no unsigned integers; `u16` is stored in the next larger INT4 and decoding
+// checks that the value is in range. `u8` can't be mapped to INT2 as `[u8]` and `Vec<u8>` are
+// BYTEA
+
+impl Type<Postgres> for u16 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INT4, "INT4")
+    }
+}
+
+impl Type<Postgres> for [u16] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_INT4, "INT4[]")
+    }
+}
+impl Type<Postgres> for Vec<u16> {
+    fn type_info() -> PgTypeInfo {
+        <[u16] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for u16 {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <i32 as Encode<Postgres>>::encode(&i32::from(*self), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        4
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for u16 {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        let value = decode_signed(value)?;
+
+        value.try_into().map_err(|_| out_of_range(value, "u16"))
+    }
+}
+
+// `u32` is an OID; it can also be decoded from an INT8 if the value is in range
+
+impl Type<Postgres> for u32 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::OID, "OID")
+    }
+}
+
+impl Type<Postgres> for [u32] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_OID, "OID[]")
+    }
+}
+impl Type<Postgres> for Vec<u32> {
+    fn type_info() -> PgTypeInfo {
+        <[u32] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for u32 {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.to_be_bytes());
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for u32 {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) if buf.len() == 4 => {
+                buf.read_u32::<NetworkEndian>().map_err(Error::decode)
+            }
+
+            value => decode_unsigned_32(Some(value)),
+        }
+    }
+
+    // 4 bytes are only read as an OID from an OID column; from an INT4 they are a signed value
+    fn decode_with_type(
+        type_info: &PgTypeInfo,
+        value: Option<PgValue<'de>>,
+    ) -> crate::Result<Postgres, Self> {
+        if type_info.id == TypeId::OID {
+            Self::decode(value)
+        } else {
+            decode_unsigned_32(value)
+        }
+    }
+}
+
+fn decode_unsigned_32(value: Option<PgValue<'_>>) -> crate::Result<Postgres, u32> {
+    let value = decode_signed(value)?;
+
+    value.try_into().map_err(|_| out_of_range(value, "u32"))
+}
+
+// The `NonZero` integers are stored like the integer they wrap; decoding a zero is an error
+
+macro_rules! impl_non_zero {
+    ($($ty:ident($int:ty)),*) => {
+        $(
+            impl Type<Postgres> for $ty {
+                fn type_info() -> PgTypeInfo {
+                    <$int as Type<Postgres>>::type_info()
+                }
+            }
+
+            impl Encode<Postgres> for $ty {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    <$int as Encode<Postgres>>::encode(&self.get(), buf)
+                }
+
+                fn size_hint(&self) -> usize {
+                    <$int as Encode<Postgres>>::size_hint(&self.get())
+                }
+            }
+
+            impl<'de> Decode<'de, Postgres> for $ty {
+                fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+                    $ty::new(<$int as Decode<Postgres>>::decode(value)?)
+                        .ok_or_else(|| out_of_range(0, stringify!($ty)))
+                }
+
+                fn decode_with_type(
+                    type_info: &PgTypeInfo,
+                    value: Option<PgValue<'de>>,
+                ) -> crate::Result<Postgres, Self> {
+                    $ty::new(<$int as Decode<Postgres>>::decode_with_type(type_info, value)?)
+                        .ok_or_else(|| out_of_range(0, stringify!($ty)))
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero!(
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroU16(u16),
+    NonZeroU32(u32)
+);
+
+// An INT2, INT4 or INT8 in either format; the binary format is told apart by its length
+fn decode_signed(value: Option<PgValue<'_>>) -> crate::Result<Postgres, i64> {
+    match value.try_into()? {
+        PgValue::Binary(mut buf) => match buf.len() {
+            2 => buf.read_i16::<NetworkEndian>().map(i64::from),
+            4 => buf.read_i32::<NetworkEndian>().map(i64::from),
+            8 => buf.read_i64::<NetworkEndian>(),
+
+            len => {
+                return Err(Error::Decode(
+                    format!("expected 2, 4 or 8 bytes for an integer, got {}", len).into(),
+                ))
+            }
+        }
+        .map_err(Error::decode),
+
+        PgValue::Text(s) => i64::from_str(s).map_err(Error::decode),
+    }
+}
+
+// e.g., an INT8 decoded into an `i32`
+fn other_width(width: usize, len: usize, ty: &str) -> crate::Error<Postgres> {
+    Error::Decode(
+        format!(
+            "expected {} bytes for `{}`, got {}; decode a `PgCheckedInt<{}>` to convert across widths",
+            width, ty, len, ty
+        )
+        .into(),
+    )
+}
+
+fn out_of_range(value: i64, ty: &str) -> crate::Error<Postgres> {
+    Error::Decode(format!("{} is out of range for `{}`", value, ty).into())
+}
+
+#[test]
+fn test_decode_char() {
+    let decode = |value| <i8 as Decode<Postgres>>::decode(Some(value));
+
+    assert_eq!(decode(PgValue::Binary(b"A")).unwrap(), 65);
+    assert_eq!(decode(PgValue::Binary(&[0xFF])).unwrap(), -1);
+    assert_eq!(decode(PgValue::Text("A")).unwrap(), 65);
+    assert_eq!(decode(PgValue::Text("\\377")).unwrap(), -1);
+    assert_eq!(decode(PgValue::Text("")).unwrap(), 0);
+
+    assert!(decode(PgValue::Text("AB")).is_err());
+    assert!(decode(PgValue::Binary(b"")).is_err());
+    assert!(decode(PgValue::Binary(b"AB")).is_err());
+}
+
+#[test]
+fn test_decode_unsigned() {
+    let oid = PgTypeInfo::new(TypeId::OID, "OID");
+    let int4 = PgTypeInfo::new(TypeId::INT4, "INT4");
+    let int8 = PgTypeInfo::new(TypeId::INT8, "INT8");
+
+    let decode_u16 = |buf: &[u8]| <u16 as Decode<Postgres>>::decode(Some(PgValue::Binary(buf)));
+    let decode_u32 = |type_info: &PgTypeInfo, buf: &[u8]| {
+        <u32 as Decode<Postgres>>::decode_with_type(type_info, Some(PgValue::Binary(buf)))
+    };
+
+    assert_eq!(decode_u16(&65535_i32.to_be_bytes()).unwrap(), 65535);
+    assert_eq!(
+        <u16 as Decode<Postgres>>::decode(Some(PgValue::Text("42"))).unwrap(),
+        42
+    );
+
+    assert!(decode_u16(&65536_i32.to_be_bytes()).is_err());
+    assert!(decode_u16(&(-1_i32).to_be_bytes()).is_err());
+    assert!(<u16 as Decode<Postgres>>::decode(Some(PgValue::Text("-1"))).is_err());
+
+    assert_eq!(decode_u32(&oid, &u32::MAX.to_be_bytes()).unwrap(), u32::MAX);
+    assert_eq!(
+        <u32 as Decode<Postgres>>::decode(Some(PgValue::Binary(&[0, 0, 0, 26]))).unwrap(),
+        26
+    );
+
+    assert_eq!(
+        decode_u32(&int8, &4_294_967_295_i64.to_be_bytes()).unwrap(),
+        u32::MAX
+    );
+    assert_eq!(decode_u32(&int4, &5_i32.to_be_bytes()).unwrap(), 5);
+    assert_eq!(
+        <u32 as Decode<Postgres>>::decode(Some(PgValue::Text("4294967295"))).unwrap(),
+        u32::MAX
+    );
+
+    assert!(decode_u32(&int8, &4_294_967_296_i64.to_be_bytes()).is_err());
+    assert!(decode_u32(&int8, &(-1_i64).to_be_bytes()).is_err());
+    assert!(decode_u32(&int4, &(-1_i32).to_be_bytes()).is_err());
+    assert!(<u32 as Decode<Postgres>>::decode(Some(PgValue::Text("-1"))).is_err());
+}
+
+#[test]
+fn test_decode_non_zero() {
+    let decode_i32 = |value| <NonZeroI32 as Decode<Postgres>>::decode(Some(value));
+    let decode_u32 = |value| <NonZeroU32 as Decode<Postgres>>::decode(Some(value));
+
+    let minus_five = (-5_i32).to_be_bytes();
+    let zero = 0_i32.to_be_bytes();
+    let zero_oid = 0_u32.to_be_bytes();
+
+    assert_eq!(
+        decode_i32(PgValue::Binary(&minus_five)).unwrap(),
+        NonZeroI32::new(-5).unwrap()
+    );
+    assert_eq!(
+        decode_u32(PgValue::Text("7")).unwrap(),
+        NonZeroU32::new(7).unwrap()
+    );
+
+    assert!(decode_i32(PgValue::Binary(&zero)).is_err());
+    assert!(decode_i32(PgValue::Text("0")).is_err());
+    assert!(decode_u32(PgValue::Binary(&zero_oid)).is_err());
+
+    // the type of the column is passed on to the wrapped integer
+    let int4 = PgTypeInfo::new(TypeId::INT4, "INT4");
+    let minus_one = (-1_i32).to_be_bytes();
+
+    assert!(<NonZeroU32 as Decode<Postgres>>::decode_with_type(
+        &int4,
+        Some(PgValue::Binary(&minus_one))
+    )
+    .is_err());
+}
+
+#[test]
+fn test_decode_signed_across_widths() {
+    fn decode<T>(buf: &[u8]) -> crate::Result<Postgres, T>
+    where
+        PgCheckedInt<T>: for<'de> Decode<'de, Postgres>,
+    {
+        <PgCheckedInt<T> as Decode<Postgres>>::decode(Some(PgValue::Binary(buf))).map(|int| int.0)
+    }
+
+    // widening
+    assert_eq!(decode::<i64>(&(-2_i16).to_be_bytes()).unwrap(), -2);
+    assert_eq!(
+        decode::<i64>(&i32::MIN.to_be_bytes()).unwrap(),
+        i32::MIN as i64
+    );
+
+    // narrowing
+    assert_eq!(decode::<i32>(&(-5_i64).to_be_bytes()).unwrap(), -5);
+    assert_eq!(decode::<i16>(&32767_i64.to_be_bytes()).unwrap(), 32767);
+    assert_eq!(
+        <PgCheckedInt<i16> as Decode<Postgres>>::decode(Some(PgValue::Text("-32768"))).unwrap(),
+        PgCheckedInt(-32768)
+    );
+
+    assert!(decode::<i32>(&(i32::MAX as i64 + 1).to_be_bytes()).is_err());
+    assert!(decode::<i16>(&(-32769_i32).to_be_bytes()).is_err());
+    assert!(<PgCheckedInt<i16> as Decode<Postgres>>::decode(Some(PgValue::Text("32768"))).is_err());
+
+    assert!(decode::<i32>(&[0, 0, 1]).is_err());
+}
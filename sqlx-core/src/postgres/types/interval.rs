@@ -0,0 +1,311 @@
+use std::convert::{TryFrom, TryInto};
+use std::mem;
+use std::time::Duration as StdDuration;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_DAY: i64 = 86_400 * MICROS_PER_SECOND;
+
+/// The three components of an `INTERVAL` as they are sent over the wire.
+///
+/// Postgres keeps months and days separate from the time component as their length in
+/// absolute time is not fixed. When folding an interval into a `Duration`, a day is taken to
+/// be exactly 24 hours and an interval with a non-zero months component is rejected.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Interval {
+    months: i32,
+    days: i32,
+    microseconds: i64,
+}
+
+impl Interval {
+    fn from_micros(microseconds: i64) -> Self {
+        Self {
+            months: 0,
+            days: 0,
+            microseconds,
+        }
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.microseconds.to_be_bytes());
+        buf.extend_from_slice(&self.days.to_be_bytes());
+        buf.extend_from_slice(&self.months.to_be_bytes());
+    }
+
+    fn decode(value: Option<PgValue<'_>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let microseconds = buf.read_i64::<NetworkEndian>().map_err(Error::decode)?;
+                let days = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+                let months = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+
+                Ok(Self {
+                    months,
+                    days,
+                    microseconds,
+                })
+            }
+
+            PgValue::Text(s) => Self::parse(s),
+        }
+    }
+
+    // Parses the default (`IntervalStyle = postgres`) text output,
+    // e.g., `1 year 2 mons -3 days +04:05:06.789`
+    fn parse(s: &str) -> crate::Result<Postgres, Self> {
+        let invalid = || Error::Decode(format!("invalid INTERVAL value: {:?}", s).into());
+
+        let mut interval = Interval::default();
+        let mut tokens = s.split_whitespace();
+
+        while let Some(token) = tokens.next() {
+            if token.contains(':') {
+                interval.microseconds = parse_time(token).ok_or_else(invalid)?;
+                continue;
+            }
+
+            let value: i32 = token.parse().map_err(|_| invalid())?;
+
+            match tokens.next().ok_or_else(invalid)? {
+                "year" | "years" => interval.months += value.checked_mul(12).ok_or_else(invalid)?,
+                "mon" | "mons" => interval.months += value,
+                "day" | "days" => interval.days += value,
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(interval)
+    }
+
+    fn to_micros(&self) -> crate::Result<Postgres, i64> {
+        if self.months != 0 {
+            return Err(Error::Decode(
+                format!(
+                    "INTERVAL with a months component ({} months) cannot be represented as a Duration",
+                    self.months
+                )
+                .into(),
+            ));
+        }
+
+        i64::from(self.days)
+            .checked_mul(MICROS_PER_DAY)
+            .and_then(|micros| micros.checked_add(self.microseconds))
+            .ok_or_else(|| Error::Decode("INTERVAL out of range for Duration".into()))
+    }
+}
+
+// Parses `[+-]HH:MM:SS[.ffffff]` into microseconds
+fn parse_time(s: &str) -> Option<i64> {
+    let (negative, s) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let mut parts = s.splitn(3, ':');
+
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let minutes: i64 = parts.next()?.parse().ok()?;
+
+    let (seconds, fraction) = match parts.next() {
+        Some(seconds) => {
+            let mut seconds = seconds.splitn(2, '.');
+            (seconds.next()?, seconds.next())
+        }
+
+        None => ("0", None),
+    };
+
+    let seconds: i64 = seconds.parse().ok()?;
+
+    let fraction: i64 = match fraction {
+        Some(fraction) if fraction.len() <= 6 => {
+            let digits: i64 = fraction.parse().ok()?;
+            digits * 10_i64.pow(6 - fraction.len() as u32)
+        }
+
+        Some(_) => return None,
+        None => 0,
+    };
+
+    let micros = hours
+        .checked_mul(3600)?
+        .checked_add(minutes * 60 + seconds)?
+        .checked_mul(MICROS_PER_SECOND)?
+        .checked_add(fraction)?;
+
+    Some(if negative { -micros } else { micros })
+}
+
+impl Type<Postgres> for StdDuration {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INTERVAL, "INTERVAL")
+    }
+}
+
+impl Type<Postgres> for [StdDuration] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_INTERVAL, "INTERVAL[]")
+    }
+}
+
+impl Type<Postgres> for Vec<StdDuration> {
+    fn type_info() -> PgTypeInfo {
+        <[StdDuration] as Type<Postgres>>::type_info()
+    }
+}
+
+/// ### Panics
+/// If the duration, in microseconds, overflows `i64`.
+impl Encode<Postgres> for StdDuration {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let micros = i64::try_from(self.as_micros())
+            .unwrap_or_else(|_| panic!("Duration out of range for Postgres: {:?}", self));
+
+        Interval::from_micros(micros).encode(buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>() + 2 * mem::size_of::<i32>()
+    }
+}
+
+/// ### Note
+/// Negative intervals and intervals with a months component will fail to decode.
+impl<'de> Decode<'de, Postgres> for StdDuration {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        let micros = Interval::decode(value)?.to_micros()?;
+
+        if micros < 0 {
+            return Err(Error::Decode(
+                "negative INTERVAL cannot be represented as std::time::Duration".into(),
+            ));
+        }
+
+        Ok(StdDuration::from_micros(micros as u64))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for chrono::Duration {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INTERVAL, "INTERVAL")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for [chrono::Duration] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_INTERVAL, "INTERVAL[]")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for Vec<chrono::Duration> {
+    fn type_info() -> PgTypeInfo {
+        <[chrono::Duration] as Type<Postgres>>::type_info()
+    }
+}
+
+/// ### Panics
+/// If the duration, in microseconds, overflows `i64`.
+#[cfg(feature = "chrono")]
+impl Encode<Postgres> for chrono::Duration {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let micros = self
+            .num_microseconds()
+            .unwrap_or_else(|| panic!("Duration out of range for Postgres: {:?}", self));
+
+        Interval::from_micros(micros).encode(buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>() + 2 * mem::size_of::<i32>()
+    }
+}
+
+/// ### Note
+/// Intervals with a months component will fail to decode.
+#[cfg(feature = "chrono")]
+impl<'de> Decode<'de, Postgres> for chrono::Duration {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        Ok(chrono::Duration::microseconds(
+            Interval::decode(value)?.to_micros()?,
+        ))
+    }
+}
+
+#[test]
+fn test_encode_interval() {
+    let mut buf = Vec::new();
+
+    Encode::<Postgres>::encode(&StdDuration::from_secs(0), &mut buf);
+    assert_eq!(buf, [0; 16]);
+    buf.clear();
+
+    // days are never produced; the whole duration goes into the microseconds field
+    Encode::<Postgres>::encode(&StdDuration::from_secs(86_400 + 1), &mut buf);
+    assert_eq!(&buf[..8], &(86_401_000_000_i64).to_be_bytes());
+    assert_eq!(&buf[8..], &[0; 8]);
+}
+
+#[test]
+fn test_decode_interval() {
+    // 1 day 2 hours
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&(2 * 3600 * MICROS_PER_SECOND).to_be_bytes());
+    buf.extend_from_slice(&1_i32.to_be_bytes());
+    buf.extend_from_slice(&0_i32.to_be_bytes());
+
+    let duration: StdDuration = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
+    assert_eq!(duration, StdDuration::from_secs(26 * 3600));
+
+    // 1 mon
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&0_i64.to_be_bytes());
+    buf.extend_from_slice(&0_i32.to_be_bytes());
+    buf.extend_from_slice(&1_i32.to_be_bytes());
+
+    assert!(<StdDuration as Decode<Postgres>>::decode(Some(PgValue::Binary(&buf))).is_err());
+}
+
+#[test]
+fn test_parse_interval() {
+    assert_eq!(
+        Interval::parse("1 day 02:00:00").unwrap(),
+        Interval {
+            months: 0,
+            days: 1,
+            microseconds: 2 * 3600 * MICROS_PER_SECOND,
+        }
+    );
+
+    assert_eq!(
+        Interval::parse("1 year 2 mons -3 days +04:05:06.789").unwrap(),
+        Interval {
+            months: 14,
+            days: -3,
+            microseconds: (4 * 3600 + 5 * 60 + 6) * MICROS_PER_SECOND + 789_000,
+        }
+    );
+
+    assert_eq!(
+        Interval::parse("-00:00:01.5").unwrap(),
+        Interval::from_micros(-1_500_000)
+    );
+
+    assert_eq!(Interval::parse("00:00:00").unwrap(), Interval::default());
+
+    assert!(Interval::parse("1 fortnight").is_err());
+}
@@ -0,0 +1,233 @@
+use std::convert::TryInto;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::row::PgValue;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::Postgres;
+use crate::types::Type;
+use crate::Error;
+
+#[cfg(windows)]
+const AF_INET: u8 = 2;
+// Maybe not used, but defining to follow Rust's libstd/net/sys
+#[cfg(redox)]
+const AF_INET: u8 = 1;
+#[cfg(not(any(windows, redox)))]
+const AF_INET: u8 = libc::AF_INET as u8;
+
+const PGSQL_AF_INET: u8 = AF_INET;
+const PGSQL_AF_INET6: u8 = AF_INET + 1;
+
+const INET_TYPE: u8 = 0;
+const CIDR_TYPE: u8 = 1;
+
+// The binary format of INET and CIDR is the address family, the number of bits in the
+// netmask, whether it is a CIDR, the number of bytes in the address and then the address
+pub(super) fn encode_inet(buf: &mut Vec<u8>, ip: IpAddr, prefix: u8) {
+    match ip {
+        IpAddr::V4(ip) => {
+            buf.push(PGSQL_AF_INET);
+            buf.push(prefix);
+            buf.push(INET_TYPE);
+            buf.push(4);
+            buf.extend_from_slice(&ip.octets());
+        }
+
+        IpAddr::V6(ip) => {
+            buf.push(PGSQL_AF_INET6);
+            buf.push(prefix);
+            buf.push(INET_TYPE);
+            buf.push(16);
+            buf.extend_from_slice(&ip.octets());
+        }
+    }
+}
+
+// Returns the address and the number of bits in its netmask
+pub(super) fn decode_inet(bytes: &[u8]) -> crate::Result<Postgres, (IpAddr, u8)> {
+    if bytes.len() < 8 {
+        return Err(Error::Decode("Input too short".into()));
+    }
+
+    let af = bytes[0];
+    let prefix = bytes[1];
+    let net_type = bytes[2];
+    let len = bytes[3];
+
+    if net_type == INET_TYPE || net_type == CIDR_TYPE {
+        if af == PGSQL_AF_INET && bytes.len() == 8 && len == 4 {
+            let ip = Ipv4Addr::new(bytes[4], bytes[5], bytes[6], bytes[7]);
+
+            return Ok((IpAddr::V4(ip), prefix));
+        }
+
+        if af == PGSQL_AF_INET6 && bytes.len() == 20 && len == 16 {
+            let mut octets = [0; 16];
+            octets.copy_from_slice(&bytes[4..]);
+
+            return Ok((IpAddr::V6(Ipv6Addr::from(octets)), prefix));
+        }
+    }
+
+    return Err(Error::Decode("Invalid input".into()));
+}
+
+fn max_prefix(ip: &IpAddr) -> u8 {
+    match ip {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+// A host address is an INET (or CIDR) whose netmask covers the whole address
+fn decode_host(value: Option<PgValue<'_>>) -> crate::Result<Postgres, IpAddr> {
+    let (ip, prefix) = match value.try_into()? {
+        PgValue::Binary(buf) => decode_inet(buf)?,
+
+        PgValue::Text(s) => {
+            let mut parts = s.splitn(2, '/');
+            let ip: IpAddr = parts
+                .next()
+                .unwrap_or_default()
+                .parse()
+                .map_err(Error::decode)?;
+
+            let prefix = match parts.next() {
+                Some(prefix) => prefix.parse().map_err(Error::decode)?,
+                None => max_prefix(&ip),
+            };
+
+            (ip, prefix)
+        }
+    };
+
+    if prefix != max_prefix(&ip) {
+        return Err(Error::Decode(
+            format!(
+                "expected a host address, got the network {}/{}; decode it into `IpNetwork`",
+                ip, prefix
+            )
+            .into(),
+        ));
+    }
+
+    Ok(ip)
+}
+
+impl Type<Postgres> for IpAddr {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INET, "INET")
+    }
+}
+
+impl Type<Postgres> for [IpAddr] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_INET, "INET[]")
+    }
+}
+
+impl Type<Postgres> for Vec<IpAddr> {
+    fn type_info() -> PgTypeInfo {
+        <[IpAddr] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for IpAddr {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_inet(buf, *self, max_prefix(self));
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            IpAddr::V4(_) => 8,
+            IpAddr::V6(_) => 20,
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for IpAddr {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        decode_host(value)
+    }
+}
+
+impl Type<Postgres> for Ipv4Addr {
+    fn type_info() -> PgTypeInfo {
+        <IpAddr as Type<Postgres>>::type_info()
+    }
+}
+
+impl Type<Postgres> for [Ipv4Addr] {
+    fn type_info() -> PgTypeInfo {
+        <[IpAddr] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Type<Postgres> for Vec<Ipv4Addr> {
+    fn type_info() -> PgTypeInfo {
+        <[IpAddr] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for Ipv4Addr {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        IpAddr::V4(*self).encode(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for Ipv4Addr {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match decode_host(value)? {
+            IpAddr::V4(ip) => Ok(ip),
+            IpAddr::V6(ip) => Err(Error::Decode(
+                format!("expected an IPv4 address, got {}", ip).into(),
+            )),
+        }
+    }
+}
+
+impl Type<Postgres> for Ipv6Addr {
+    fn type_info() -> PgTypeInfo {
+        <IpAddr as Type<Postgres>>::type_info()
+    }
+}
+
+impl Type<Postgres> for [Ipv6Addr] {
+    fn type_info() -> PgTypeInfo {
+        <[IpAddr] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Type<Postgres> for Vec<Ipv6Addr> {
+    fn type_info() -> PgTypeInfo {
+        <[IpAddr] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for Ipv6Addr {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        IpAddr::V6(*self).encode(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        20
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for Ipv6Addr {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match decode_host(value)? {
+            IpAddr::V6(ip) => Ok(ip),
+            IpAddr::V4(ip) => Err(Error::Decode(
+                format!("expected an IPv6 address, got {}", ip).into(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::convert::TryInto;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+/// The **Postgres** `PG_LSN` type; a log sequence number, i.e., a position in the write-ahead log.
+///
+/// Formats as, and parses from, the two 32-bit halves in hex separated by a slash
+/// (e.g., `16/B374D848`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgLsn(pub u64);
+
+impl Type<Postgres> for PgLsn {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::PG_LSN, "PG_LSN")
+    }
+}
+
+impl Type<Postgres> for [PgLsn] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_PG_LSN, "PG_LSN[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgLsn> {
+    fn type_info() -> PgTypeInfo {
+        <[PgLsn] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for PgLsn {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0.to_be_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgLsn {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => buf
+                .read_u64::<NetworkEndian>()
+                .map_err(Error::decode)
+                .map(PgLsn),
+
+            PgValue::Text(s) => s.parse(),
+        }
+    }
+}
+
+impl Display for PgLsn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:X}/{:X}", self.0 >> 32, self.0 as u32)
+    }
+}
+
+impl FromStr for PgLsn {
+    type Err = crate::Error<Postgres>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::Decode(format!("invalid PG_LSN value: {:?}", s).into());
+
+        let mut parts = s.splitn(2, '/');
+        let mut half = || {
+            parts
+                .next()
+                .filter(|part| {
+                    !part.is_empty()
+                        && part.len() <= 8
+                        && part.chars().all(|ch| ch.is_ascii_hexdigit())
+                })
+                .and_then(|part| u32::from_str_radix(part, 16).ok())
+                .ok_or_else(invalid)
+        };
+
+        let hi = half()?;
+        let lo = half()?;
+
+        Ok(PgLsn(u64::from(hi) << 32 | u64::from(lo)))
+    }
+}
+
+#[test]
+fn test_display_lsn() {
+    let lsn = PgLsn(0x16_B374_D848);
+
+    assert_eq!(lsn.to_string(), "16/B374D848");
+    assert_eq!("16/B374D848".parse::<PgLsn>().unwrap(), lsn);
+    assert_eq!("16/b374d848".parse::<PgLsn>().unwrap(), lsn);
+    assert_eq!(PgLsn(0).to_string(), "0/0");
+    assert_eq!(PgLsn(u64::max_value()).to_string(), "FFFFFFFF/FFFFFFFF");
+
+    assert!("16".parse::<PgLsn>().is_err());
+    assert!("16/".parse::<PgLsn>().is_err());
+    assert!("16/B374D848/0".parse::<PgLsn>().is_err());
+    assert!("100000000/0".parse::<PgLsn>().is_err());
+    assert!("+16/0".parse::<PgLsn>().is_err());
+}
@@ -0,0 +1,140 @@
+use std::convert::TryInto;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::Buf;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+// the only version of the binary format so far
+const VERSION: u8 = 1;
+
+/// The **Postgres** `ltree` type from the extension of the same name; a path of labels
+/// separated by dots, e.g., `Top.Science.Astronomy`.
+///
+/// Each label is made up of alphanumeric characters and underscores. The path is checked when it
+/// is parsed so an invalid path is never sent to the server.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgLtree(String);
+
+impl PgLtree {
+    /// Creates an `ltree` from a path of dot-separated labels.
+    pub fn new(path: impl Into<String>) -> crate::Result<Postgres, Self> {
+        let path = path.into();
+
+        if !is_valid_path(&path) {
+            return Err(Error::Decode(
+                format!("invalid LTREE value: {:?}", path).into(),
+            ));
+        }
+
+        Ok(PgLtree(path))
+    }
+
+    /// The path as text.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns an iterator over the labels of the path.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.0.split('.').filter(|label| !label.is_empty())
+    }
+}
+
+// an empty path is valid; otherwise `label(.label)*`
+fn is_valid_path(path: &str) -> bool {
+    path.is_empty()
+        || path.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|ch| ch.is_alphanumeric() || ch == '_')
+        })
+}
+
+impl Display for PgLtree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for PgLtree {
+    type Err = crate::Error<Postgres>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PgLtree::new(s)
+    }
+}
+
+// The OID of `ltree` is assigned when the extension is created so we can't know it ahead of time
+impl Type<Postgres> for PgLtree {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("LTREE")
+    }
+}
+
+impl Encode<Postgres> for PgLtree {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(VERSION);
+        buf.extend_from_slice(self.0.as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + self.0.len()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgLtree {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let version = buf.get_u8()?;
+
+                if version != VERSION {
+                    return Err(decode_err!("unsupported LTREE version: {}", version));
+                }
+
+                let path = buf.get_str(buf.len())?;
+
+                Ok(PgLtree(path.to_owned()))
+            }
+
+            PgValue::Text(s) => Ok(PgLtree(s.to_owned())),
+        }
+    }
+}
+
+#[test]
+fn test_parse_ltree() {
+    let ltree: PgLtree = "Top.Science.Astronomy".parse().unwrap();
+
+    assert_eq!(ltree.as_str(), "Top.Science.Astronomy");
+    assert_eq!(
+        ltree.labels().collect::<Vec<_>>(),
+        ["Top", "Science", "Astronomy"]
+    );
+
+    assert!(PgLtree::new("").unwrap().labels().next().is_none());
+    assert!(PgLtree::new("snake_case.Label2").is_ok());
+
+    assert!(PgLtree::new(".Top").is_err());
+    assert!(PgLtree::new("Top..Science").is_err());
+    assert!(PgLtree::new("Top.").is_err());
+    assert!(PgLtree::new("Top.Sci-ence").is_err());
+    assert!(PgLtree::new("Top Science").is_err());
+}
+
+#[test]
+fn test_encode_ltree() {
+    let mut buf = Vec::new();
+    Encode::<Postgres>::encode(&PgLtree::new("a.b").unwrap(), &mut buf);
+    assert_eq!(buf, b"\x01a.b");
+
+    let ltree: PgLtree = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
+    assert_eq!(ltree.as_str(), "a.b");
+
+    let buf = b"\x02a.b";
+    assert!(<PgLtree as Decode<Postgres>>::decode(Some(PgValue::Binary(buf))).is_err());
+}
@@ -0,0 +1,210 @@
+use std::convert::TryInto;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+/// The **Postgres** `MACADDR` type; a 6-byte MAC address.
+///
+/// Formats as, and parses from, colon-separated hex (e.g., `08:00:2b:01:02:03`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgMacAddr(pub [u8; 6]);
+
+/// The **Postgres** `MACADDR8` type; an 8-byte (EUI-64) MAC address.
+///
+/// Formats as, and parses from, colon-separated hex (e.g., `08:00:2b:01:02:03:04:05`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgMacAddr8(pub [u8; 8]);
+
+impl Type<Postgres> for PgMacAddr {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::MACADDR, "MACADDR")
+    }
+}
+
+impl Type<Postgres> for [PgMacAddr] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_MACADDR, "MACADDR[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgMacAddr> {
+    fn type_info() -> PgTypeInfo {
+        <[PgMacAddr] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for PgMacAddr {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0);
+    }
+
+    fn size_hint(&self) -> usize {
+        6
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgMacAddr {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(buf) => buf.try_into().map(PgMacAddr).map_err(|_| {
+                Error::Decode(format!("expected 6 bytes for MACADDR, got {}", buf.len()).into())
+            }),
+
+            PgValue::Text(s) => s.parse(),
+        }
+    }
+}
+
+impl Display for PgMacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_mac(&self.0, f)
+    }
+}
+
+impl FromStr for PgMacAddr {
+    type Err = crate::Error<Postgres>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut addr = [0; 6];
+
+        parse_mac(s, &mut addr)
+            .map(|_| PgMacAddr(addr))
+            .ok_or_else(|| Error::Decode(format!("invalid MACADDR value: {:?}", s).into()))
+    }
+}
+
+impl Type<Postgres> for PgMacAddr8 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::MACADDR8, "MACADDR8")
+    }
+}
+
+impl Type<Postgres> for [PgMacAddr8] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_MACADDR8, "MACADDR8[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgMacAddr8> {
+    fn type_info() -> PgTypeInfo {
+        <[PgMacAddr8] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for PgMacAddr8 {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0);
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgMacAddr8 {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(buf) => buf.try_into().map(PgMacAddr8).map_err(|_| {
+                Error::Decode(format!("expected 8 bytes for MACADDR8, got {}", buf.len()).into())
+            }),
+
+            PgValue::Text(s) => s.parse(),
+        }
+    }
+}
+
+impl Display for PgMacAddr8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_mac(&self.0, f)
+    }
+}
+
+impl FromStr for PgMacAddr8 {
+    type Err = crate::Error<Postgres>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut addr = [0; 8];
+
+        parse_mac(s, &mut addr)
+            .map(|_| PgMacAddr8(addr))
+            .ok_or_else(|| Error::Decode(format!("invalid MACADDR8 value: {:?}", s).into()))
+    }
+}
+
+fn fmt_mac(addr: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, byte) in addr.iter().enumerate() {
+        if i > 0 {
+            f.write_str(":")?;
+        }
+
+        write!(f, "{:02x}", byte)?;
+    }
+
+    Ok(())
+}
+
+// 08:00:2b:01:02:03 or 08-00-2b-01-02-03
+fn parse_mac(s: &str, addr: &mut [u8]) -> Option<()> {
+    let mut parts = s.split(|c| c == ':' || c == '-');
+
+    for byte in addr.iter_mut() {
+        let part = parts.next()?;
+
+        if part.len() != 2 {
+            return None;
+        }
+
+        *byte = u8::from_str_radix(part, 16).ok()?;
+    }
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(())
+}
+
+#[test]
+fn test_display_macaddr() {
+    let addr = PgMacAddr([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03]);
+
+    assert_eq!(addr.to_string(), "08:00:2b:01:02:03");
+    assert_eq!("08:00:2b:01:02:03".parse::<PgMacAddr>().unwrap(), addr);
+    assert_eq!("08-00-2B-01-02-03".parse::<PgMacAddr>().unwrap(), addr);
+
+    assert!("08:00:2b:01:02".parse::<PgMacAddr>().is_err());
+    assert!("08:00:2b:01:02:03:04".parse::<PgMacAddr>().is_err());
+    assert!("08:00:2b:01:02:zz".parse::<PgMacAddr>().is_err());
+}
+
+#[test]
+fn test_display_macaddr8() {
+    let addr = PgMacAddr8([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03, 0x04, 0x05]);
+
+    assert_eq!(addr.to_string(), "08:00:2b:01:02:03:04:05");
+    assert_eq!(
+        "08:00:2b:01:02:03:04:05".parse::<PgMacAddr8>().unwrap(),
+        addr
+    );
+
+    assert!("08:00:2b:01:02:03".parse::<PgMacAddr8>().is_err());
+}
+
+#[test]
+fn test_macaddr_types_are_distinct() {
+    use crate::types::TypeInfo;
+
+    let macaddr = <PgMacAddr as Type<Postgres>>::type_info();
+    let macaddr8 = <PgMacAddr8 as Type<Postgres>>::type_info();
+
+    assert!(macaddr.compatible(&macaddr));
+    assert!(!macaddr.compatible(&macaddr8));
+    assert!(!macaddr8.compatible(&macaddr));
+}
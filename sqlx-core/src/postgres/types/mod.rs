@@ -5,13 +5,48 @@
 //! | Rust type                             | Postgres type(s)                                     |
 //! |---------------------------------------|------------------------------------------------------|
 //! | `bool`                                | BOOL                                                 |
+//! | `i8`                                  | "CHAR"                                               |
 //! | `i16`                                 | SMALLINT, SMALLSERIAL, INT2                          |
 //! | `i32`                                 | INT, SERIAL, INT4                                    |
 //! | `i64`                                 | BIGINT, BIGSERIAL, INT8                              |
+//! | `u16`                                 | INT4                                                 |
+//! | `u32`                                 | OID                                                  |
 //! | `f32`                                 | REAL, FLOAT4                                         |
 //! | `f64`                                 | DOUBLE PRECISION, FLOAT8                             |
-//! | `&str`, `String`                      | VARCHAR, CHAR(N), TEXT, CITEXT, NAME                 |
+//! | `&str`, `String`, `Cow<str>`          | VARCHAR, CHAR(N), TEXT, CITEXT, NAME, XML            |
 //! | `&[u8]`, `Vec<u8>`                    | BYTEA                                                |
+//! | `std::time::Duration`                 | INTERVAL                                             |
+//! | `PgCheckedInt<T>`                     | INT2, INT4, INT8                                     |
+//! | `PgMoney`                             | MONEY                                                |
+//! | `PgNumeric`                           | NUMERIC                                              |
+//! | `PgPoint`                             | POINT                                                |
+//! | `PgBits`                              | BIT, VARBIT                                          |
+//! | `PgMacAddr`                           | MACADDR                                              |
+//! | `PgMacAddr8`                          | MACADDR8                                             |
+//! | `PgLsn`                               | PG_LSN                                               |
+//! | `PgTsVector`                          | TSVECTOR                                             |
+//! | `PgTsQuery`                           | TSQUERY                                              |
+//! | `PgRange<i32>`                        | INT4RANGE                                            |
+//! | `PgRange<i64>`                        | INT8RANGE                                            |
+//! | `HashMap<String, Option<String>>`     | HSTORE                                               |
+//! | `PgLtree`                             | LTREE                                                |
+//! | `IpAddr`, `Ipv4Addr`, `Ipv6Addr`      | INET, CIDR (of a host address)                       |
+//!
+//! ### [`bigdecimal`](https://crates.io/crates/bigdecimal)
+//!
+//! Requires the `bigdecimal` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `bigdecimal::BigDecimal`              | NUMERIC                                              |
+//!
+//! ### [`rust_decimal`](https://crates.io/crates/rust_decimal)
+//!
+//! Requires the `rust_decimal` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `rust_decimal::Decimal`               | NUMERIC                                              |
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
@@ -22,8 +57,9 @@
 //! | `chrono::DateTime<Utc>`               | TIMESTAMPTZ                                          |
 //! | `chrono::DateTime<Local>`             | TIMESTAMPTZ                                          |
 //! | `chrono::NaiveDateTime`               | TIMESTAMP                                            |
-//! | `chrono::NaiveTime`                   | DATE                                                 |
-//! | `chrono::NaiveDate`                   | TIME                                                 |
+//! | `chrono::NaiveDate`                   | DATE                                                 |
+//! | `chrono::NaiveTime`                   | TIME                                                 |
+//! | `chrono::Duration`                    | INTERVAL                                             |
 //!
 //! ### [`uuid`](https://crates.io/crates/uuid)
 //!
@@ -41,15 +77,84 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `ipnetwork::IpNetwork`                | INET, CIDR                                           |
 //!
+//! ### [`bytes`](https://crates.io/crates/bytes)
+//!
+//! Requires the `bytes` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `bytes::Bytes`                        | BYTEA                                                |
+//!
+//! ### [`json`](https://crates.io/crates/serde_json)
+//!
+//! Requires the `json` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `serde_json::Value`                   | JSONB, JSON                                          |
+//! | `&serde_json::value::RawValue`        | JSONB, JSON                                          |
+//! | `Json<T> where T: Serialize`          | JSONB, JSON                                          |
+//! | `PgJson<T> where T: Serialize`        | JSON, JSONB                                          |
+//!
+//! A value is bound as `JSONB`, or as `JSON` with `PgJson`, and Postgres casts it when it is
+//! assigned to a column of the other type. Either type is decoded, with or without the version
+//! byte of `JSONB`.
+//!
+//! ### Note
+//! `i16`, `i32` and `i64` are only decoded from a column of their own width. Wrap one in
+//! `PgCheckedInt` to decode it from any of `INT2`, `INT4` and `INT8`; a value that does not fit
+//! into the requested type is an error. The `std::num::NonZero` integers are stored like the
+//! integer they wrap and decoding a zero into one is an error.
+//!
+//! Postgres has no unsigned integers. `u16` is stored in an `INT4` and a `u32` that is not an
+//! object identifier can be stored in an `INT8`; it is bound as an `OID` (cast it, e.g.,
+//! `$1::int8`) and decodes from either. Decoding checks that the value is in range and returns
+//! an error for a negative value or one that is too large. `u8` is not supported as `&[u8]` and
+//! `Vec<u8>` are `BYTEA`.
+//!
+//! `&[u8]` borrows a `BYTEA` from the row without copying it. This needs the binary format of a
+//! prepared query; in the text format of the simple query protocol a `BYTEA` is hex-encoded and
+//! can only be decoded into a `Vec<u8>`.
+//!
+//! `PgTsVector` and `PgTsQuery` hold the text that Postgres would output for the value and can only
+//! be decoded; use, e.g., `to_tsvector($1)` to bind a full-text search value.
+//!
+//! `XML` is decoded as text without being validated. There is no implicit cast from `TEXT` to
+//! `XML` so a bind parameter must be cast explicitly, e.g., `INSERT INTO docs (body) VALUES ($1::xml)`.
+//!
 //! # Composite types
 //!
-//! Anonymous composite types are represented as tuples.
+//! Anonymous composite types are represented as tuples of up to 12 fields, e.g., the result of
+//! `ROW(1, 'two', true)` decodes into `(i32, String, bool)`. Each field is decoded as the type of
+//! its element of the tuple; a record with fewer fields than the tuple is an error.
+//!
+//! A named composite type is represented by a struct that derives `Type`. Its fields are encoded
+//! and decoded in order of declaration and the type is looked up by the name of the struct, or
+//! by `#[sqlx(rename = "..")]`, unless an OID is given with `#[sqlx(postgres(oid = ..))]`.
+//! A `NULL` field must be decoded into an `Option`.
+//!
+//! ```rust,ignore
+//! // CREATE TYPE address AS (street TEXT, city TEXT);
+//! #[derive(sqlx::Type)]
+//! #[sqlx(rename = "address")]
+//! struct Address {
+//!     street: String,
+//!     city: Option<String>,
+//! }
+//! ```
+//!
+//! # Arrays
+//!
+//! A `Vec<T>` is a one-dimensional array, e.g., `Vec<i32>` is an `INT4[]`, for the types above
+//! with an array type in Postgres, including `Uuid`, the `chrono` and `time` types and the
+//! numeric types. An array of nullable elements is a `Vec<Option<T>>`.
 //!
 //! # Nullable
 //!
 //! An `Option<T>` represents a potentially `NULL` value from Postgres.
 //!
 
+use std::borrow::Cow;
 use std::fmt::{self, Debug, Display};
 use std::ops::Deref;
 use std::sync::Arc;
@@ -59,11 +164,24 @@ use crate::postgres::protocol::TypeId;
 use crate::postgres::{PgValue, Postgres};
 use crate::types::TypeInfo;
 
+mod any;
 mod array;
+mod bits;
 mod bool;
 mod bytes;
 mod float;
+mod fts;
+mod geometric;
+mod hstore;
 mod int;
+mod interval;
+mod ipaddr;
+mod lsn;
+mod ltree;
+mod macaddr;
+mod money;
+mod numeric;
+mod range;
 mod record;
 mod str;
 
@@ -74,6 +192,9 @@ pub mod raw;
 #[cfg(feature = "bigdecimal_bigint")]
 mod bigdecimal;
 
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+
 #[cfg(feature = "chrono")]
 mod chrono;
 
@@ -89,6 +210,18 @@ pub mod json;
 #[cfg(feature = "ipnetwork")]
 mod ipnetwork;
 
+pub(crate) use any::decode_any;
+pub use bits::PgBits;
+pub use fts::{PgTsQuery, PgTsVector};
+pub use geometric::PgPoint;
+pub use int::PgCheckedInt;
+pub use lsn::PgLsn;
+pub use ltree::PgLtree;
+pub use macaddr::{PgMacAddr, PgMacAddr8};
+pub use money::PgMoney;
+pub use numeric::{PgNumeric, PgNumericSign};
+pub use range::{PgRange, RangeBound};
+
 #[cfg(feature = "json")]
 pub use raw::{PgJson, PgJsonb};
 
@@ -118,6 +251,89 @@ impl PgTypeInfo {
         }
     }
 
+    /// Create a `PgTypeInfo` from a type's name.
+    ///
+    /// The object identifier is left unspecified and Postgres will infer the type of a bind
+    /// parameter from the context of the query. This is intended for types that do not have a
+    /// fixed object identifier such as those created by extensions (e.g., `hstore`).
+    pub fn with_name(name: &'static str) -> Self {
+        Self {
+            id: TypeId::UNSPECIFIED,
+            name: Some(name.into()),
+        }
+    }
+
+    /// Create a `PgTypeInfo` for a built-in type from its name, e.g., `int4`, `text` or
+    /// `timestamptz`, without querying the database.
+    ///
+    /// Names are matched without regard to case and common aliases such as `integer` or
+    /// `double precision` are accepted. An array is named after its element type like
+    /// `int4[]` or `_int4`.
+    ///
+    /// Returns `None` if the name is not one of a built-in type; its object identifier can
+    /// then be looked up in `pg_type` and used with [`with_oid`](#method.with_oid).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+
+        let element = if name.ends_with("[]") {
+            Some(&name[..name.len() - 2])
+        } else if name.starts_with('_') {
+            Some(&name[1..])
+        } else {
+            None
+        };
+
+        if let Some(element) = element {
+            let element = Self::from_name(element)?;
+            let id = TypeId::array_of(element.id)?;
+
+            return Some(Self::new(id, format!("{}[]", element.type_name())));
+        }
+
+        let (id, name) = match &*name {
+            "bool" | "boolean" => (TypeId::BOOL, "BOOL"),
+            "\"char\"" => (TypeId::CHAR, "\"CHAR\""),
+            "int2" | "smallint" => (TypeId::INT2, "INT2"),
+            "int4" | "int" | "integer" => (TypeId::INT4, "INT4"),
+            "int8" | "bigint" => (TypeId::INT8, "INT8"),
+            "oid" => (TypeId::OID, "OID"),
+            "float4" | "real" => (TypeId::FLOAT4, "FLOAT4"),
+            "float8" | "double precision" => (TypeId::FLOAT8, "FLOAT8"),
+            "numeric" | "decimal" => (TypeId::NUMERIC, "NUMERIC"),
+            "money" => (TypeId::MONEY, "MONEY"),
+            "text" => (TypeId::TEXT, "TEXT"),
+            "varchar" | "character varying" => (TypeId::VARCHAR, "VARCHAR"),
+            "bpchar" | "character" => (TypeId::BPCHAR, "BPCHAR"),
+            "name" => (TypeId::NAME, "NAME"),
+            "xml" => (TypeId::XML, "XML"),
+            "date" => (TypeId::DATE, "DATE"),
+            "time" | "time without time zone" => (TypeId::TIME, "TIME"),
+            "timestamp" | "timestamp without time zone" => (TypeId::TIMESTAMP, "TIMESTAMP"),
+            "timestamptz" | "timestamp with time zone" => (TypeId::TIMESTAMPTZ, "TIMESTAMPTZ"),
+            "interval" => (TypeId::INTERVAL, "INTERVAL"),
+            "bytea" => (TypeId::BYTEA, "BYTEA"),
+            "uuid" => (TypeId::UUID, "UUID"),
+            "cidr" => (TypeId::CIDR, "CIDR"),
+            "inet" => (TypeId::INET, "INET"),
+            "macaddr" => (TypeId::MACADDR, "MACADDR"),
+            "macaddr8" => (TypeId::MACADDR8, "MACADDR8"),
+            "bit" => (TypeId::BIT, "BIT"),
+            "varbit" | "bit varying" => (TypeId::VARBIT, "VARBIT"),
+            "pg_lsn" => (TypeId::PG_LSN, "PG_LSN"),
+            "tsvector" => (TypeId::TSVECTOR, "TSVECTOR"),
+            "tsquery" => (TypeId::TSQUERY, "TSQUERY"),
+            "point" => (TypeId::POINT, "POINT"),
+            "int4range" => (TypeId::INT4RANGE, "INT4RANGE"),
+            "int8range" => (TypeId::INT8RANGE, "INT8RANGE"),
+            "json" => (TypeId::JSON, "JSON"),
+            "jsonb" => (TypeId::JSONB, "JSONB"),
+
+            _ => return None,
+        };
+
+        Some(Self::new(id, name))
+    }
+
     #[doc(hidden)]
     pub fn type_name(&self) -> &str {
         self.name.as_deref().unwrap_or("<UNKNOWN>")
@@ -126,9 +342,17 @@ impl PgTypeInfo {
     #[doc(hidden)]
     pub fn type_feature_gate(&self) -> Option<&'static str> {
         match self.id {
+            TypeId::DATE | TypeId::TIME | TypeId::TIMESTAMP | TypeId::TIMESTAMPTZ
+                if cfg!(all(feature = "time", not(feature = "chrono"))) =>
+            {
+                Some("time")
+            }
             TypeId::DATE | TypeId::TIME | TypeId::TIMESTAMP | TypeId::TIMESTAMPTZ => Some("chrono"),
             TypeId::UUID => Some("uuid"),
             // we can support decoding `PgNumeric` but it's decidedly less useful to the layman
+            TypeId::NUMERIC if cfg!(all(feature = "rust_decimal", not(feature = "bigdecimal"))) => {
+                Some("rust_decimal")
+            }
             TypeId::NUMERIC => Some("bigdecimal"),
             TypeId::CIDR | TypeId::INET => Some("ipnetwork"),
             _ => None,
@@ -139,6 +363,13 @@ impl PgTypeInfo {
     pub fn oid(&self) -> u32 {
         self.id.0
     }
+
+    // `CITEXT` is created by an extension and only known by its name
+    fn is_citext(&self) -> bool {
+        self.name
+            .as_deref()
+            .map_or(false, |name| name.eq_ignore_ascii_case("citext"))
+    }
 }
 
 impl Display for PgTypeInfo {
@@ -152,21 +383,57 @@ impl Display for PgTypeInfo {
 }
 
 impl TypeInfo for PgTypeInfo {
+    fn name(&self) -> Cow<'_, str> {
+        match &self.name {
+            Some(name) => Cow::Borrowed(&**name),
+            None => Cow::Owned(self.to_string()),
+        }
+    }
+
     fn compatible(&self, other: &Self) -> bool {
         match (self.id, other.id) {
             (TypeId::CIDR, TypeId::INET)
             | (TypeId::INET, TypeId::CIDR)
-            | (TypeId::ARRAY_CIDR, TypeId::ARRAY_INET)
-            | (TypeId::ARRAY_INET, TypeId::ARRAY_CIDR) => true,
+            | (TypeId::BIT, TypeId::VARBIT)
+            | (TypeId::VARBIT, TypeId::BIT)
+            | (TypeId::TEXT, TypeId::XML)
+            | (TypeId::XML, TypeId::TEXT)
+            | (TypeId::JSON, TypeId::JSONB)
+            | (TypeId::JSONB, TypeId::JSON) => true,
+
+            // the text types only differ in how their values are checked by Postgres
+            (id, other_id) if is_text(id) && is_text(other_id) => true,
+
+            (id, _) if is_text(id) && other.is_citext() => true,
+            (_, other_id) if is_text(other_id) && self.is_citext() => true,
 
-            _ => {
-                // TODO: 99% of postgres types are direct equality for [compatible]; when we add something that isn't (e.g, JSON/JSONB), fix this here
-                self.id.0 == other.id.0
+            // types without a fixed OID can only be compared by name
+            (TypeId::UNSPECIFIED, _) | (_, TypeId::UNSPECIFIED) => {
+                match (&self.name, &other.name) {
+                    (Some(name), Some(other_name)) => name.eq_ignore_ascii_case(other_name),
+                    _ => false,
+                }
             }
+
+            // arrays are compatible if their elements are
+            (id, other_id) => match (TypeId::element_of(id), TypeId::element_of(other_id)) {
+                (Some(elem), Some(other_elem)) => {
+                    PgTypeInfo::with_oid(elem.0).compatible(&PgTypeInfo::with_oid(other_elem.0))
+                }
+
+                _ => id.0 == other_id.0,
+            },
         }
     }
 }
 
+fn is_text(id: TypeId) -> bool {
+    matches!(
+        id,
+        TypeId::TEXT | TypeId::VARCHAR | TypeId::BPCHAR | TypeId::NAME
+    )
+}
+
 impl<'de, T> Decode<'de, Postgres> for Option<T>
 where
     T: Decode<'de, Postgres>,
@@ -176,6 +443,15 @@ where
             .map(|value| <T as Decode<Postgres>>::decode(Some(value)))
             .transpose()
     }
+
+    fn decode_with_type(
+        type_info: &PgTypeInfo,
+        value: Option<PgValue<'de>>,
+    ) -> crate::Result<Postgres, Self> {
+        value
+            .map(|value| <T as Decode<Postgres>>::decode_with_type(type_info, Some(value)))
+            .transpose()
+    }
 }
 
 /// Copy of `Cow` but for strings; clones guaranteed to be cheap.
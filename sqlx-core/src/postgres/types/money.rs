@@ -0,0 +1,125 @@
+use std::convert::TryInto;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+/// The **Postgres** `MONEY` type.
+///
+/// Postgres stores `MONEY` as a 64-bit integer in the smallest unit of the currency, where the
+/// number of fractional digits is determined by the `lc_monetary` setting of the server. This
+/// type preserves that raw integer; for the common locales (such as the default `C` and
+/// `en_US`) there are 2 fractional digits, so `'$1,234.56'::money` is `PgMoney(123456)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgMoney(pub i64);
+
+impl PgMoney {
+    /// The number of fractional digits in the `C` and `en_US` locales.
+    pub const DEFAULT_SCALE: i64 = 2;
+
+    /// Converts the raw value to a [`BigDecimal`] with `scale` fractional digits.
+    ///
+    /// [`BigDecimal`]: crate::types::BigDecimal
+    #[cfg(feature = "bigdecimal")]
+    pub fn to_bigdecimal(self, scale: i64) -> bigdecimal::BigDecimal {
+        bigdecimal::BigDecimal::new(self.0.into(), scale)
+    }
+}
+
+impl Type<Postgres> for PgMoney {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::MONEY, "MONEY")
+    }
+}
+
+impl Type<Postgres> for [PgMoney] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_MONEY, "MONEY[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgMoney> {
+    fn type_info() -> PgTypeInfo {
+        <[PgMoney] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for PgMoney {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.0.to_be_bytes());
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgMoney {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => buf
+                .read_i64::<NetworkEndian>()
+                .map_err(Error::decode)
+                .map(PgMoney),
+
+            PgValue::Text(s) => parse_money(s)
+                .map(PgMoney)
+                .ok_or_else(|| Error::Decode(format!("invalid MONEY value: {:?}", s).into())),
+        }
+    }
+}
+
+// The text format is locale-dependent (e.g., `$1,234.56`, `-$12.50` or `($12.50)`) but always
+// includes every fractional digit so dropping everything but the digits gives the raw value
+fn parse_money(s: &str) -> Option<i64> {
+    let negative = s.contains('-') || s.starts_with('(');
+    let mut value: i64 = 0;
+    let mut any_digits = false;
+
+    for digit in s.bytes().filter(u8::is_ascii_digit) {
+        any_digits = true;
+        value = value
+            .checked_mul(10)?
+            .checked_sub(i64::from(digit - b'0'))?;
+    }
+
+    if !any_digits {
+        return None;
+    }
+
+    if negative {
+        Some(value)
+    } else {
+        value.checked_neg()
+    }
+}
+
+#[test]
+fn test_parse_money() {
+    assert_eq!(parse_money("$1,234.56"), Some(123456));
+    assert_eq!(parse_money("-$12.50"), Some(-1250));
+    assert_eq!(parse_money("($12.50)"), Some(-1250));
+    assert_eq!(parse_money("$0.00"), Some(0));
+    assert_eq!(
+        parse_money("-$92,233,720,368,547,758.08"),
+        Some(i64::min_value())
+    );
+    assert_eq!(
+        parse_money("$92,233,720,368,547,758.07"),
+        Some(i64::max_value())
+    );
+    assert_eq!(parse_money("$"), None);
+}
+
+#[test]
+#[cfg(feature = "bigdecimal")]
+fn test_money_to_bigdecimal() {
+    let money = PgMoney(123456);
+
+    assert_eq!(
+        money.to_bigdecimal(PgMoney::DEFAULT_SCALE),
+        "1234.56".parse::<bigdecimal::BigDecimal>().unwrap()
+    );
+}
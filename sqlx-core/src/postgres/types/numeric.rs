@@ -0,0 +1,425 @@
+use std::convert::TryInto;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use byteorder::BigEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::{Buf, BufMut};
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+/// Represents a `NUMERIC` value in the **Postgres** wire protocol.
+///
+/// This is the lossless representation of a `NUMERIC` and does not require the `bigdecimal`
+/// feature. The [`Display`] implementation renders the value the same way Postgres does
+/// (e.g., `-1.500`, `NaN`).
+#[derive(Debug, PartialEq, Eq)]
+pub enum PgNumeric {
+    /// Equivalent to the `'NaN'` value in Postgres. The result of, e.g. `1 / 0`.
+    NotANumber,
+    /// A populated `NUMERIC` value.
+    ///
+    /// A description of these fields can be found here (although the type being described is the
+    /// version for in-memory calculations, the field names are the same):
+    /// https://github.com/postgres/postgres/blob/bcd1c3630095e48bc3b1eb0fc8e8c8a7c851eba1/src/backend/utils/adt/numeric.c#L224-L269
+    Number {
+        /// The sign of the value: positive (also set for 0 and -0), or negative.
+        sign: PgNumericSign,
+        /// The digits of the number in base-10000 with the most significant digit first
+        /// (big-endian).
+        ///
+        /// The length of this vector must not overflow `i16` for the binary protocol.
+        ///
+        /// *Note*: the `Encode` implementation will panic if any digit is `>= 10000`.
+        digits: Vec<i16>,
+        /// The scaling factor of the number, such that the value will be interpreted as
+        ///
+        /// ```text
+        ///   digits[0] * 10,000 ^ weight
+        /// + digits[1] * 10,000 ^ (weight - 1)
+        /// ...
+        /// + digits[N] * 10,000 ^ (weight - N) where N = digits.len() - 1
+        /// ```
+        /// May be negative.
+        weight: i16,
+        /// How many _decimal_ (base-10) digits following the decimal point to consider in
+        /// arithmetic regardless of how many actually follow the decimal point as determined by
+        /// `weight`--the comment in the Postgres code linked above recommends using this only for
+        /// ignoring unnecessary trailing zeroes (as trimming nonzero digits means reducing the
+        /// precision of the value).
+        ///
+        /// Must be `>= 0`.
+        scale: i16,
+    },
+}
+
+// https://github.com/postgres/postgres/blob/bcd1c3630095e48bc3b1eb0fc8e8c8a7c851eba1/src/backend/utils/adt/numeric.c#L167-L170
+const SIGN_POS: u16 = 0x0000;
+const SIGN_NEG: u16 = 0x4000;
+const SIGN_NAN: u16 = 0xC000; // overflows i16 (C equivalent truncates from integer literal)
+
+/// Possible sign values for [PgNumeric].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum PgNumericSign {
+    Positive = SIGN_POS,
+    Negative = SIGN_NEG,
+}
+
+impl PgNumericSign {
+    fn try_from_u16(val: u16) -> crate::Result<Postgres, Self> {
+        match val {
+            SIGN_POS => Ok(PgNumericSign::Positive),
+            SIGN_NEG => Ok(PgNumericSign::Negative),
+            SIGN_NAN => panic!("BUG: sign value for NaN passed to PgNumericSign"),
+            _ => Err(Error::Decode(
+                format!("invalid value for PgNumericSign: {:#04X}", val).into(),
+            )),
+        }
+    }
+}
+
+impl Type<Postgres> for PgNumeric {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::NUMERIC, "NUMERIC")
+    }
+}
+
+impl Type<Postgres> for [PgNumeric] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_NUMERIC, "NUMERIC[]")
+    }
+}
+
+impl Type<Postgres> for Vec<PgNumeric> {
+    fn type_info() -> PgTypeInfo {
+        <[PgNumeric] as Type<Postgres>>::type_info()
+    }
+}
+
+impl PgNumeric {
+    pub(crate) fn from_bytes(mut bytes: &[u8]) -> crate::Result<Postgres, Self> {
+        // https://github.com/postgres/postgres/blob/bcd1c3630095e48bc3b1eb0fc8e8c8a7c851eba1/src/backend/utils/adt/numeric.c#L874
+        let num_digits = bytes.get_u16::<BigEndian>()?;
+        let weight = bytes.get_i16::<BigEndian>()?;
+        let sign = bytes.get_u16::<BigEndian>()?;
+        let scale = bytes.get_i16::<BigEndian>()?;
+
+        if bytes.len() < 2 * num_digits as usize {
+            return Err(Error::Decode(
+                format!(
+                    "expected {} digits for a NUMERIC, got {} bytes",
+                    num_digits,
+                    bytes.len()
+                )
+                .into(),
+            ));
+        }
+
+        if sign == SIGN_NAN {
+            Ok(PgNumeric::NotANumber)
+        } else {
+            let digits: Vec<_> = (0..num_digits)
+                .map(|_| bytes.get_i16::<BigEndian>())
+                .collect::<Result<_, _>>()?;
+            Ok(PgNumeric::Number {
+                sign: PgNumericSign::try_from_u16(sign)?,
+                scale,
+                weight,
+                digits,
+            })
+        }
+    }
+
+    /// Converts this value to the nearest `f64`.
+    ///
+    /// This is lossy; `NUMERIC` has up to 131072 digits before the decimal point and up to
+    /// 16383 digits after it, far more than `f64` can represent. Values too large for `f64`
+    /// become infinite and `NaN` becomes [`f64::NAN`].
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            PgNumeric::NotANumber => f64::NAN,
+
+            // formatting and then parsing gives us a correctly rounded value
+            _ => self
+                .to_string()
+                .parse()
+                .expect("PgNumeric should format as a valid f64"),
+        }
+    }
+}
+
+impl Display for PgNumeric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, digits, weight, scale) = match self {
+            PgNumeric::NotANumber => return f.write_str("NaN"),
+            PgNumeric::Number {
+                sign,
+                digits,
+                weight,
+                scale,
+            } => (*sign, digits, i32::from(*weight), i32::from(*scale)),
+        };
+
+        // the digit for the group with the base-10000 exponent `weight - index`
+        let digit = |index: i32| -> i16 {
+            if index < 0 {
+                0
+            } else {
+                digits.get(index as usize).copied().unwrap_or(0)
+            }
+        };
+
+        if sign == PgNumericSign::Negative && !digits.is_empty() {
+            f.write_str("-")?;
+        }
+
+        if weight < 0 {
+            f.write_str("0")?;
+        } else {
+            write!(f, "{}", digit(0))?;
+
+            for index in 1..=weight {
+                write!(f, "{:04}", digit(index))?;
+            }
+        }
+
+        if scale > 0 {
+            let mut fraction = String::with_capacity(scale as usize + 3);
+            let mut index = weight + 1;
+
+            while fraction.len() < scale as usize {
+                fraction.push_str(&format!("{:04}", digit(index)));
+                index += 1;
+            }
+
+            fraction.truncate(scale as usize);
+
+            write!(f, ".{}", fraction)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses the text format of a `NUMERIC`, e.g., `-1234.5678` or `NaN`.
+impl FromStr for PgNumeric {
+    type Err = crate::Error<Postgres>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::Decode(format!("invalid NUMERIC value: {:?}", s).into());
+
+        if s.eq_ignore_ascii_case("NaN") {
+            return Ok(PgNumeric::NotANumber);
+        }
+
+        let (sign, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (PgNumericSign::Negative, &s[1..]),
+            Some(b'+') => (PgNumericSign::Positive, &s[1..]),
+            _ => (PgNumericSign::Positive, s),
+        };
+
+        let mut parts = unsigned.splitn(2, '.');
+        let integer = parts.next().unwrap_or_default();
+        let fraction = parts.next().unwrap_or_default();
+
+        if (integer.is_empty() && fraction.is_empty())
+            || !integer
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let scale: i16 = fraction.len().try_into().map_err(|_| invalid())?;
+
+        // pad both parts out to whole base-10000 digits; the integer part on the left and
+        // the fractional part on the right
+        let integer_pad = (4 - integer.len() % 4) % 4;
+        let fraction_pad = (4 - fraction.len() % 4) % 4;
+
+        let decimal: Vec<u8> = std::iter::repeat(0)
+            .take(integer_pad)
+            .chain(integer.bytes().map(|b| b - b'0'))
+            .chain(fraction.bytes().map(|b| b - b'0'))
+            .chain(std::iter::repeat(0).take(fraction_pad))
+            .collect();
+
+        let mut digits: Vec<i16> = decimal
+            .chunks(4)
+            .map(|chunk| chunk.iter().fold(0, |acc, &d| acc * 10 + i16::from(d)))
+            .collect();
+
+        let mut weight: i16 = ((integer.len() + integer_pad) / 4)
+            .try_into()
+            .map_err(|_| invalid())?;
+        weight -= 1;
+
+        // Postgres never sends leading or trailing zero digits
+        let leading = digits.iter().take_while(|&&d| d == 0).count();
+        digits.drain(..leading);
+        weight -= leading as i16;
+
+        while let Some(&0) = digits.last() {
+            digits.pop();
+        }
+
+        if digits.is_empty() {
+            return Ok(PgNumeric::Number {
+                sign: PgNumericSign::Positive,
+                digits,
+                weight: 0,
+                scale,
+            });
+        }
+
+        Ok(PgNumeric::Number {
+            sign,
+            digits,
+            weight,
+            scale,
+        })
+    }
+}
+impl<'de> Decode<'de, Postgres> for PgNumeric {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(bytes) => Self::from_bytes(bytes),
+            PgValue::Text(s) => s.parse(),
+        }
+    }
+}
+
+/// ### Panics
+///
+/// * If `digits.len()` overflows `i16`
+/// * If any element in `digits` is greater than or equal to 10000
+impl Encode<Postgres> for PgNumeric {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match *self {
+            PgNumeric::Number {
+                ref digits,
+                sign,
+                scale,
+                weight,
+            } => {
+                let digits_len: i16 = digits
+                    .len()
+                    .try_into()
+                    .expect("PgNumeric.digits.len() should not overflow i16");
+
+                buf.put_i16::<BigEndian>(digits_len);
+                buf.put_i16::<BigEndian>(weight);
+                buf.put_i16::<BigEndian>(sign as i16);
+                buf.put_i16::<BigEndian>(scale);
+                for &digit in digits {
+                    assert!(digit < 10000, "PgNumeric digits must be in base-10000");
+                    buf.put_i16::<BigEndian>(digit);
+                }
+            }
+            PgNumeric::NotANumber => {
+                buf.put_i16::<BigEndian>(0);
+                buf.put_i16::<BigEndian>(0);
+                buf.put_u16::<BigEndian>(SIGN_NAN);
+                buf.put_i16::<BigEndian>(0);
+            }
+        }
+    }
+    fn size_hint(&self) -> usize {
+        // 4 i16's plus digits
+        8 + if let PgNumeric::Number { digits, .. } = self {
+            digits.len() * 2
+        } else {
+            0
+        }
+    }
+}
+
+#[test]
+fn test_display_numeric() {
+    let number = |sign, digits: &[i16], weight, scale| PgNumeric::Number {
+        sign,
+        digits: digits.to_vec(),
+        weight,
+        scale,
+    };
+
+    assert_eq!(PgNumeric::NotANumber.to_string(), "NaN");
+    assert_eq!(number(PgNumericSign::Positive, &[], 0, 0).to_string(), "0");
+    assert_eq!(
+        number(PgNumericSign::Positive, &[], 0, 2).to_string(),
+        "0.00"
+    );
+    assert_eq!(
+        number(PgNumericSign::Positive, &[1], 1, 0).to_string(),
+        "10000"
+    );
+    assert_eq!(
+        number(PgNumericSign::Negative, &[12, 3400], 0, 2).to_string(),
+        "-12.34"
+    );
+    assert_eq!(
+        number(PgNumericSign::Positive, &[123, 4000], -1, 5).to_string(),
+        "0.01234"
+    );
+    assert_eq!(
+        number(PgNumericSign::Positive, &[1, 5000], 0, 4).to_string(),
+        "1.5000"
+    );
+}
+
+#[test]
+fn test_parse_numeric() {
+    assert_eq!(
+        "0.01234".parse::<PgNumeric>().unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            digits: vec![123, 4000],
+            weight: -1,
+            scale: 5,
+        }
+    );
+
+    assert_eq!(
+        "-10000.50".parse::<PgNumeric>().unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Negative,
+            digits: vec![1, 0, 5000],
+            weight: 1,
+            scale: 2,
+        }
+    );
+
+    assert_eq!(
+        "0.000".parse::<PgNumeric>().unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            digits: vec![],
+            weight: 0,
+            scale: 3,
+        }
+    );
+
+    assert_eq!("NaN".parse::<PgNumeric>().unwrap(), PgNumeric::NotANumber);
+
+    for s in &["-1.500", "123456789.0001", "0.5", "1"] {
+        assert_eq!(s.parse::<PgNumeric>().unwrap().to_string(), *s);
+    }
+
+    assert!("".parse::<PgNumeric>().is_err());
+    assert!("1.2.3".parse::<PgNumeric>().is_err());
+    assert!("1e5".parse::<PgNumeric>().is_err());
+}
+
+#[test]
+fn test_numeric_to_f64() {
+    assert_eq!("-1.5".parse::<PgNumeric>().unwrap().to_f64(), -1.5);
+    assert_eq!("12.3400".parse::<PgNumeric>().unwrap().to_f64(), 12.34);
+    assert_eq!("-0.000".parse::<PgNumeric>().unwrap().to_f64(), 0.0);
+    assert!(PgNumeric::NotANumber.to_f64().is_nan());
+}
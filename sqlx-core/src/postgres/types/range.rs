@@ -0,0 +1,320 @@
+use std::convert::TryInto;
+
+use byteorder::BigEndian;
+
+use crate::decode::{Decode, DecodeOwned};
+use crate::encode::Encode;
+use crate::io::{Buf, BufMut};
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+
+// https://github.com/postgres/postgres/blob/2f48ede080f42b97b594fb14102c82ca1001b80c/src/include/utils/rangetypes.h#L35-L44
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LB_INC: u8 = 0x02;
+const RANGE_UB_INC: u8 = 0x04;
+const RANGE_LB_INF: u8 = 0x08;
+const RANGE_UB_INF: u8 = 0x10;
+
+/// One end of a [`PgRange`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeBound<T> {
+    /// The range includes this value; `[` or `]`.
+    Inclusive(T),
+    /// The range stops just short of this value; `(` or `)`.
+    Exclusive(T),
+    /// The range extends infinitely in this direction.
+    Unbounded,
+}
+
+/// A **Postgres** range type, e.g., `INT4RANGE` or `INT8RANGE`.
+///
+/// Note that Postgres normalizes the bounds of discrete ranges to the `[lower,upper)` form so
+/// a range encoded as `(,5]` will be decoded as `(,6)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PgRange<T> {
+    /// A range containing no values; `'empty'`.
+    Empty,
+    /// A range between two bounds; either of which may be unbounded.
+    Range {
+        lower: RangeBound<T>,
+        upper: RangeBound<T>,
+    },
+}
+
+impl<T> PgRange<T> {
+    /// Create a range between the two bounds.
+    pub fn new(lower: RangeBound<T>, upper: RangeBound<T>) -> Self {
+        PgRange::Range { lower, upper }
+    }
+
+    /// Returns `true` if this is the empty range.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            PgRange::Empty => true,
+            PgRange::Range { .. } => false,
+        }
+    }
+}
+
+impl Type<Postgres> for PgRange<i32> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INT4RANGE, "INT4RANGE")
+    }
+}
+
+impl Type<Postgres> for PgRange<i64> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INT8RANGE, "INT8RANGE")
+    }
+}
+
+impl<T> Encode<Postgres> for PgRange<T>
+where
+    T: Encode<Postgres>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let (lower, upper) = match self {
+            PgRange::Empty => {
+                buf.push(RANGE_EMPTY);
+                return;
+            }
+
+            PgRange::Range { lower, upper } => (lower, upper),
+        };
+
+        let mut flags = 0;
+
+        flags |= match lower {
+            RangeBound::Inclusive(_) => RANGE_LB_INC,
+            RangeBound::Exclusive(_) => 0,
+            RangeBound::Unbounded => RANGE_LB_INF,
+        };
+
+        flags |= match upper {
+            RangeBound::Inclusive(_) => RANGE_UB_INC,
+            RangeBound::Exclusive(_) => 0,
+            RangeBound::Unbounded => RANGE_UB_INF,
+        };
+
+        buf.push(flags);
+
+        encode_bound(lower, buf);
+        encode_bound(upper, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        let bound_size = |bound: &RangeBound<T>| match bound {
+            RangeBound::Inclusive(value) | RangeBound::Exclusive(value) => 4 + value.size_hint(),
+            RangeBound::Unbounded => 0,
+        };
+
+        1 + match self {
+            PgRange::Empty => 0,
+            PgRange::Range { lower, upper } => bound_size(lower) + bound_size(upper),
+        }
+    }
+}
+
+fn encode_bound<T: Encode<Postgres>>(bound: &RangeBound<T>, buf: &mut Vec<u8>) {
+    if let RangeBound::Inclusive(value) | RangeBound::Exclusive(value) = bound {
+        let len_index = buf.len();
+        buf.put_i32::<BigEndian>(0);
+
+        let start = buf.len();
+        value.encode(buf);
+
+        let len = (buf.len() - start) as i32;
+        buf[len_index..start].copy_from_slice(&len.to_be_bytes());
+    }
+}
+
+impl<'de, T> Decode<'de, Postgres> for PgRange<T>
+where
+    T: 'de,
+    T: DecodeOwned<Postgres>,
+{
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let flags = buf.get_u8()?;
+
+                if flags & RANGE_EMPTY != 0 {
+                    return Ok(PgRange::Empty);
+                }
+
+                let lower = decode_bound(&mut buf, flags, RANGE_LB_INF, RANGE_LB_INC)?;
+                let upper = decode_bound(&mut buf, flags, RANGE_UB_INF, RANGE_UB_INC)?;
+
+                Ok(PgRange::Range { lower, upper })
+            }
+
+            PgValue::Text(s) => parse_range(s),
+        }
+    }
+}
+
+fn decode_bound<T>(
+    buf: &mut &[u8],
+    flags: u8,
+    infinite: u8,
+    inclusive: u8,
+) -> crate::Result<Postgres, RangeBound<T>>
+where
+    T: DecodeOwned<Postgres>,
+{
+    if flags & infinite != 0 {
+        return Ok(RangeBound::Unbounded);
+    }
+
+    let len = buf.get_i32::<BigEndian>()? as usize;
+
+    if buf.len() < len {
+        return Err(decode_err!("range bound of {} bytes is truncated", len));
+    }
+
+    let value = T::decode(Some(PgValue::Binary(&buf[..len])))?;
+    buf.advance(len);
+
+    Ok(if flags & inclusive != 0 {
+        RangeBound::Inclusive(value)
+    } else {
+        RangeBound::Exclusive(value)
+    })
+}
+
+// empty | [lower,upper) | (,upper] | ...
+fn parse_range<T>(s: &str) -> crate::Result<Postgres, PgRange<T>>
+where
+    T: DecodeOwned<Postgres>,
+{
+    if s.eq_ignore_ascii_case("empty") {
+        return Ok(PgRange::Empty);
+    }
+
+    let invalid = || decode_err!("invalid range value: {:?}", s);
+
+    let lower_inclusive = match s.chars().next() {
+        Some('[') => true,
+        Some('(') => false,
+        _ => return Err(invalid()),
+    };
+
+    let upper_inclusive = match s.chars().last() {
+        Some(']') => true,
+        Some(')') => false,
+        _ => return Err(invalid()),
+    };
+
+    let inner = &s[1..(s.len() - 1)];
+    let comma = split_index(inner).ok_or_else(invalid)?;
+
+    let lower = parse_bound(&inner[..comma], lower_inclusive)?;
+    let upper = parse_bound(&inner[(comma + 1)..], upper_inclusive)?;
+
+    Ok(PgRange::Range { lower, upper })
+}
+
+// finds the comma separating the bounds, skipping over any quoted bound
+fn split_index(s: &str) -> Option<usize> {
+    let mut in_quotes = false;
+    let mut in_escape = false;
+
+    for (index, ch) in s.char_indices() {
+        match ch {
+            _ if in_escape => in_escape = false,
+            '\\' => in_escape = true,
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn parse_bound<T>(s: &str, inclusive: bool) -> crate::Result<Postgres, RangeBound<T>>
+where
+    T: DecodeOwned<Postgres>,
+{
+    if s.is_empty() {
+        return Ok(RangeBound::Unbounded);
+    }
+
+    let value = if s.starts_with('"') && s.ends_with('"') && s.len() >= 2 {
+        let mut unquoted = String::with_capacity(s.len());
+        let mut chars = s[1..(s.len() - 1)].chars();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => unquoted.extend(chars.next()),
+                '"' => unquoted.extend(chars.next()),
+                ch => unquoted.push(ch),
+            }
+        }
+
+        T::decode(Some(PgValue::Text(&unquoted)))?
+    } else {
+        T::decode(Some(PgValue::Text(s)))?
+    };
+
+    Ok(if inclusive {
+        RangeBound::Inclusive(value)
+    } else {
+        RangeBound::Exclusive(value)
+    })
+}
+
+#[test]
+fn test_encode_range() {
+    let mut buf = Vec::new();
+
+    Encode::<Postgres>::encode(&PgRange::<i32>::Empty, &mut buf);
+    assert_eq!(buf, [RANGE_EMPTY]);
+    buf.clear();
+
+    // (,5]
+    Encode::<Postgres>::encode(
+        &PgRange::new(RangeBound::Unbounded, RangeBound::Inclusive(5_i32)),
+        &mut buf,
+    );
+    assert_eq!(buf, [RANGE_LB_INF | RANGE_UB_INC, 0, 0, 0, 4, 0, 0, 0, 5]);
+}
+
+#[test]
+fn test_decode_range() {
+    let buf = [
+        RANGE_LB_INC,
+        0,
+        0,
+        0,
+        4,
+        0,
+        0,
+        0,
+        1,
+        0,
+        0,
+        0,
+        4,
+        0,
+        0,
+        0,
+        10,
+    ];
+    let range: PgRange<i32> = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
+    assert_eq!(
+        range,
+        PgRange::new(RangeBound::Inclusive(1), RangeBound::Exclusive(10))
+    );
+
+    let range: PgRange<i32> = Decode::<Postgres>::decode(Some(PgValue::Text("(,6)"))).unwrap();
+    assert_eq!(
+        range,
+        PgRange::new(RangeBound::Unbounded, RangeBound::Exclusive(6))
+    );
+
+    let range: PgRange<i64> = Decode::<Postgres>::decode(Some(PgValue::Text("empty"))).unwrap();
+    assert!(range.is_empty());
+}
@@ -2,8 +2,8 @@ use crate::decode::DecodeOwned;
 use crate::encode::{Encode, IsNull};
 use crate::io::{Buf, BufMut};
 use crate::postgres::types::raw::sequence::PgSequenceDecoder;
-use crate::postgres::{PgValue, Postgres};
-use crate::types::Type;
+use crate::postgres::{PgTypeInfo, PgValue, Postgres};
+use crate::types::{Type, TypeInfo};
 use byteorder::BE;
 use std::convert::TryInto;
 use std::marker::PhantomData;
@@ -84,11 +84,32 @@ where
     }
 }
 
+// the maximum number of dimensions of an array in Postgres
+const MAX_DIMENSIONS: i32 = 6;
+
 pub(crate) struct PgArrayDecoder<'de, T> {
-    inner: PgSequenceDecoder<'de>,
+    elements: PgArrayElements<'de>,
     phantom: PhantomData<T>,
 }
 
+enum PgArrayElements<'de> {
+    // the elements of a one-dimensional array or an array in the text format; the length is the
+    // number of elements declared in the header, if known
+    Sequence {
+        inner: PgSequenceDecoder<'de>,
+        len: Option<usize>,
+    },
+
+    // the sub-arrays of a multidimensional array in the binary format; as the elements are stored
+    // flat, each sub-array is decoded from the elements it spans behind its own header
+    SubArrays {
+        header: Vec<u8>,
+        elements: usize,
+        remaining: usize,
+        buf: &'de [u8],
+    },
+}
+
 impl<'de, T> PgArrayDecoder<'de, T>
 where
     T: DecodeOwned<Postgres>,
@@ -103,55 +124,204 @@ where
                 let ndim = buf.get_i32::<BE>()?;
 
                 if ndim == 0 {
-                    return Ok(Self {
+                    return Ok(Self::from_elements(PgArrayElements::Sequence {
                         inner: PgSequenceDecoder::new(PgValue::Binary(&[]), false),
-                        phantom: PhantomData,
-                    });
+                        len: Some(0),
+                    }));
                 }
 
-                if ndim != 1 {
+                if ndim < 0 || ndim > MAX_DIMENSIONS {
                     return Err(decode_err!(
-                        "encountered an array of {} dimensions; only one-dimensional arrays are supported",
+                        "encountered an array of {} dimensions; expected 1 to {} dimensions",
+                        ndim,
+                        MAX_DIMENSIONS
+                    ));
+                }
+
+                // the rest of the header is the data offset and element type followed by the
+                // length and lower bound of each dimension
+                if buf.len() < 8 + 8 * ndim as usize {
+                    return Err(decode_err!(
+                        "encountered a truncated header of an array of {} dimensions",
                         ndim
                     ));
                 }
 
                 // offset to stored data
                 // this doesn't matter as the data is always at the end of the header
-                let _dataoffset = buf.get_i32::<BE>()?;
+                let dataoffset = buf.get_i32::<BE>()?;
 
                 // TODO: Validate element type with whatever framework is put in place to do so
                 //       As a reminder, we have no way to do this yet and still account for [compatible]
                 //       types.
 
                 // element type OID
-                let _elemtype = buf.get_i32::<BE>()?;
+                let elemtype = buf.get_u32::<BE>()?;
+
+                let mut dimensions = Vec::with_capacity(ndim as usize);
+
+                for dimension in 1..=ndim {
+                    // length of the array axis
+                    let len = buf.get_i32::<BE>()?;
+
+                    // lower boundary of the dimension
+                    let lower_bnds = buf.get_i32::<BE>()?;
+
+                    if len < 0 {
+                        return Err(decode_err!(
+                            "encountered an array with a length of {} in dimension {}",
+                            len,
+                            dimension
+                        ));
+                    }
+
+                    // the elements are always stored in order so the lower bound only needs to
+                    // be checked for the same overflow that Postgres rejects
+                    if lower_bnds.checked_add(len).is_none() {
+                        return Err(decode_err!(
+                            "encountered an array with a lower bound of {} and a length of {} in dimension {}",
+                            lower_bnds,
+                            len,
+                            dimension
+                        ));
+                    }
+
+                    dimensions.push((len as usize, lower_bnds));
+                }
 
-                // length of each array axis
-                let _dimensions = buf.get_i32::<BE>()?;
+                if ndim > 1 {
+                    if T::type_info().compatible(&PgTypeInfo::with_oid(elemtype)) {
+                        return Err(decode_err!(
+                            "encountered an array of {} dimensions; decode it into a nested `Vec` for each dimension",
+                            ndim
+                        ));
+                    }
+
+                    let elements = dimensions[1..]
+                        .iter()
+                        .try_fold(1_usize, |elements, &(len, _)| elements.checked_mul(len))
+                        .ok_or_else(|| {
+                            decode_err!("array of {:?} elements is too large", dimensions)
+                        })?;
+
+                    // the header of each sub-array is that of the array without its first dimension
+                    let mut header = Vec::with_capacity(12 + 8 * (dimensions.len() - 1));
+                    header.put_i32::<BE>(ndim - 1);
+                    header.put_i32::<BE>(dataoffset);
+                    header.put_u32::<BE>(elemtype);
+
+                    for &(len, lower_bnds) in &dimensions[1..] {
+                        header.put_i32::<BE>(len as i32);
+                        header.put_i32::<BE>(lower_bnds);
+                    }
+
+                    return Ok(Self::from_elements(PgArrayElements::SubArrays {
+                        header,
+                        elements,
+                        remaining: dimensions[0].0,
+                        buf: *buf,
+                    }));
+                }
 
-                // lower boundary of each dimension
-                let lower_bnds = buf.get_i32::<BE>()?;
+                return Ok(Self::from_elements(PgArrayElements::Sequence {
+                    inner: PgSequenceDecoder::new(PgValue::Binary(*buf), false),
+                    len: Some(dimensions[0].0),
+                }));
+            }
 
-                if lower_bnds != 1 {
-                    return Err(decode_err!(
-                        "encountered an array with a lower bound of {} in the first dimension; only arrays starting at one are supported",
-                        lower_bnds
-                    ));
+            PgValue::Text(ref mut s) => {
+                // an array with a lower bound other than one is prefixed by its bounds,
+                // e.g., `[0:2]={1,2,3}`
+                if s.starts_with('[') {
+                    let start = s
+                        .find("]={")
+                        .ok_or_else(|| decode_err!("invalid array bounds: {:?}", s))?;
+
+                    *s = &s[(start + 2)..];
                 }
             }
-
-            PgValue::Text(_) => {}
         }
 
-        Ok(Self {
+        Ok(Self::from_elements(PgArrayElements::Sequence {
             inner: PgSequenceDecoder::new(value, false),
+            len: None,
+        }))
+    }
+
+    fn from_elements(elements: PgArrayElements<'de>) -> Self {
+        Self {
+            elements,
             phantom: PhantomData,
-        })
+        }
     }
 
     fn decode(&mut self) -> crate::Result<Postgres, Option<T>> {
-        self.inner.decode()
+        match self.elements {
+            PgArrayElements::Sequence { ref mut inner, len } => {
+                let value = inner.decode()?;
+
+                match len {
+                    Some(len) if value.is_none() && inner.len() != len => Err(decode_err!(
+                        "encountered an array of {} elements; expected {} elements",
+                        inner.len(),
+                        len
+                    )),
+
+                    _ => Ok(value),
+                }
+            }
+
+            PgArrayElements::SubArrays {
+                ref header,
+                elements,
+                ref mut remaining,
+                ref mut buf,
+            } => {
+                if *remaining == 0 {
+                    if !buf.is_empty() {
+                        return Err(decode_err!(
+                            "encountered {} unexpected bytes after the last sub-array",
+                            buf.len()
+                        ));
+                    }
+
+                    return Ok(None);
+                }
+
+                // find the end of the elements of this sub-array
+                let mut end = 0;
+
+                for _ in 0..elements {
+                    let truncated = || {
+                        decode_err!(
+                            "encountered a truncated sub-array; expected {} elements",
+                            elements
+                        )
+                    };
+
+                    if buf.len() - end < 4 {
+                        return Err(truncated());
+                    }
+
+                    let mut element = &buf[end..];
+                    let len = element.get_i32::<BE>()?;
+
+                    end += 4 + len.max(0) as usize;
+
+                    if end > buf.len() {
+                        return Err(truncated());
+                    }
+                }
+
+                let mut sub_array = header.clone();
+                sub_array.extend_from_slice(&buf[..end]);
+
+                *buf = &buf[end..];
+                *remaining -= 1;
+
+                T::decode(Some(PgValue::Binary(&sub_array))).map(Some)
+            }
+        }
     }
 }
 
@@ -216,6 +386,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_decodes_text_nulls() -> crate::Result<Postgres, ()> {
+        let s = "{1,NULL,3}";
+        let decoder = PgArrayDecoder::<Option<i32>>::new(Some(PgValue::Text(s)))?;
+        let value = decoder.collect::<crate::Result<Postgres, Vec<_>>>()?;
+
+        assert_eq!(value, [Some(1), None, Some(3)]);
+
+        let decoder = PgArrayDecoder::<i32>::new(Some(PgValue::Text(s)))?;
+        assert!(decoder
+            .collect::<crate::Result<Postgres, Vec<_>>>()
+            .is_err());
+
+        // only an unquoted NULL is a NULL
+        let s = "{NULL,\"NULL\"}";
+        let decoder = PgArrayDecoder::<Option<String>>::new(Some(PgValue::Text(s)))?;
+        let value = decoder.collect::<crate::Result<Postgres, Vec<_>>>()?;
+
+        assert_eq!(value, [None, Some("NULL".to_owned())]);
+
+        Ok(())
+    }
+
     #[test]
     fn it_decodes_binary_nulls() -> crate::Result<Postgres, ()> {
         let mut decoder = PgArrayDecoder::<Option<bool>>::new(Some(PgValue::Binary(
@@ -230,6 +423,85 @@ mod tests {
         Ok(())
     }
 
+    // SELECT ARRAY[[1, 2, 3], [4, 5, 6]]::int[][]
+    const BUF_BINARY_I32_2D: &[u8] = b"\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x17\x00\x00\x00\x02\x00\x00\x00\x01\x00\x00\x00\x03\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x02\x00\x00\x00\x04\x00\x00\x00\x03\x00\x00\x00\x04\x00\x00\x00\x04\x00\x00\x00\x04\x00\x00\x00\x05\x00\x00\x00\x04\x00\x00\x00\x06";
+
+    #[test]
+    fn it_decodes_binary_i32_2d() -> crate::Result<Postgres, ()> {
+        let decoder = PgArrayDecoder::<Vec<i32>>::new(Some(PgValue::Binary(BUF_BINARY_I32_2D)))?;
+        let value = decoder.collect::<crate::Result<Postgres, Vec<_>>>()?;
+
+        assert_eq!(value, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_invalid_binary_i32_2d() {
+        let decode = |buf: &[u8]| {
+            PgArrayDecoder::<Vec<i32>>::new(Some(PgValue::Binary(buf)))
+                .and_then(|decoder| decoder.collect::<crate::Result<Postgres, Vec<_>>>())
+        };
+
+        // missing the last element
+        assert!(decode(&BUF_BINARY_I32_2D[..BUF_BINARY_I32_2D.len() - 8]).is_err());
+
+        // an extra element
+        let mut buf = BUF_BINARY_I32_2D.to_vec();
+        buf.extend_from_slice(b"\x00\x00\x00\x04\x00\x00\x00\x07");
+        assert!(decode(&buf).is_err());
+
+        // a negative length in the second dimension
+        let mut buf = BUF_BINARY_I32_2D.to_vec();
+        buf[20..24].copy_from_slice(&(-3_i32).to_be_bytes());
+        assert!(decode(&buf).is_err());
+
+        // a one-dimensional array of elements that are not arrays
+        assert!(decode(BUF_BINARY_I32).is_err());
+
+        // not enough dimensions to decode into
+        assert!(PgArrayDecoder::<i32>::new(Some(PgValue::Binary(BUF_BINARY_I32_2D))).is_err());
+    }
+
+    #[test]
+    fn it_decodes_lower_bounds() -> crate::Result<Postgres, ()> {
+        // SELECT '[0:1]={1,2}'::int[]
+        let buf = b"\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x17\x00\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x04\x00\x00\x00\x01\x00\x00\x00\x04\x00\x00\x00\x02";
+        let decoder = PgArrayDecoder::<i32>::new(Some(PgValue::Binary(buf)))?;
+        assert_eq!(
+            decoder.collect::<crate::Result<Postgres, Vec<_>>>()?,
+            [1, 2]
+        );
+
+        let decoder = PgArrayDecoder::<i32>::new(Some(PgValue::Text("[0:1]={1,2}")))?;
+        assert_eq!(
+            decoder.collect::<crate::Result<Postgres, Vec<_>>>()?,
+            [1, 2]
+        );
+
+        let decoder = PgArrayDecoder::<Vec<i32>>::new(Some(PgValue::Text("[2:2][-1:0]={{1,2}}")))?;
+        assert_eq!(
+            decoder.collect::<crate::Result<Postgres, Vec<_>>>()?,
+            [[1, 2]]
+        );
+
+        assert!(PgArrayDecoder::<i32>::new(Some(PgValue::Text("[0:1]{1,2}"))).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_decodes_text_i32_2d() -> crate::Result<Postgres, ()> {
+        let s = "{{1,2,3},{4,5,6}}";
+        let mut decoder = PgArrayDecoder::<Vec<i32>>::new(Some(PgValue::Text(s)))?;
+
+        assert_eq!(decoder.decode()?, Some(vec![1, 2, 3]));
+        assert_eq!(decoder.decode()?, Some(vec![4, 5, 6]));
+        assert_eq!(decoder.decode()?, None);
+
+        Ok(())
+    }
+
     #[test]
     fn it_decodes_binary_i32() -> crate::Result<Postgres, ()> {
         let mut decoder = PgArrayDecoder::<i32>::new(Some(PgValue::Binary(BUF_BINARY_I32)))?;
@@ -44,6 +44,8 @@ where
 
 // This type has the Pg prefix as it is a postgres-only extension
 // unlike the normal Json<T> wrapper
+/// ### Note
+/// This also decodes `JSON`, which is sent without the version byte of `JSONB`.
 #[derive(Debug, PartialEq)]
 pub struct PgJsonb<T>(pub T);
 
@@ -75,13 +77,22 @@ where
         (match value.try_into()? {
             PgValue::Text(s) => serde_json::from_str(s),
             PgValue::Binary(mut buf) => {
-                let version = buf.get_u8()?;
+                // JSON text never starts with a control character other than whitespace,
+                // so anything else is the version of the JSONB format
+                match buf.first() {
+                    Some(&version) if version < 0x20 && !b"\t\n\r".contains(&version) => {
+                        if version != 1 {
+                            return Err(decode_err!(
+                                "unsupported JSONB format version {}; please open an issue",
+                                version
+                            ));
+                        }
 
-                assert_eq!(
-                    version, 1,
-                    "unsupported JSONB format version {}; please open an issue",
-                    version
-                );
+                        buf.advance(1);
+                    }
+
+                    _ => {}
+                }
 
                 serde_json::from_slice(buf)
             }
@@ -90,3 +101,48 @@ where
         .map_err(crate::Error::decode)
     }
 }
+
+#[test]
+fn test_json_types_are_compatible() {
+    use crate::types::TypeInfo;
+
+    let json = <PgJson<()> as Type<Postgres>>::type_info();
+    let jsonb = <PgJsonb<()> as Type<Postgres>>::type_info();
+
+    assert!(json.compatible(&jsonb));
+    assert!(jsonb.compatible(&json));
+
+    let json_array = PgTypeInfo::new(TypeId::ARRAY_JSON, "JSON[]");
+    let jsonb_array = PgTypeInfo::new(TypeId::ARRAY_JSONB, "JSONB[]");
+
+    assert!(json_array.compatible(&jsonb_array));
+    assert!(jsonb_array.compatible(&json_array));
+
+    assert!(!json.compatible(&jsonb_array));
+    assert!(!json.compatible(&PgTypeInfo::new(TypeId::TEXT, "TEXT")));
+}
+
+#[test]
+fn test_decode_json_and_jsonb() {
+    let jsonb: PgJsonb<Vec<u32>> =
+        Decode::<Postgres>::decode(Some(PgValue::Binary(b"\x01[1, 2]"))).unwrap();
+    assert_eq!(jsonb.0, [1, 2]);
+
+    // JSON does not have a version byte
+    let json: PgJsonb<Vec<u32>> =
+        Decode::<Postgres>::decode(Some(PgValue::Binary(b"\n[1, 2]"))).unwrap();
+    assert_eq!(json.0, [1, 2]);
+
+    let json: PgJson<Vec<u32>> =
+        Decode::<Postgres>::decode(Some(PgValue::Binary(b"[1, 2]"))).unwrap();
+    assert_eq!(json.0, [1, 2]);
+
+    assert!(
+        <PgJsonb<Vec<u32>> as Decode<Postgres>>::decode(Some(PgValue::Binary(b"\x02[1, 2]")))
+            .is_err()
+    );
+
+    let mut buf = Vec::new();
+    PgJsonb(vec![1]).encode(&mut buf);
+    assert_eq!(buf, b"\x01[1]");
+}
@@ -1,5 +1,4 @@
 mod array;
-mod numeric;
 mod record;
 mod sequence;
 
@@ -11,8 +10,5 @@ pub use json::{PgJson, PgJsonb};
 
 pub(crate) use array::{PgArrayDecoder, PgArrayEncoder};
 
-// Used in integration tests
-pub use numeric::{PgNumeric, PgNumericSign};
-
 // Used in #[derive(Type)] for structs
 pub use record::{PgRecordDecoder, PgRecordEncoder};
@@ -1,6 +1,6 @@
 use crate::decode::DecodeOwned;
 use crate::io::Buf;
-use crate::postgres::{PgValue, Postgres};
+use crate::postgres::{PgTypeInfo, PgValue, Postgres};
 use crate::types::Type;
 use byteorder::BigEndian;
 
@@ -48,24 +48,37 @@ impl<'de> PgSequenceDecoder<'de> {
 
                 // mixed sequences can contain values of many different types
                 // the OID of the type is encoded next to each value
-                if self.mixed {
+                let type_info = if self.mixed {
                     // TODO: We should fail if this type is not _compatible_; but
                     //       I want to make sure we handle this _and_ the outer level
                     //       type mismatch errors at the same time
 
-                    let _oid = buf.get_u32::<BigEndian>()?;
-                }
+                    Some(PgTypeInfo::with_oid(buf.get_u32::<BigEndian>()?))
+                } else {
+                    None
+                };
+
+                let decode = |value| match &type_info {
+                    Some(type_info) => T::decode_with_type(type_info, value),
+                    None => T::decode(value),
+                };
 
                 let len = buf.get_i32::<BigEndian>()? as isize;
 
                 let value = if len < 0 {
-                    T::decode(None)?
+                    decode(None)?
+                } else if len as usize > buf.len() {
+                    return Err(decode_err!(
+                        "encountered a truncated value of {} bytes; only {} bytes remain",
+                        len,
+                        buf.len()
+                    ));
                 } else {
                     let value_buf = &buf[..(len as usize)];
 
                     *buf = &buf[(len as usize)..];
 
-                    T::decode(Some(PgValue::Binary(value_buf)))?
+                    decode(Some(PgValue::Binary(value_buf)))?
                 };
 
                 self.len += 1;
@@ -78,11 +91,40 @@ impl<'de> PgSequenceDecoder<'de> {
                     return Ok(None);
                 }
 
+                // the sub-arrays of a multidimensional array are not quoted so they are passed on
+                // as-is to be decoded as arrays themselves
+                if !self.mixed && s.starts_with('{') {
+                    let text: &'de str = *s;
+
+                    let end = sub_array_end(text).ok_or_else(|| {
+                        decode_err!("encountered an unterminated sub-array: {:?}", text)
+                    })?;
+
+                    let value = T::decode(Some(PgValue::Text(&text[..=end])))?;
+
+                    let rest = &text[end + 1..];
+
+                    *s = if rest.is_empty() {
+                        rest
+                    } else if rest.starts_with(',') {
+                        &rest[1..]
+                    } else {
+                        return Err(decode_err!("expected `,` after a sub-array: {:?}", text));
+                    };
+
+                    self.len += 1;
+
+                    return Ok(Some(value));
+                }
+
                 let mut value = String::new();
                 let mut in_quotes = false;
                 let mut in_escape = false;
                 let mut in_maybe_quote_escape = false;
 
+                // a quoted `"NULL"` is the string and not a NULL
+                let mut quoted = false;
+
                 let end: Option<usize> = 'outer: loop {
                     let mut iter = s.char_indices().peekable();
                     while let Some((index, ch)) = iter.next() {
@@ -123,6 +165,7 @@ impl<'de> PgSequenceDecoder<'de> {
 
                             '"' => {
                                 in_quotes = !in_quotes;
+                                quoted = true;
                             }
 
                             _ => value.push(ch),
@@ -135,7 +178,7 @@ impl<'de> PgSequenceDecoder<'de> {
 
                 let value = T::decode(if end == Some(0) {
                     None
-                } else if !self.mixed && value == "NULL" {
+                } else if !self.mixed && !quoted && value == "NULL" {
                     // Yes, in arrays the text encoding of a NULL is just NULL
                     None
                 } else {
@@ -156,6 +199,34 @@ impl<'de> PgSequenceDecoder<'de> {
     }
 }
 
+// the index of the `}` that closes the `{` at the start of `s`
+fn sub_array_end(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut in_quotes = false;
+    let mut in_escape = false;
+
+    for (index, ch) in s.char_indices() {
+        match ch {
+            _ if in_escape => in_escape = false,
+            '\\' => in_escape = true,
+            '"' => in_quotes = !in_quotes,
+            '{' if !in_quotes => depth += 1,
+
+            '}' if !in_quotes => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    None
+}
+
 impl<'de> From<&'de str> for PgSequenceDecoder<'de> {
     fn from(s: &'de str) -> Self {
         Self::new(PgValue::Text(s), false)
@@ -89,3 +89,45 @@ impl_pg_record_for_tuple!(
     _8: T8,
     _9: T9
 );
+
+impl_pg_record_for_tuple!(
+    _1: T1,
+    _2: T2,
+    _3: T3,
+    _4: T4,
+    _5: T5,
+    _6: T6,
+    _7: T7,
+    _8: T8,
+    _9: T9,
+    _10: T10
+);
+
+impl_pg_record_for_tuple!(
+    _1: T1,
+    _2: T2,
+    _3: T3,
+    _4: T4,
+    _5: T5,
+    _6: T6,
+    _7: T7,
+    _8: T8,
+    _9: T9,
+    _10: T10,
+    _11: T11
+);
+
+impl_pg_record_for_tuple!(
+    _1: T1,
+    _2: T2,
+    _3: T3,
+    _4: T4,
+    _5: T5,
+    _6: T6,
+    _7: T7,
+    _8: T8,
+    _9: T9,
+    _10: T10,
+    _11: T11,
+    _12: T12
+);
@@ -0,0 +1,258 @@
+use std::convert::{TryFrom, TryInto};
+
+use rust_decimal::Decimal;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::{PgTypeInfo, PgValue, Postgres};
+use crate::types::Type;
+
+use super::{PgNumeric, PgNumericSign};
+
+// the largest scale supported by `Decimal`
+const MAX_SCALE: i32 = 28;
+
+// the mantissa of a `Decimal` is a 96-bit unsigned integer
+const MAX_MANTISSA: u128 = (1 << 96) - 1;
+
+impl Type<Postgres> for Decimal {
+    fn type_info() -> PgTypeInfo {
+        <PgNumeric as Type<Postgres>>::type_info()
+    }
+}
+
+impl Type<Postgres> for [Decimal] {
+    fn type_info() -> PgTypeInfo {
+        <[PgNumeric] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Type<Postgres> for Vec<Decimal> {
+    fn type_info() -> PgTypeInfo {
+        <[PgNumeric] as Type<Postgres>>::type_info()
+    }
+}
+
+impl From<&'_ Decimal> for PgNumeric {
+    fn from(decimal: &Decimal) -> Self {
+        let bytes = decimal.serialize();
+        let scale = decimal.scale() as i32;
+
+        // bytes 4..16 are the little-endian `lo`, `mid` and `hi` words of the mantissa
+        let word = |i: usize| {
+            u128::from(u32::from_le_bytes([
+                bytes[i],
+                bytes[i + 1],
+                bytes[i + 2],
+                bytes[i + 3],
+            ]))
+        };
+
+        let mut mantissa = word(4) | word(8) << 32 | word(12) << 64;
+
+        if mantissa == 0 {
+            return PgNumeric::Number {
+                sign: PgNumericSign::Positive,
+                digits: vec![],
+                weight: 0,
+                scale: scale as i16,
+            };
+        }
+
+        // line the decimal point up with a base-10000 digit boundary; this can't overflow as
+        // the mantissa is at most 96 bits
+        let fraction_digits = (scale + 3) / 4;
+        mantissa *= 10_u128.pow((fraction_digits * 4 - scale) as u32);
+
+        let mut digits = Vec::new();
+
+        while mantissa != 0 {
+            digits.push((mantissa % 10_000) as i16);
+            mantissa /= 10_000;
+        }
+
+        digits.reverse();
+
+        let weight = (digits.len() as i32 - fraction_digits - 1) as i16;
+
+        while let Some(&0) = digits.last() {
+            digits.pop();
+        }
+
+        PgNumeric::Number {
+            sign: if decimal.is_sign_negative() {
+                PgNumericSign::Negative
+            } else {
+                PgNumericSign::Positive
+            },
+            digits,
+            weight,
+            scale: scale as i16,
+        }
+    }
+}
+
+impl TryFrom<PgNumeric> for Decimal {
+    type Error = crate::Error<Postgres>;
+
+    fn try_from(numeric: PgNumeric) -> crate::Result<Postgres, Self> {
+        let (digits, sign, weight, scale) = match numeric {
+            PgNumeric::Number {
+                digits,
+                sign,
+                weight,
+                scale,
+            } => (digits, sign, i32::from(weight), i32::from(scale)),
+
+            PgNumeric::NotANumber => {
+                return Err(crate::Error::Decode(
+                    "Decimal does not support NaN values".into(),
+                ))
+            }
+        };
+
+        let out_of_range = || crate::Error::Decode("NUMERIC value out of range for Decimal".into());
+
+        if scale > MAX_SCALE {
+            return Err(crate::Error::Decode(
+                format!(
+                    "NUMERIC scale of {} exceeds the maximum scale of Decimal ({})",
+                    scale, MAX_SCALE
+                )
+                .into(),
+            ));
+        }
+
+        let mut mantissa: u128 = 0;
+
+        for &digit in &digits {
+            mantissa = mantissa
+                .checked_mul(10_000)
+                .and_then(|mantissa| mantissa.checked_add(digit as u128))
+                .ok_or_else(out_of_range)?;
+        }
+
+        // the digits are an integer times `10000 ^ (weight - digits.len() + 1)`; rescale that
+        // integer so it is the mantissa for `scale` fractional digits
+        let exponent = (weight - digits.len() as i32 + 1) * 4 + scale;
+
+        if exponent >= 0 {
+            mantissa = 10_u128
+                .checked_pow(exponent as u32)
+                .and_then(|factor| mantissa.checked_mul(factor))
+                .ok_or_else(out_of_range)?;
+        } else {
+            // only trailing zeroes can be dropped here as Postgres keeps every digit within `scale`
+            mantissa /= 10_u128
+                .checked_pow((-exponent) as u32)
+                .unwrap_or(u128::max_value());
+        }
+
+        if mantissa > MAX_MANTISSA {
+            return Err(out_of_range());
+        }
+
+        Ok(Decimal::from_parts(
+            mantissa as u32,
+            (mantissa >> 32) as u32,
+            (mantissa >> 64) as u32,
+            sign == PgNumericSign::Negative && mantissa != 0,
+            scale as u32,
+        ))
+    }
+}
+
+impl Encode<Postgres> for Decimal {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        PgNumeric::from(self).encode(buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        // a 96-bit mantissa has at most 29 decimal digits which, once aligned to the decimal
+        // point, is at most 9 base-10000 digits
+        8 + 9 * 2
+    }
+}
+
+impl Decode<'_, Postgres> for Decimal {
+    fn decode(value: Option<PgValue>) -> crate::Result<Postgres, Self> {
+        match value.try_into()? {
+            PgValue::Binary(binary) => PgNumeric::from_bytes(binary)?.try_into(),
+            PgValue::Text(text) => text.parse::<PgNumeric>()?.try_into(),
+        }
+    }
+}
+
+#[test]
+fn test_decimal_to_pgnumeric() {
+    let decimal = |s: &str| s.parse::<Decimal>().unwrap();
+
+    assert_eq!(
+        PgNumeric::from(&decimal("0")),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            digits: vec![],
+            weight: 0,
+            scale: 0,
+        }
+    );
+
+    assert_eq!(
+        PgNumeric::from(&decimal("12345.6789")),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            digits: vec![1, 2345, 6789],
+            weight: 1,
+            scale: 4,
+        }
+    );
+
+    assert_eq!(
+        PgNumeric::from(&decimal("-0.01234")),
+        PgNumeric::Number {
+            sign: PgNumericSign::Negative,
+            digits: vec![123, 4000],
+            weight: -1,
+            scale: 5,
+        }
+    );
+
+    assert_eq!(
+        PgNumeric::from(&decimal("10000.00")),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            digits: vec![1],
+            weight: 1,
+            scale: 2,
+        }
+    );
+}
+
+#[test]
+fn test_pgnumeric_to_decimal() {
+    for s in &[
+        "0",
+        "0.00",
+        "1",
+        "-1.500",
+        "12345.6789",
+        "0.0000000000000000000000000001",
+        "79228162514264337593543950335",
+        "-79228162514264337593543950335",
+    ] {
+        let numeric: PgNumeric = s.parse().unwrap();
+        let decimal = Decimal::try_from(numeric).unwrap();
+
+        assert_eq!(decimal.to_string(), *s);
+        assert_eq!(PgNumeric::from(&decimal), s.parse().unwrap());
+    }
+
+    // 2 ^ 96
+    let too_large: PgNumeric = "79228162514264337593543950336".parse().unwrap();
+    assert!(Decimal::try_from(too_large).is_err());
+
+    let too_precise: PgNumeric = "0.00000000000000000000000000001".parse().unwrap();
+    assert!(Decimal::try_from(too_precise).is_err());
+
+    assert!(Decimal::try_from(PgNumeric::NotANumber).is_err());
+}
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::convert::TryInto;
 use std::str::from_utf8;
 
@@ -46,6 +47,12 @@ impl Type<Postgres> for Vec<String> {
     }
 }
 
+impl Type<Postgres> for Cow<'_, str> {
+    fn type_info() -> PgTypeInfo {
+        <str as Type<Postgres>>::type_info()
+    }
+}
+
 impl Encode<Postgres> for str {
     fn encode(&self, buf: &mut Vec<u8>) {
         buf.extend_from_slice(self.as_bytes());
@@ -66,12 +73,24 @@ impl Encode<Postgres> for String {
     }
 }
 
+impl Encode<Postgres> for Cow<'_, str> {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <str as Encode<Postgres>>::encode(self, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+
 impl<'de> Decode<'de, Postgres> for String {
     fn decode(buf: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
         <&'de str as Decode<Postgres>>::decode(buf).map(ToOwned::to_owned)
     }
 }
 
+// The binary format of `XML` (and other text-like types) is the UTF-8 text itself so those
+// can be decoded here as well
 impl<'de> Decode<'de, Postgres> for &'de str {
     fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
         match value.try_into()? {
@@ -80,3 +99,10 @@ impl<'de> Decode<'de, Postgres> for &'de str {
         }
     }
 }
+
+// The value is always borrowed from the row; use `into_owned` to keep it past the row
+impl<'de> Decode<'de, Postgres> for Cow<'de, str> {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
+        <&'de str as Decode<Postgres>>::decode(value).map(Cow::Borrowed)
+    }
+}
@@ -1,9 +1,10 @@
-use std::borrow::Cow;
 use std::convert::TryInto;
 use std::mem;
 
 use byteorder::BigEndian;
-use time::{date, offset, Date, NumericalDuration, OffsetDateTime, PrimitiveDateTime, Time};
+use time::{
+    date, offset, Date, NumericalDuration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset,
+};
 
 use crate::decode::Decode;
 use crate::encode::Encode;
@@ -117,21 +118,70 @@ impl<'de> Decode<'de, Postgres> for Time {
                 from_microseconds_since_midnight(micros as u64)
             }
 
-            PgValue::Text(s) => {
-                // If there are less than 9 digits after the decimal point
-                // We need to zero-pad
-                // TODO: Ask [time] to add a parse % for less-than-fixed-9 nanos
-
-                let s = if s.len() < 20 {
-                    Cow::Owned(format!("{:0<19}", s))
-                } else {
-                    Cow::Borrowed(s)
-                };
+            PgValue::Text(s) => parse_time(s),
+        }
+    }
+}
 
-                Time::parse(&*s, "%H:%M:%S.%N").map_err(crate::Error::decode)
-            }
+// `HH:MM:SS` with up to 9 digits of a fraction of a second
+fn parse_time(s: &str) -> crate::Result<Postgres, Time> {
+    let (hms, fraction) = match s.find('.') {
+        Some(index) => (&s[..index], &s[(index + 1)..]),
+        None => return Time::parse(s, "%H:%M:%S").map_err(crate::Error::decode),
+    };
+
+    let time = Time::parse(hms, "%H:%M:%S").map_err(crate::Error::decode)?;
+
+    if fraction.is_empty() || fraction.len() > 9 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(decode_err!("invalid fraction of a second in TIME: {:?}", s));
+    }
+
+    let nanosecond: u32 = format!("{:0<9}", fraction)
+        .parse()
+        .map_err(crate::Error::decode)?;
+
+    Time::try_from_hms_nano(time.hour(), time.minute(), time.second(), nanosecond)
+        .map_err(crate::Error::decode)
+}
+
+// `YYYY-MM-DD HH:MM:SS[.fraction]`
+fn parse_date_time(s: &str) -> crate::Result<Postgres, PrimitiveDateTime> {
+    let mut parts = s.splitn(2, ' ');
+
+    let date =
+        Date::parse(parts.next().unwrap_or_default(), "%Y-%m-%d").map_err(crate::Error::decode)?;
+
+    let time = match parts.next() {
+        Some(time) => parse_time(time)?,
+        None => return Err(decode_err!("invalid TIMESTAMP value: {:?}", s)),
+    };
+
+    Ok(PrimitiveDateTime::new(date, time))
+}
+
+// `+HH`, `+HH:MM` or `+HH:MM:SS`
+fn parse_offset(s: &str) -> crate::Result<Postgres, UtcOffset> {
+    let invalid = || decode_err!("invalid UTC offset: {:?}", s);
+
+    let (sign, hms) = match s.as_bytes().first() {
+        Some(b'+') => (1, &s[1..]),
+        Some(b'-') => (-1, &s[1..]),
+        _ => return Err(invalid()),
+    };
+
+    let mut seconds = 0;
+    let mut unit = 60 * 60;
+
+    for part in hms.splitn(3, ':') {
+        if part.len() != 2 || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
         }
+
+        seconds += part.parse::<i32>().map_err(|_| invalid())? * unit;
+        unit /= 60;
     }
+
+    Ok(UtcOffset::seconds(sign * seconds))
 }
 
 impl Encode<Postgres> for Time {
@@ -185,34 +235,7 @@ impl<'de> Decode<'de, Postgres> for PrimitiveDateTime {
                 Ok(POSTGRES_EPOCH + n.microseconds())
             }
 
-            // TODO: Try and fix duplication between here and MySQL
-            PgValue::Text(s) => {
-                // If there are less than 9 digits after the decimal point
-                // We need to zero-pad
-                // TODO: Ask [time] to add a parse % for less-than-fixed-9 nanos
-
-                let s = if let Some(plus) = s.rfind('+') {
-                    let mut big = String::from(&s[..plus]);
-
-                    while big.len() < 31 {
-                        big.push('0');
-                    }
-
-                    big.push_str(&s[plus..]);
-
-                    Cow::Owned(big)
-                } else if s.len() < 31 {
-                    if s.contains('.') {
-                        Cow::Owned(format!("{:0<30}", s))
-                    } else {
-                        Cow::Owned(format!("{}.000000000", s))
-                    }
-                } else {
-                    Cow::Borrowed(s)
-                };
-
-                PrimitiveDateTime::parse(&*s, "%Y-%m-%d %H:%M:%S.%N").map_err(crate::Error::decode)
-            }
+            PgValue::Text(s) => parse_date_time(s),
         }
     }
 }
@@ -232,11 +255,29 @@ impl Encode<Postgres> for PrimitiveDateTime {
     }
 }
 
+/// The time is converted to UTC.
 impl<'de> Decode<'de, Postgres> for OffsetDateTime {
     fn decode(value: Option<PgValue<'de>>) -> crate::Result<Postgres, Self> {
-        let primitive: PrimitiveDateTime = Decode::<Postgres>::decode(value)?;
+        match value.try_into()? {
+            PgValue::Binary(buf) => {
+                let primitive: PrimitiveDateTime =
+                    Decode::<Postgres>::decode(Some(PgValue::Binary(buf)))?;
+
+                Ok(primitive.assume_utc())
+            }
+
+            // in the time zone of the session, e.g., `2019-01-02 05:10:20.1151+05:30`
+            PgValue::Text(s) => {
+                let (date_time, offset) = match s.rfind(|ch| ch == '+' || ch == '-') {
+                    Some(index) if s[..index].contains(' ') => (&s[..index], &s[index..]),
+                    _ => (s, "+00"),
+                };
 
-        Ok(primitive.assume_utc())
+                Ok(parse_date_time(date_time)?
+                    .assume_offset(parse_offset(offset)?)
+                    .to_offset(offset!(UTC)))
+            }
+        }
     }
 }
 
@@ -428,3 +469,34 @@ fn test_decode_date() {
     let date: Date = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
     assert_eq!(date, date!(2019 - 12 - 11));
 }
+
+#[test]
+fn test_parse_text() {
+    assert_eq!(parse_time("05:10:20").unwrap(), time!(5:10:20));
+    assert_eq!(parse_time("05:10:20.1151").unwrap(), time!(5:10:20.1151));
+    assert!(parse_time("05:10:20.").is_err());
+    assert!(parse_time("05:10:20.1234567890").is_err());
+
+    assert_eq!(
+        parse_date_time("1999-12-31 23:59:59.5").unwrap(),
+        date!(1999 - 12 - 31).with_time(time!(23:59:59.5))
+    );
+    assert!(parse_date_time("1999-12-31").is_err());
+
+    assert_eq!(parse_offset("+00").unwrap(), offset!(UTC));
+    assert_eq!(parse_offset("-05").unwrap(), offset!(-5));
+    assert_eq!(parse_offset("+05:30").unwrap(), UtcOffset::minutes(330));
+    assert_eq!(parse_offset("-00:00:15").unwrap(), UtcOffset::seconds(-15));
+    assert!(parse_offset("05").is_err());
+    assert!(parse_offset("+5").is_err());
+
+    let date_time: OffsetDateTime =
+        Decode::<Postgres>::decode(Some(PgValue::Text("2019-01-02 10:40:20.1151+05:30"))).unwrap();
+
+    assert_eq!(
+        date_time,
+        date!(2019 - 1 - 2)
+            .with_time(time!(5:10:20.1151))
+            .assume_utc()
+    );
+}
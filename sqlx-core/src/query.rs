@@ -172,6 +172,31 @@ where
         val.map(|row| mapper.try_map_row(row)).transpose()
     }
 
+    /// Get the only row in the result, if any; unlike [`fetch_optional`], more than one row
+    /// is an error ([`Error::TooManyRows`]).
+    ///
+    /// [`fetch_optional`]: #method.fetch_optional
+    /// [`Error::TooManyRows`]: crate::Error::TooManyRows
+    pub async fn fetch_at_most_one<'e, E>(self, executor: E) -> crate::Result<DB, Option<F::Output>>
+    where
+        E: RefExecutor<'e, Database = DB>,
+        'q: 'e,
+    {
+        let mut cursor = executor.fetch_by_ref(self.query);
+        let mut mapper = self.mapper;
+
+        let val = match cursor.next().await? {
+            Some(row) => mapper.try_map_row(row)?,
+            None => return Ok(None),
+        };
+
+        if cursor.next().await?.is_some() {
+            return Err(crate::Error::<DB>::TooManyRows);
+        }
+
+        Ok(Some(val))
+    }
+
     pub async fn fetch_one<'e, E>(self, executor: E) -> crate::Result<DB, F::Output>
     where
         E: RefExecutor<'e, Database = DB>,
@@ -49,6 +49,21 @@ where
 /// using [`FromRow`](crate::row::FromRow).
 ///
 /// Returns [`QueryAs`].
+///
+/// The rows returned by a statement that modifies data, e.g., `INSERT .. RETURNING` or
+/// `UPDATE .. RETURNING` in Postgres, are decoded like those of a `SELECT`; the statement is
+/// executed and its rows are decoded in a single call:
+///
+/// ```rust,ignore
+/// let (id,): (i64,) = sqlx::query_as("INSERT INTO users (name) VALUES ($1) RETURNING id")
+///     .bind("Alice")
+///     .fetch_one(&mut conn)
+///     .await?;
+/// ```
+///
+/// `fetch_one` returns as soon as the first row is received. When a statement returns several
+/// rows, use `fetch_all` so that an error the statement raises after its first row, which undoes
+/// the whole statement, is returned as well.
 pub fn query_as<DB, O>(sql: &str) -> QueryAs<DB, O>
 where
     DB: Database,
@@ -107,6 +122,15 @@ macro_rules! make_query_as {
                 E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
                 O: 'e + Send + for<'c> crate::row::FromRow<'c, $row<'c>>,
                 'q: 'e;
+
+            fn fetch_at_most_one<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::future::BoxFuture<'e, crate::Result<$db, Option<O>>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send + for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e;
         }
 
         impl<'q, O> $name<'q, O> for crate::query_as::QueryAs<'q, $db, O> {
@@ -151,6 +175,33 @@ macro_rules! make_query_as {
                 })
             }
 
+            fn fetch_at_most_one<'e, E>(
+                self,
+                executor: E,
+            ) -> futures_core::future::BoxFuture<'e, crate::Result<$db, Option<O>>>
+            where
+                E: 'e + Send + crate::executor::RefExecutor<'e, Database = $db>,
+                O: 'e + Send + for<'c> crate::row::FromRow<'c, $row<'c>>,
+                'q: 'e,
+            {
+                use crate::cursor::Cursor;
+
+                Box::pin(async move {
+                    let mut cursor = executor.fetch_by_ref(self);
+
+                    let obj = match cursor.next().await? {
+                        Some(row) => O::from_row(&row)?,
+                        None => return Ok(None),
+                    };
+
+                    if cursor.next().await?.is_some() {
+                        return Err(crate::Error::TooManyRows);
+                    }
+
+                    Ok(Some(obj))
+                })
+            }
+
             fn fetch_one<'e, E>(
                 self,
                 executor: E,
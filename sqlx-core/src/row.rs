@@ -31,7 +31,7 @@ where
 impl<'c, R, I> ColumnIndex<'c, R> for &'_ I
 where
     R: Row<'c>,
-    I: ColumnIndex<'c, R>,
+    I: ColumnIndex<'c, R> + ?Sized,
 {
     #[inline]
     fn index(&self, row: &R) -> crate::Result<<R as Row<'c>>::Database, usize> {
@@ -44,7 +44,7 @@ mod private_column_index {
     pub trait Sealed {}
     impl Sealed for usize {}
     impl Sealed for str {}
-    impl<T> Sealed for &'_ T where T: Sealed {}
+    impl<T> Sealed for &'_ T where T: Sealed + ?Sized {}
 }
 
 /// Represents a single row from the database.
@@ -135,6 +135,44 @@ where
         Ok(Decode::decode(self.try_get_raw(index)?)?)
     }
 
+    /// Index into the database row and decode a single value, or `None` if there is no
+    /// such column.
+    ///
+    /// This is useful when the columns of the result are not known ahead of time. A column that
+    /// exists but is `NULL` is decoded like with [`try_get`](#method.try_get), so it is
+    /// `Some(None)` when `T` is an `Option`.
+    ///
+    /// ```rust,ignore
+    /// # let mut cursor = sqlx::query("SELECT id, name FROM users")
+    /// #     .fetch(&mut conn);
+    /// #
+    /// # let row = cursor.next().await?.unwrap();
+    /// #
+    /// let name: Option<Option<&str>> = row.try_get_opt("name")?; // Some(..) as "name" is selected
+    /// let email: Option<Option<&str>> = row.try_get_opt("email")?; // None
+    /// ```
+    ///
+    /// # Errors
+    ///  * [`Decode`] if the value could not be decoded into the requested type.
+    ///
+    /// [`Decode`]: crate::Error::Decode
+    #[inline]
+    fn try_get_opt<T, I>(&self, index: I) -> crate::Result<Self::Database, Option<T>>
+    where
+        T: Type<Self::Database>,
+        I: ColumnIndex<'c, Self>,
+        T: Decode<'c, Self::Database>,
+    {
+        match self.try_get(index) {
+            Ok(value) => Ok(Some(value)),
+
+            Err(crate::Error::ColumnNotFound(_))
+            | Err(crate::Error::ColumnIndexOutOfBounds { .. }) => Ok(None),
+
+            Err(error) => Err(error),
+        }
+    }
+
     #[doc(hidden)]
     fn try_get_raw<I>(
         &self,
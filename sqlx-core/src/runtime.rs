@@ -1,8 +1,20 @@
-#[cfg(not(any(feature = "runtime-tokio", feature = "runtime-async-std")))]
-compile_error!("one of 'runtime-async-std' or 'runtime-tokio' features must be enabled");
+#[cfg(not(any(
+    feature = "runtime-tokio",
+    feature = "runtime-async-std",
+    feature = "runtime-smol"
+)))]
+compile_error!(
+    "one of 'runtime-async-std', 'runtime-tokio' or 'runtime-smol' features must be enabled"
+);
 
-#[cfg(all(feature = "runtime-tokio", feature = "runtime-async-std"))]
-compile_error!("only one of 'runtime-async-std' or 'runtime-tokio' features must be enabled");
+#[cfg(any(
+    all(feature = "runtime-tokio", feature = "runtime-async-std"),
+    all(feature = "runtime-tokio", feature = "runtime-smol"),
+    all(feature = "runtime-async-std", feature = "runtime-smol")
+))]
+compile_error!(
+    "only one of 'runtime-async-std', 'runtime-tokio' or 'runtime-smol' features must be enabled"
+);
 
 #[cfg(feature = "runtime-async-std")]
 pub(crate) use async_std::{
@@ -22,3 +34,183 @@ pub(crate) use tokio::{
     time::delay_for as sleep,
     time::timeout,
 };
+
+#[cfg(feature = "runtime-smol")]
+pub(crate) use self::smol_runtime::{sleep, spawn, timeout, TcpStream};
+
+#[cfg(feature = "runtime-smol")]
+pub(crate) use futures_util::io::{AsyncRead, AsyncReadExt, AsyncWrite};
+
+// Whether a task can be spawned here; `tokio::spawn` panics outside of a runtime
+#[cfg(any(feature = "runtime-async-std", feature = "runtime-smol"))]
+pub(crate) fn can_spawn() -> bool {
+    true
+}
+
+#[cfg(feature = "runtime-tokio")]
+pub(crate) fn can_spawn() -> bool {
+    tokio::runtime::Handle::try_current().is_ok()
+}
+
+#[cfg(all(feature = "runtime-async-std", unix))]
+pub(crate) use async_std::os::unix::net::UnixStream;
+
+#[cfg(all(feature = "runtime-tokio", unix))]
+pub(crate) use tokio::net::UnixStream;
+
+#[cfg(all(feature = "runtime-smol", unix))]
+pub(crate) use self::smol_runtime::UnixStream;
+
+// smol only provides `Async<T>` for the std sockets, a timer and tasks; these wrap
+// them in the shape the rest of the crate expects from the other runtimes
+#[cfg(feature = "runtime-smol")]
+mod smol_runtime {
+    use std::fmt::{self, Display};
+    use std::future::Future;
+    use std::io;
+    use std::net::{Shutdown, ToSocketAddrs};
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+    use std::time::Duration;
+
+    use futures_util::future::{select, Either};
+    use futures_util::io::{AsyncRead, AsyncWrite};
+    use futures_util::pin_mut;
+    use smol::{Async, Task, Timer};
+
+    pub(crate) fn spawn<F>(future: F)
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        Task::spawn(future).detach();
+    }
+
+    pub(crate) async fn sleep(duration: Duration) {
+        Timer::after(duration).await;
+    }
+
+    pub(crate) async fn timeout<F: Future>(
+        duration: Duration,
+        future: F,
+    ) -> Result<F::Output, TimeoutError> {
+        let timer = Timer::after(duration);
+
+        pin_mut!(future);
+        pin_mut!(timer);
+
+        match select(future, timer).await {
+            Either::Left((output, _)) => Ok(output),
+            Either::Right(_) => Err(TimeoutError(())),
+        }
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct TimeoutError(());
+
+    impl Display for TimeoutError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("future has timed out")
+        }
+    }
+
+    impl std::error::Error for TimeoutError {}
+
+    macro_rules! async_socket {
+        ($name:ident($std:ty)) => {
+            pub(crate) struct $name(Async<$std>);
+
+            impl $name {
+                pub(crate) fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+                    self.0.get_ref().shutdown(how)
+                }
+            }
+
+            #[cfg(unix)]
+            impl std::os::unix::io::AsRawFd for $name {
+                fn as_raw_fd(&self) -> std::os::unix::io::RawFd {
+                    self.0.as_raw_fd()
+                }
+            }
+
+            impl AsyncRead for $name {
+                fn poll_read(
+                    mut self: Pin<&mut Self>,
+                    cx: &mut Context,
+                    buf: &mut [u8],
+                ) -> Poll<io::Result<usize>> {
+                    Pin::new(&mut self.0).poll_read(cx, buf)
+                }
+            }
+
+            impl AsyncWrite for $name {
+                fn poll_write(
+                    mut self: Pin<&mut Self>,
+                    cx: &mut Context,
+                    buf: &[u8],
+                ) -> Poll<io::Result<usize>> {
+                    Pin::new(&mut self.0).poll_write(cx, buf)
+                }
+
+                fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+                    Pin::new(&mut self.0).poll_flush(cx)
+                }
+
+                fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+                    Pin::new(&mut self.0).poll_close(cx)
+                }
+            }
+        };
+    }
+
+    async_socket!(TcpStream(std::net::TcpStream));
+
+    impl TcpStream {
+        pub(crate) async fn connect((host, port): (&str, u16)) -> io::Result<Self> {
+            let host = host.to_owned();
+
+            // name resolution blocks so it is moved off of the executor
+            let addrs = Task::blocking(async move { (&*host, port).to_socket_addrs() }).await?;
+
+            let mut error = None;
+
+            for addr in addrs {
+                match Async::<std::net::TcpStream>::connect(addr).await {
+                    Ok(stream) => return Ok(TcpStream(stream)),
+                    Err(e) => error = Some(e),
+                }
+            }
+
+            Err(error.unwrap_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "could not resolve to any addresses",
+                )
+            }))
+        }
+
+        pub(crate) fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+            self.0.get_ref().set_nodelay(nodelay)
+        }
+
+        pub(crate) fn nodelay(&self) -> io::Result<bool> {
+            self.0.get_ref().nodelay()
+        }
+
+        pub(crate) fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+            net2::TcpStreamExt::set_keepalive(self.0.get_ref(), keepalive)
+        }
+    }
+
+    #[cfg(unix)]
+    async_socket!(UnixStream(std::os::unix::net::UnixStream));
+
+    #[cfg(unix)]
+    impl UnixStream {
+        pub(crate) async fn connect(path: impl AsRef<std::path::Path>) -> io::Result<Self> {
+            Async::<std::os::unix::net::UnixStream>::connect(path)
+                .await
+                .map(UnixStream)
+        }
+    }
+}
@@ -59,6 +59,10 @@ impl Arguments for SqliteArguments {
         self.values.reserve(len);
     }
 
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
     fn add<T>(&mut self, value: T)
     where
         T: Encode<Self::Database> + Type<Self::Database>,
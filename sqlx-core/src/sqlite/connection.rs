@@ -1,17 +1,23 @@
 use core::ptr::{null, null_mut, NonNull};
 
-use std::collections::HashMap;
 use std::convert::TryInto;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::io;
+use std::os::raw::c_int;
 
 use futures_core::future::BoxFuture;
-use futures_util::future;
+use futures_util::{future, TryFutureExt};
 use libsqlite3_sys::{
-    sqlite3, sqlite3_close, sqlite3_extended_result_codes, sqlite3_open_v2, SQLITE_OK,
-    SQLITE_OPEN_CREATE, SQLITE_OPEN_NOMUTEX, SQLITE_OPEN_READWRITE, SQLITE_OPEN_SHAREDCACHE,
+    sqlite3, sqlite3_busy_timeout, sqlite3_changes, sqlite3_close, sqlite3_enable_load_extension,
+    sqlite3_exec, sqlite3_extended_result_codes, sqlite3_free, sqlite3_last_insert_rowid,
+    sqlite3_load_extension, sqlite3_open_v2, sqlite3_total_changes, SQLITE_AUTH, SQLITE_OK,
+    SQLITE_OPEN_CREATE, SQLITE_OPEN_MEMORY, SQLITE_OPEN_NOMUTEX, SQLITE_OPEN_PRIVATECACHE,
+    SQLITE_OPEN_READONLY, SQLITE_OPEN_READWRITE, SQLITE_OPEN_SHAREDCACHE, SQLITE_OPEN_URI,
 };
+use percent_encoding::percent_decode_str;
 
-use crate::connection::{Connect, Connection};
+use crate::cache::StatementCache;
+use crate::connection::{Connect, Connection, QueryLogger};
 use crate::executor::Executor;
 use crate::sqlite::statement::Statement;
 use crate::sqlite::worker::Worker;
@@ -24,14 +30,47 @@ use crate::url::Url;
 pub(super) struct SqliteConnectionHandle(pub(super) NonNull<sqlite3>);
 
 /// A connection to a [Sqlite](struct.Sqlite.html) database.
+///
+/// The connection URL is the path to the database file followed by optional parameters,
+/// e.g., `sqlite://data.db?mode=ro&cache=private`.
+///
+/// ### Parameters
+///
+/// | Parameter | Values | Default |
+/// |-----------|--------|---------|
+/// | `mode` | `ro` (read-only), `rw` (read-write), `rwc` (read-write, created if missing), `memory` (in-memory) | `rwc` |
+/// | `cache` | `shared`, `private` | `shared` |
+/// | `busy_timeout` | How long to wait for a locked database, in milliseconds | none; fails immediately |
+/// | `journal_mode` | `DELETE`, `TRUNCATE`, `PERSIST`, `MEMORY`, `WAL`, `OFF` | `WAL`; the mode of the database file with `mode=ro` |
+/// | `synchronous` | `OFF`, `NORMAL`, `FULL`, `EXTRA` | `NORMAL` |
+/// | `foreign_keys` | `true`, `false`; whether foreign key constraints are enforced | the SQLite default; enabled when bundled |
+/// | `load_extension` | `true`, `false`; whether [`load_extension`] may be used | `false` |
+/// | `statement_cache_capacity` | How many prepared statements are kept for reuse; the least recently used is finalized to make room and `0` disables the cache | `100` |
+///
+///
+/// ### URI Filenames
+///
+/// A path starting with `file:` is a [URI filename], e.g., `sqlite:file:data.db?mode=ro` or
+/// `sqlite:file:cache?mode=memory&cache=shared` for an in-memory database that is shared by
+/// all connections of the process that open it by the same name (`sqlite::memory:` is never
+/// shared). The whole query string is then also given to SQLite, which ignores the parameters
+/// above that it does not know of and applies its own, e.g., `vfs` or `immutable`. Both read
+/// `mode` and `cache`, which have the same meaning to either, so in a URI filename they are
+/// set by the one parameter and cannot disagree.
+///
+/// [`load_extension`]: #method.load_extension
+/// [URI filename]: https://www.sqlite.org/uri.html
 pub struct SqliteConnection {
     pub(super) handle: SqliteConnectionHandle,
     pub(super) worker: Worker,
     // Storage of the most recently prepared, non-persistent statement
     pub(super) statement: Option<Statement>,
-    // Storage of persistent statements
+    // Storage of persistent statements; the cache maps a query to its index in here
     pub(super) statements: Vec<Statement>,
-    pub(super) statement_by_query: HashMap<String, usize>,
+    pub(super) statement_cache: StatementCache<usize>,
+    load_extension: bool,
+    // How the statements that are run are logged, e.g., by [Connection::on_query]
+    pub(super) query_logger: QueryLogger,
 }
 
 // A SQLite3 handle is safe to send between threads, provided not more than
@@ -46,30 +85,47 @@ pub struct SqliteConnection {
 #[allow(unsafe_code)]
 unsafe impl Send for SqliteConnectionHandle {}
 
-async fn establish(url: Result<Url, url::ParseError>) -> crate::Result<Sqlite, SqliteConnection> {
+async fn establish(url: &Url) -> crate::Result<Sqlite, SqliteConnection> {
     let mut worker = Worker::new();
 
-    let url = url?;
-    let url = url
+    let mut flags = open_flags(url)?;
+    let busy_timeout = busy_timeout(url)?;
+    let load_extension = bool_param(url, "load_extension")?.unwrap_or(false);
+    let statement_cache_capacity = statement_cache_capacity(url)?;
+
+    let path = url
         .as_str()
         .trim_start_matches("sqlite:")
         .trim_start_matches("//");
 
+    // The parameters are not part of the path unless it is a URI for SQLite to interpret
+    // https://www.sqlite.org/uri.html
+    let path: Vec<u8> = if path.starts_with("file:") {
+        flags |= SQLITE_OPEN_URI;
+        path.into()
+    } else {
+        // The URL is percent-encoded, e.g., a space in the path is `%20`
+        percent_decode_str(path.splitn(2, '?').next().unwrap_or_default()).collect()
+    };
+
     // By default, we connect to an in-memory database.
-    // TODO: Handle the error when there are internal NULs in the database URL
-    let filename = CString::new(url).unwrap();
+    let filename = CString::new(path).map_err(|err| {
+        let position = err.nul_position();
+
+        crate::Error::Configuration(
+            format!(
+                "invalid database path {:?}: contains a NUL byte at {}",
+                String::from_utf8_lossy(&err.into_vec()),
+                position
+            )
+            .into(),
+        )
+    })?;
 
     let handle = worker
         .run(move || -> crate::Result<Sqlite, SqliteConnectionHandle> {
             let mut handle = null_mut();
 
-            // [SQLITE_OPEN_NOMUTEX] will instruct [sqlite3_open_v2] to return an error if it
-            // cannot satisfy our wish for a thread-safe, lock-free connection object
-            let flags = SQLITE_OPEN_READWRITE
-                | SQLITE_OPEN_CREATE
-                | SQLITE_OPEN_NOMUTEX
-                | SQLITE_OPEN_SHAREDCACHE;
-
             // <https://www.sqlite.org/c3ref/open.html>
             #[allow(unsafe_code)]
             let status = unsafe { sqlite3_open_v2(filename.as_ptr(), &mut handle, flags, null()) };
@@ -80,6 +136,9 @@ async fn establish(url: Result<Url, url::ParseError>) -> crate::Result<Sqlite, S
             }
 
             if status != SQLITE_OK {
+                // Read the error before the handle is freed
+                let err = SqliteError::from_connection(handle);
+
                 // Close the handle if there was an error here
                 // https://sqlite.org/c3ref/close.html
                 #[allow(unsafe_code)]
@@ -87,7 +146,7 @@ async fn establish(url: Result<Url, url::ParseError>) -> crate::Result<Sqlite, S
                     let _ = sqlite3_close(handle);
                 }
 
-                return Err(SqliteError::from_connection(handle).into());
+                return Err(err.into());
             }
 
             // Enable extended result codes
@@ -97,6 +156,15 @@ async fn establish(url: Result<Url, url::ParseError>) -> crate::Result<Sqlite, S
                 sqlite3_extended_result_codes(handle, 1);
             }
 
+            // Retry for up to the given time when the database is locked by another connection
+            // https://www.sqlite.org/c3ref/busy_timeout.html
+            if let Some(ms) = busy_timeout {
+                #[allow(unsafe_code)]
+                unsafe {
+                    sqlite3_busy_timeout(handle, ms);
+                }
+            }
+
             Ok(SqliteConnectionHandle(NonNull::new(handle).unwrap()))
         })
         .await?;
@@ -105,16 +173,371 @@ async fn establish(url: Result<Url, url::ParseError>) -> crate::Result<Sqlite, S
         worker,
         handle,
         statement: None,
-        statements: Vec::with_capacity(10),
-        statement_by_query: HashMap::with_capacity(10),
+        statements: Vec::with_capacity(statement_cache_capacity.min(10)),
+        statement_cache: StatementCache::new(statement_cache_capacity),
+        load_extension,
+        query_logger: QueryLogger::default(),
     })
 }
 
+// <https://www.sqlite.org/c3ref/open.html>
+fn open_flags(url: &Url) -> crate::Result<Sqlite, c_int> {
+    // [SQLITE_OPEN_NOMUTEX] will instruct [sqlite3_open_v2] to return an error if it
+    // cannot satisfy our wish for a thread-safe, lock-free connection object
+    let mut flags = SQLITE_OPEN_NOMUTEX;
+
+    flags |= match url.param("mode").as_deref() {
+        Some("ro") => SQLITE_OPEN_READONLY,
+        Some("rw") => SQLITE_OPEN_READWRITE,
+        Some("rwc") | None => SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE,
+        Some("memory") => SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE | SQLITE_OPEN_MEMORY,
+
+        Some(mode) => {
+            return Err(crate::Error::Configuration(
+                format!(
+                    "unknown `mode` value: {:?}; expected one of `ro`, `rw`, `rwc` or `memory`",
+                    mode
+                )
+                .into(),
+            ));
+        }
+    };
+
+    flags |= match url.param("cache").as_deref() {
+        Some("shared") | None => SQLITE_OPEN_SHAREDCACHE,
+        Some("private") => SQLITE_OPEN_PRIVATECACHE,
+
+        Some(cache) => {
+            return Err(crate::Error::Configuration(
+                format!(
+                    "unknown `cache` value: {:?}; expected `shared` or `private`",
+                    cache
+                )
+                .into(),
+            ));
+        }
+    };
+
+    Ok(flags)
+}
+
+fn busy_timeout(url: &Url) -> crate::Result<Sqlite, Option<c_int>> {
+    url.param("busy_timeout")
+        .map(|ms| {
+            ms.parse::<c_int>()
+                .ok()
+                .filter(|ms| *ms >= 0)
+                .ok_or_else(|| {
+                    crate::Error::Configuration(
+                        format!(
+                            "invalid `busy_timeout` value: {:?}; expected a number of milliseconds",
+                            ms
+                        )
+                        .into(),
+                    )
+                })
+        })
+        .transpose()
+}
+
+fn statement_cache_capacity(url: &Url) -> crate::Result<Sqlite, usize> {
+    match url.param("statement_cache_capacity") {
+        Some(capacity) => capacity.parse().map_err(|_| {
+            crate::Error::Configuration(
+                format!(
+                    "invalid `statement_cache_capacity` value: {:?}; expected a number of statements",
+                    capacity
+                )
+                .into(),
+            )
+        }),
+
+        None => Ok(100),
+    }
+}
+
+// <https://www.sqlite.org/pragma.html#pragma_journal_mode>
+// <https://www.sqlite.org/pragma.html#pragma_synchronous>
+// <https://www.sqlite.org/foreignkeys.html#fk_enable>
+fn pragmas(url: &Url) -> crate::Result<Sqlite, String> {
+    let mut pragmas = String::new();
+
+    // `journal_mode = WAL` cannot be applied to a read-only database that is not already in WAL
+    // mode, so one opened with `mode=ro` is left in the mode of its file unless asked otherwise
+    let default_journal_mode = match url.param("mode").as_deref() {
+        Some("ro") => None,
+        _ => Some("WAL"),
+    };
+
+    if let Some(journal_mode) = pragma_param(
+        url,
+        "journal_mode",
+        &["DELETE", "TRUNCATE", "PERSIST", "MEMORY", "WAL", "OFF"],
+    )?
+    .or(default_journal_mode)
+    {
+        pragmas.push_str(&format!(" PRAGMA journal_mode = {};", journal_mode));
+    }
+
+    let synchronous =
+        pragma_param(url, "synchronous", &["OFF", "NORMAL", "FULL", "EXTRA"])?.unwrap_or("NORMAL");
+
+    pragmas.push_str(&format!(" PRAGMA synchronous = {};", synchronous));
+
+    // this is a no-op inside of a transaction so it is set before anything else is executed
+    if let Some(foreign_keys) = bool_param(url, "foreign_keys")? {
+        pragmas.push_str(if foreign_keys {
+            " PRAGMA foreign_keys = ON;"
+        } else {
+            " PRAGMA foreign_keys = OFF;"
+        });
+    }
+
+    Ok(pragmas)
+}
+
+fn bool_param(url: &Url, key: &str) -> crate::Result<Sqlite, Option<bool>> {
+    match url.param(key).as_deref() {
+        Some("true") => Ok(Some(true)),
+        Some("false") => Ok(Some(false)),
+        None => Ok(None),
+
+        Some(value) => Err(crate::Error::Configuration(
+            format!(
+                "invalid `{}` value: {:?}; expected `true` or `false`",
+                key, value
+            )
+            .into(),
+        )),
+    }
+}
+
+// the value is matched case-insensitively so only a known value is ever put into the SQL
+fn pragma_param(
+    url: &Url,
+    key: &str,
+    values: &[&'static str],
+) -> crate::Result<Sqlite, Option<&'static str>> {
+    let value = match url.param(key) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    values
+        .iter()
+        .copied()
+        .find(|known| known.eq_ignore_ascii_case(&value))
+        .map(Some)
+        .ok_or_else(|| {
+            crate::Error::Configuration(
+                format!(
+                    "unknown `{}` value: {:?}; expected one of {}",
+                    key,
+                    value,
+                    values.join(", ")
+                )
+                .into(),
+            )
+        })
+}
+
 impl SqliteConnection {
     #[inline]
     pub(super) fn handle(&mut self) -> *mut sqlite3 {
         self.handle.0.as_ptr()
     }
+
+    /// The number of prepared statements that are kept for reuse.
+    ///
+    /// This is at most the `statement_cache_capacity` the connection was opened with.
+    pub fn cached_statements_size(&self) -> usize {
+        self.statement_cache.len()
+    }
+
+    /// The rowid of the row most recently inserted by a successful `INSERT` on this connection,
+    /// or `0` if none has been.
+    ///
+    /// An `INSERT` into a table with an `INTEGER PRIMARY KEY` column returns that column's value.
+    ///
+    /// <https://www.sqlite.org/c3ref/last_insert_rowid.html>
+    pub async fn last_insert_rowid(&mut self) -> i64 {
+        let handle = self.handle;
+
+        self.worker
+            .run(move || {
+                #[allow(unsafe_code)]
+                unsafe {
+                    sqlite3_last_insert_rowid(handle.0.as_ptr())
+                }
+            })
+            .await
+    }
+
+    /// The number of rows inserted, updated or deleted by the most recently completed
+    /// `INSERT`, `UPDATE` or `DELETE` statement on this connection.
+    ///
+    /// <https://www.sqlite.org/c3ref/changes.html>
+    pub async fn changes(&mut self) -> u64 {
+        let handle = self.handle;
+
+        let changes = self
+            .worker
+            .run(move || {
+                #[allow(unsafe_code)]
+                unsafe {
+                    sqlite3_changes(handle.0.as_ptr())
+                }
+            })
+            .await;
+
+        changes as u64
+    }
+
+    /// The number of rows inserted, updated or deleted by all statements since the connection
+    /// was opened.
+    ///
+    /// <https://www.sqlite.org/c3ref/total_changes.html>
+    pub async fn total_changes(&mut self) -> u64 {
+        let handle = self.handle;
+
+        let changes = self
+            .worker
+            .run(move || {
+                #[allow(unsafe_code)]
+                unsafe {
+                    sqlite3_total_changes(handle.0.as_ptr())
+                }
+            })
+            .await;
+
+        changes as u64
+    }
+
+    /// Loads the SQLite extension in the shared library at `path`.
+    ///
+    /// If `entry_point` is `None`, SQLite derives the name of the entry point from the
+    /// file name of the library.
+    ///
+    /// As an extension can run arbitrary code, this fails unless the connection was opened with
+    /// `load_extension=true`. Loading is only enabled for the duration of this call so the
+    /// `load_extension()` SQL function stays unavailable to queries.
+    ///
+    /// <https://www.sqlite.org/loadext.html>
+    pub async fn load_extension(
+        &mut self,
+        path: &str,
+        entry_point: Option<&str>,
+    ) -> crate::Result<Sqlite, ()> {
+        if !self.load_extension {
+            // reported the way SQLite refuses `load_extension()` from SQL
+            return Err(SqliteError::new(
+                SQLITE_AUTH,
+                "not authorized: extension loading is disabled; \
+                 enable it with `load_extension=true`",
+            )
+            .into());
+        }
+
+        let path = CString::new(path).map_err(io::Error::from)?;
+        let entry_point = entry_point
+            .map(CString::new)
+            .transpose()
+            .map_err(io::Error::from)?;
+
+        let handle = self.handle;
+
+        self.worker
+            .run(move || -> Result<(), SqliteError> {
+                let handle = handle.0.as_ptr();
+                let mut message = null_mut();
+
+                // <https://www.sqlite.org/c3ref/enable_load_extension.html>
+                // <https://www.sqlite.org/c3ref/load_extension.html>
+                #[allow(unsafe_code)]
+                unsafe {
+                    sqlite3_enable_load_extension(handle, 1);
+
+                    let status = sqlite3_load_extension(
+                        handle,
+                        path.as_ptr(),
+                        entry_point.as_ref().map_or(null(), |name| name.as_ptr()),
+                        &mut message,
+                    );
+
+                    sqlite3_enable_load_extension(handle, 0);
+
+                    if status == SQLITE_OK {
+                        return Ok(());
+                    }
+
+                    let err = if message.is_null() {
+                        SqliteError::from_connection(handle)
+                    } else {
+                        SqliteError::new(status, CStr::from_ptr(message).to_string_lossy())
+                    };
+
+                    sqlite3_free(message.cast());
+
+                    Err(err)
+                }
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Runs a script of one or more statements separated by semicolons and returns the total
+    /// number of rows they inserted, updated or deleted.
+    ///
+    /// The script is run by SQLite in a single call without preparing statements for reuse,
+    /// which suits migrations and schema creation. It cannot have arguments and any rows that
+    /// are returned are discarded. It stops at the first statement that fails; the statements
+    /// before it are not rolled back unless the script is in a transaction.
+    ///
+    /// ```rust,ignore
+    /// conn.execute_batch(
+    ///     "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+    ///      CREATE INDEX users_name ON users (name);
+    ///      INSERT INTO users (name) VALUES ('Alice'), ('Bob');",
+    /// )
+    /// .await?;
+    /// ```
+    ///
+    /// <https://www.sqlite.org/c3ref/exec.html>
+    pub async fn execute_batch(&mut self, sql: &str) -> crate::Result<Sqlite, u64> {
+        let sql = CString::new(sql).map_err(io::Error::from)?;
+
+        let handle = self.handle;
+
+        self.worker
+            .run(move || -> Result<u64, SqliteError> {
+                let handle = handle.0.as_ptr();
+                let mut message = null_mut();
+
+                // <https://www.sqlite.org/c3ref/total_changes.html>
+                #[allow(unsafe_code)]
+                unsafe {
+                    let before = sqlite3_total_changes(handle);
+
+                    let status = sqlite3_exec(handle, sql.as_ptr(), None, null_mut(), &mut message);
+
+                    if status == SQLITE_OK {
+                        return Ok((sqlite3_total_changes(handle) - before) as u64);
+                    }
+
+                    let err = if message.is_null() {
+                        SqliteError::from_connection(handle)
+                    } else {
+                        SqliteError::new(status, CStr::from_ptr(message).to_string_lossy())
+                    };
+
+                    sqlite3_free(message.cast());
+
+                    Err(err)
+                }
+            })
+            .await
+            .map_err(Into::into)
+    }
 }
 
 impl Connect for SqliteConnection {
@@ -126,18 +549,13 @@ impl Connect for SqliteConnection {
         let url = url.try_into();
 
         Box::pin(async move {
-            let mut conn = establish(url).await?;
+            let url = url?;
+            let pragmas = pragmas(&url)?;
 
-            // https://www.sqlite.org/wal.html
+            let mut conn = establish(&url).await?;
 
-            // language=SQLite
-            conn.execute(
-                r#"
-PRAGMA journal_mode = WAL;
-PRAGMA synchronous = NORMAL;
-                "#,
-            )
-            .await?;
+            // https://www.sqlite.org/wal.html
+            conn.execute(&*pragmas).await?;
 
             Ok(conn)
         })
@@ -151,14 +569,19 @@ impl Connection for SqliteConnection {
     }
 
     fn ping(&mut self) -> BoxFuture<crate::Result<Sqlite, ()>> {
-        // For SQLite connections, PING does effectively nothing
-        Box::pin(future::ok(()))
+        Box::pin(Executor::execute(self, "SELECT 1").map_ok(|_| ()))
+    }
+
+    #[doc(hidden)]
+    fn query_logger(&mut self) -> &mut QueryLogger {
+        &mut self.query_logger
     }
 }
 
 impl Drop for SqliteConnection {
     fn drop(&mut self) {
-        // Drop all statements first
+        // Drop all statements first; the connection is not closed while any are left
+        self.statement = None;
         self.statements.clear();
 
         // Next close the statement
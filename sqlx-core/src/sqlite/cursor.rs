@@ -1,6 +1,7 @@
 use futures_core::future::BoxFuture;
 
-use crate::connection::ConnectionSource;
+use crate::arguments::Arguments;
+use crate::connection::{ConnectionSource, QueryTimer};
 use crate::cursor::Cursor;
 use crate::executor::Execute;
 use crate::pool::Pool;
@@ -12,6 +13,11 @@ pub struct SqliteCursor<'c, 'q> {
     query: &'q str,
     arguments: Option<SqliteArguments>,
     pub(super) statement: Option<Option<usize>>,
+
+    // Times the query for the hook of the connection, from the first call to [next] until the
+    // last row has been fetched
+    started: bool,
+    timer: Option<QueryTimer>,
 }
 
 impl crate::cursor::private::Sealed for SqliteCursor<'_, '_> {}
@@ -32,6 +38,8 @@ impl<'c, 'q> Cursor<'c, 'q> for SqliteCursor<'c, 'q> {
             statement: None,
             query,
             arguments,
+            started: false,
+            timer: None,
         }
     }
 
@@ -48,6 +56,8 @@ impl<'c, 'q> Cursor<'c, 'q> for SqliteCursor<'c, 'q> {
             statement: None,
             query,
             arguments,
+            started: false,
+            timer: None,
         }
     }
 
@@ -61,6 +71,13 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
 ) -> crate::Result<Sqlite, Option<SqliteRow<'a>>> {
     let conn = cursor.source.resolve().await?;
 
+    if !cursor.started {
+        let num_arguments = cursor.arguments.as_ref().map_or(0, Arguments::len);
+
+        cursor.started = true;
+        cursor.timer = QueryTimer::start(&conn.query_logger, cursor.query, num_arguments);
+    }
+
     loop {
         if cursor.statement.is_none() {
             let key = conn.prepare(&mut cursor.query, cursor.arguments.is_some())?;
@@ -87,6 +104,8 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
             }
 
             Step::Done if cursor.query.is_empty() => {
+                cursor.timer = None;
+
                 return Ok(None);
             }
 
@@ -1,5 +1,6 @@
 use crate::database::{Database, HasCursor, HasRawValue, HasRow};
 use crate::sqlite::error::SqliteError;
+use crate::transaction::{IsolationLevel, Options as TransactionOptions};
 
 /// **Sqlite** database driver.
 #[derive(Debug)]
@@ -17,6 +18,35 @@ impl Database for Sqlite {
     type RawBuffer = Vec<super::SqliteArgumentValue>;
 
     type Error = SqliteError;
+
+    // <https://www.sqlite.org/isolation.html>
+    // <https://www.sqlite.org/pragma.html#pragma_query_only>
+    fn begin_transaction(
+        options: &TransactionOptions,
+    ) -> crate::Result<Self, (String, Option<&'static str>)> {
+        match options.isolation_level {
+            Some(IsolationLevel::Serializable) | None => {}
+
+            Some(level) => {
+                return Err(crate::Error::Configuration(
+                    format!(
+                        "unsupported isolation level: {:?}; SQLite transactions are serializable",
+                        level
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        if options.read_only {
+            Ok((
+                "BEGIN; PRAGMA query_only = ON;".into(),
+                Some("PRAGMA query_only = OFF"),
+            ))
+        } else {
+            Ok(("BEGIN".into(), None))
+        }
+    }
 }
 
 impl<'c> HasRow<'c> for Sqlite {
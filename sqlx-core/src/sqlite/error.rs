@@ -1,7 +1,9 @@
 use crate::error::DatabaseError;
 use crate::sqlite::Sqlite;
 use bitflags::_core::str::from_utf8_unchecked;
-use libsqlite3_sys::{sqlite3, sqlite3_errmsg, sqlite3_extended_errcode};
+use libsqlite3_sys::{
+    sqlite3, sqlite3_errmsg, sqlite3_extended_errcode, SQLITE_BUSY, SQLITE_LOCKED,
+};
 use std::error::Error as StdError;
 use std::ffi::CStr;
 use std::fmt::{self, Display};
@@ -17,6 +19,13 @@ pub struct SqliteError {
 // https://www.sqlite.org/c3ref/errcode.html
 
 impl SqliteError {
+    pub(super) fn new(code: c_int, message: impl Into<String>) -> Self {
+        Self {
+            code: code.to_string(),
+            message: message.into(),
+        }
+    }
+
     pub(super) fn from_connection(conn: *mut sqlite3) -> Self {
         #[allow(unsafe_code)]
         let code: c_int = unsafe { sqlite3_extended_errcode(conn) };
@@ -50,6 +59,14 @@ impl DatabaseError for SqliteError {
     fn code(&self) -> Option<&str> {
         Some(&self.code)
     }
+
+    // `SQLITE_BUSY` or `SQLITE_LOCKED`, including their extended codes
+    fn is_retryable(&self) -> bool {
+        match self.code.parse::<c_int>() {
+            Ok(code) => code & 0xFF == SQLITE_BUSY || code & 0xFF == SQLITE_LOCKED,
+            Err(_) => false,
+        }
+    }
 }
 
 impl StdError for SqliteError {}
@@ -59,3 +76,16 @@ impl From<SqliteError> for crate::Error<Sqlite> {
         crate::Error::Database(Box::new(err))
     }
 }
+
+#[test]
+fn test_retryable_error() {
+    use libsqlite3_sys::{
+        SQLITE_BUSY_SNAPSHOT, SQLITE_CONSTRAINT_UNIQUE, SQLITE_LOCKED_SHAREDCACHE,
+    };
+
+    assert!(SqliteError::new(SQLITE_BUSY, "database is locked").is_retryable());
+    assert!(SqliteError::new(SQLITE_BUSY_SNAPSHOT, "database is locked").is_retryable());
+    assert!(SqliteError::new(SQLITE_LOCKED_SHAREDCACHE, "database table is locked").is_retryable());
+
+    assert!(!SqliteError::new(SQLITE_CONSTRAINT_UNIQUE, "UNIQUE constraint failed").is_retryable());
+}
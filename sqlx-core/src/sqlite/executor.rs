@@ -1,13 +1,12 @@
 use futures_core::future::BoxFuture;
 
-use libsqlite3_sys::sqlite3_changes;
-
+use crate::arguments::Arguments;
+use crate::connection::QueryTimer;
 use crate::cursor::Cursor;
 use crate::describe::{Column, Describe};
 use crate::executor::{Execute, Executor, RefExecutor};
 use crate::sqlite::cursor::SqliteCursor;
 use crate::sqlite::statement::{Statement, Step};
-use crate::sqlite::types::SqliteType;
 use crate::sqlite::{Sqlite, SqliteConnection, SqliteTypeInfo};
 
 impl SqliteConnection {
@@ -16,18 +15,15 @@ impl SqliteConnection {
         query: &mut &str,
         persistent: bool,
     ) -> crate::Result<Sqlite, Option<usize>> {
-        // TODO: Revisit statement caching and allow cache expiration by using a
-        //       generational index
-
-        if !persistent {
+        if !persistent || self.statement_cache.capacity() == 0 {
             // A non-persistent query will be immediately prepared and returned,
             // regardless of the current state of the cache
             self.statement = Some(Statement::new(self, query, false)?);
             return Ok(None);
         }
 
-        if let Some(key) = self.statement_by_query.get(&**query) {
-            let statement = &mut self.statements[*key];
+        if let Some(&mut key) = self.statement_cache.get_mut(query) {
+            let statement = &mut self.statements[key];
 
             // Adjust the passed in query string as if [string3_prepare]
             // did the tail parsing
@@ -37,7 +33,7 @@ impl SqliteConnection {
             // it to clear the bindings and its program state
             statement.reset();
 
-            return Ok(Some(*key));
+            return Ok(Some(key));
         }
 
         // Prepare a new statement object; ensuring to tell SQLite that this will be stored
@@ -46,25 +42,22 @@ impl SqliteConnection {
         let query_key = query.to_owned();
         let statement = Statement::new(self, query, true)?;
 
-        let key = self.statements.len();
+        let key = if self.statement_cache.len() < self.statement_cache.capacity() {
+            self.statements.push(statement);
+            self.statements.len() - 1
+        } else {
+            // The cache is full; the least recently used statement is replaced,
+            // which finalizes it
+            let key = self.statement_cache.remove_lru().unwrap();
+            self.statements[key] = statement;
+            key
+        };
 
-        self.statement_by_query.insert(query_key, key);
-        self.statements.push(statement);
+        self.statement_cache.insert(&query_key, key);
 
         Ok(Some(key))
     }
 
-    // This is used for [affected_rows] in the public API.
-    fn changes(&mut self) -> u64 {
-        // Returns the number of rows modified, inserted or deleted by the most recently
-        // completed INSERT, UPDATE or DELETE statement.
-
-        // https://www.sqlite.org/c3ref/changes.html
-        #[allow(unsafe_code)]
-        let changes = unsafe { sqlite3_changes(self.handle()) };
-        changes as u64
-    }
-
     #[inline]
     pub(super) fn statement(&self, key: Option<usize>) -> &Statement {
         match key {
@@ -95,6 +88,9 @@ impl Executor for SqliteConnection {
         let (mut query, mut arguments) = query.into_parts();
 
         Box::pin(async move {
+            let num_arguments = arguments.as_ref().map_or(0, Arguments::len);
+            let _timer = QueryTimer::start(&self.query_logger, query, num_arguments);
+
             loop {
                 let key = self.prepare(&mut query, arguments.is_some())?;
                 let statement = self.statement_mut(key);
@@ -112,7 +108,7 @@ impl Executor for SqliteConnection {
                 }
             }
 
-            Ok(self.changes())
+            Ok(self.changes().await)
         })
     }
 
@@ -148,27 +144,11 @@ impl Executor for SqliteConnection {
                 let name = statement.column_name(i).to_owned();
                 let decl = statement.column_decltype(i);
 
-                let r#type = match decl {
-                    None => None,
-                    Some(decl) => match &*decl.to_ascii_lowercase() {
-                        "bool" | "boolean" => Some(SqliteType::Boolean),
-                        "clob" | "text" => Some(SqliteType::Text),
-                        "blob" => Some(SqliteType::Blob),
-                        "real" | "double" | "double precision" | "float" => Some(SqliteType::Float),
-                        decl @ _ if decl.contains("int") => Some(SqliteType::Integer),
-                        decl @ _ if decl.contains("char") => Some(SqliteType::Text),
-                        _ => None,
-                    },
-                };
-
                 columns.push(Column {
                     name: Some(name.into()),
                     non_null: None,
                     table_id: None,
-                    type_info: r#type.map(|r#type| SqliteTypeInfo {
-                        r#type,
-                        affinity: None,
-                    }),
+                    type_info: decl.and_then(SqliteTypeInfo::from_decltype),
                 })
             }
 
@@ -17,7 +17,7 @@ pub use connection::SqliteConnection;
 pub use cursor::SqliteCursor;
 pub use database::Sqlite;
 pub use error::SqliteError;
-pub use row::SqliteRow;
+pub use row::{SqliteColumn, SqliteRow};
 pub use types::SqliteTypeInfo;
 pub use value::SqliteValue;
 
@@ -1,7 +1,41 @@
 use crate::row::{ColumnIndex, Row};
 use crate::sqlite::statement::Statement;
 use crate::sqlite::value::SqliteValue;
-use crate::sqlite::{Sqlite, SqliteConnection};
+use crate::sqlite::{Sqlite, SqliteConnection, SqliteTypeInfo};
+
+/// The name, position and declared type of a column of a [`SqliteRow`].
+#[derive(Debug, Clone)]
+pub struct SqliteColumn {
+    pub(super) name: String,
+    pub(super) ordinal: usize,
+    pub(super) decl_type: Option<String>,
+    pub(super) type_info: Option<SqliteTypeInfo>,
+}
+
+impl SqliteColumn {
+    /// The name of the column, e.g., as given with `AS`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The position of the column in the row, starting at `0`.
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// The type that the column was declared with in `CREATE TABLE`, e.g., `VARCHAR(255)`;
+    /// `None` if the column is an expression.
+    pub fn decl_type(&self) -> Option<&str> {
+        self.decl_type.as_deref()
+    }
+
+    /// The type of the column from its declared type, if it is one that is known.
+    ///
+    /// SQLite is dynamically typed so the values of a column may still have other types.
+    pub fn type_info(&self) -> Option<&SqliteTypeInfo> {
+        self.type_info.as_ref()
+    }
+}
 
 pub struct SqliteRow<'c> {
     pub(super) values: usize,
@@ -26,6 +60,14 @@ impl<'c> SqliteRow<'c> {
     fn statement(&self) -> &'c Statement {
         self.connection.statement(self.statement)
     }
+
+    /// The columns of the row, in order.
+    ///
+    /// The columns are the same for every row of a result and are known without decoding
+    /// any values.
+    pub fn columns(&self) -> &'c [SqliteColumn] {
+        &self.statement().fields
+    }
 }
 
 impl<'c> Row<'c> for SqliteRow<'c> {
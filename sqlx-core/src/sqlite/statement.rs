@@ -15,7 +15,7 @@ use crate::sqlite::connection::SqliteConnectionHandle;
 use crate::sqlite::worker::Worker;
 use crate::sqlite::Sqlite;
 use crate::sqlite::SqliteError;
-use crate::sqlite::{SqliteArguments, SqliteConnection};
+use crate::sqlite::{SqliteArguments, SqliteColumn, SqliteConnection, SqliteTypeInfo};
 
 /// Return values from [SqliteStatement::step].
 pub(super) enum Step {
@@ -40,6 +40,7 @@ pub(super) struct Statement {
     pub(super) worker: Worker,
     pub(super) tail: usize,
     pub(super) columns: HashMap<String, usize>,
+    pub(super) fields: Vec<SqliteColumn>,
 }
 
 // SQLite3 statement objects are safe to send between threads, but *not* safe
@@ -97,16 +98,26 @@ impl Statement {
             connection: conn.handle,
             handle: SqliteStatementHandle(NonNull::new(statement_handle).unwrap()),
             columns: HashMap::new(),
+            fields: Vec::new(),
             tail,
         };
 
         // Prepare a column hash map for use in pulling values from a column by name
         let count = self_.column_count();
         self_.columns.reserve(count);
+        self_.fields.reserve(count);
 
         for i in 0..count {
             let name = self_.column_name(i).to_owned();
-            self_.columns.insert(name, i);
+            let decl_type = self_.column_decltype(i).map(ToOwned::to_owned);
+
+            self_.columns.insert(name.clone(), i);
+            self_.fields.push(SqliteColumn {
+                type_info: decl_type.as_deref().and_then(SqliteTypeInfo::from_decltype),
+                name,
+                ordinal: i,
+                decl_type,
+            });
         }
 
         Ok(self_)
@@ -0,0 +1,66 @@
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
+use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
+use crate::types::{AnyValue, Type};
+
+// A column with BLOB affinity (formerly called NONE) holds values of any type
+impl Type<Sqlite> for AnyValue {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo::new(SqliteType::Blob, SqliteTypeAffinity::Blob)
+    }
+}
+
+impl<'de> Decode<'de, Sqlite> for AnyValue {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<Sqlite, Self> {
+        let type_info = match value.type_info() {
+            Some(type_info) => type_info,
+            None => return Ok(AnyValue::Null),
+        };
+
+        Ok(match type_info.r#type {
+            SqliteType::Integer | SqliteType::Boolean => AnyValue::Int(value.int64()),
+            SqliteType::Float => AnyValue::Float(value.double()),
+            SqliteType::Text => AnyValue::Text(Decode::<Sqlite>::decode(value)?),
+            SqliteType::Blob => AnyValue::Bytes(Decode::<Sqlite>::decode(value)?),
+        })
+    }
+}
+
+// SQLite has no boolean, JSON or UUID values; these are sent as an integer, text and
+// 16 bytes respectively
+impl Encode<Sqlite> for AnyValue {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(match self {
+            AnyValue::Null => SqliteArgumentValue::Null,
+            AnyValue::Bool(value) => SqliteArgumentValue::Int((*value).into()),
+            AnyValue::Int(value) => SqliteArgumentValue::Int64(*value),
+            AnyValue::Float(value) => SqliteArgumentValue::Double(*value),
+            AnyValue::Text(value) => SqliteArgumentValue::Text(value.clone()),
+            AnyValue::Bytes(value) => SqliteArgumentValue::Blob(value.clone()),
+
+            #[cfg(feature = "json")]
+            AnyValue::Json(value) => SqliteArgumentValue::Text(value.to_string()),
+
+            #[cfg(feature = "uuid")]
+            AnyValue::Uuid(value) => SqliteArgumentValue::Blob(value.as_bytes().to_vec()),
+
+            AnyValue::Other { bytes, .. } => SqliteArgumentValue::Blob(bytes.clone()),
+        });
+    }
+}
+
+#[cfg(feature = "any")]
+impl From<&'_ SqliteTypeInfo> for crate::any::AnyTypeInfo {
+    fn from(type_info: &SqliteTypeInfo) -> Self {
+        use crate::any::AnyTypeKind;
+
+        crate::any::AnyTypeInfo(match type_info.r#type {
+            SqliteType::Boolean => AnyTypeKind::Bool,
+            SqliteType::Integer => AnyTypeKind::Int,
+            SqliteType::Float => AnyTypeKind::Float,
+            SqliteType::Text => AnyTypeKind::Text,
+            SqliteType::Blob => AnyTypeKind::Bytes,
+        })
+    }
+}
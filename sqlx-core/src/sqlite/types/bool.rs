@@ -1,5 +1,6 @@
 use crate::decode::Decode;
 use crate::encode::Encode;
+use crate::error::UnexpectedNullError;
 use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
 use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
 use crate::types::Type;
@@ -18,6 +19,10 @@ impl Encode<Sqlite> for bool {
 
 impl<'a> Decode<'a, Sqlite> for bool {
     fn decode(value: SqliteValue<'a>) -> crate::Result<Sqlite, bool> {
+        if value.is_null() {
+            return Err(crate::Error::decode(UnexpectedNullError));
+        }
+
         Ok(value.int() != 0)
     }
 }
@@ -1,5 +1,6 @@
 use crate::decode::Decode;
 use crate::encode::Encode;
+use crate::error::UnexpectedNullError;
 use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
 use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
 use crate::types::Type;
@@ -31,6 +32,11 @@ impl Encode<Sqlite> for Vec<u8> {
 
 impl<'de> Decode<'de, Sqlite> for &'de [u8] {
     fn decode(value: SqliteValue<'de>) -> crate::Result<Sqlite, &'de [u8]> {
+        // an empty BLOB and NULL are both returned as an empty slice
+        if value.is_null() {
+            return Err(crate::Error::decode(UnexpectedNullError));
+        }
+
         Ok(value.blob())
     }
 }
@@ -40,3 +46,24 @@ impl<'de> Decode<'de, Sqlite> for Vec<u8> {
         <&[u8] as Decode<Sqlite>>::decode(value).map(ToOwned::to_owned)
     }
 }
+
+#[cfg(feature = "bytes")]
+impl Type<Sqlite> for bytes::Bytes {
+    fn type_info() -> SqliteTypeInfo {
+        <[u8] as Type<Sqlite>>::type_info()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Encode<Sqlite> for bytes::Bytes {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        <[u8] as Encode<Sqlite>>::encode(self, values)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'de> Decode<'de, Sqlite> for bytes::Bytes {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<Sqlite, bytes::Bytes> {
+        <&[u8] as Decode<Sqlite>>::decode(value).map(bytes::Bytes::copy_from_slice)
+    }
+}
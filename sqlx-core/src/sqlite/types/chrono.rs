@@ -0,0 +1,142 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
+use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
+use crate::types::Type;
+use crate::Error;
+
+// the format of the SQLite date and time functions, e.g., `datetime('now')`
+const FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
+
+impl Type<Sqlite> for NaiveDateTime {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo::new(SqliteType::Text, SqliteTypeAffinity::Text)
+    }
+}
+
+impl Encode<Sqlite> for NaiveDateTime {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(SqliteArgumentValue::Text(self.format(FORMAT).to_string()));
+    }
+}
+
+/// Accepts a space or `T` between the date and time, seconds are optional and a trailing `Z` or
+/// UTC offset (e.g., `+02:00`) is converted to UTC.
+impl<'de> Decode<'de, Sqlite> for NaiveDateTime {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<Sqlite, Self> {
+        let s = <&str as Decode<Sqlite>>::decode(value)?;
+
+        parse_datetime(s)
+            .ok_or_else(|| Error::Decode(format!("invalid DATETIME value: {:?}", s).into()))
+    }
+}
+
+impl Type<Sqlite> for DateTime<Utc> {
+    fn type_info() -> SqliteTypeInfo {
+        <NaiveDateTime as Type<Sqlite>>::type_info()
+    }
+}
+
+/// Encodes the time in UTC without an offset, as the SQLite date and time functions do.
+impl Encode<Sqlite> for DateTime<Utc> {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        Encode::<Sqlite>::encode(&self.naive_utc(), values);
+    }
+}
+
+/// A time without an offset is assumed to be in UTC.
+impl<'de> Decode<'de, Sqlite> for DateTime<Utc> {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<Sqlite, Self> {
+        let naive: NaiveDateTime = Decode::<Sqlite>::decode(value)?;
+
+        Ok(DateTime::from_utc(naive, Utc))
+    }
+}
+
+impl Type<Sqlite> for NaiveDate {
+    fn type_info() -> SqliteTypeInfo {
+        <NaiveDateTime as Type<Sqlite>>::type_info()
+    }
+}
+
+impl Encode<Sqlite> for NaiveDate {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(SqliteArgumentValue::Text(
+            self.format("%Y-%m-%d").to_string(),
+        ));
+    }
+}
+
+/// Also accepts a date and time, of which only the date is kept.
+impl<'de> Decode<'de, Sqlite> for NaiveDate {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<Sqlite, Self> {
+        let s = <&str as Decode<Sqlite>>::decode(value)?;
+
+        NaiveDate::parse_from_str(s, "%Y-%m-%d")
+            .ok()
+            .or_else(|| parse_datetime(s).map(|datetime| datetime.date()))
+            .ok_or_else(|| Error::Decode(format!("invalid DATE value: {:?}", s).into()))
+    }
+}
+
+// https://www.sqlite.org/lang_datefunc.html#time_values
+fn parse_datetime(s: &str) -> Option<NaiveDateTime> {
+    const FORMATS: &[&str] = &[
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+    ];
+
+    let utc = if s.ends_with('Z') {
+        &s[..(s.len() - 1)]
+    } else {
+        s
+    };
+
+    for format in FORMATS {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(utc, format) {
+            return Some(datetime);
+        }
+
+        if let Ok(datetime) = DateTime::<FixedOffset>::parse_from_str(s, &format!("{}%:z", format))
+        {
+            return Some(datetime.naive_utc());
+        }
+    }
+
+    None
+}
+
+#[test]
+fn test_parse_datetime() {
+    let expected = NaiveDate::from_ymd(2019, 1, 2).and_hms_micro(5, 10, 20, 115_100);
+
+    assert_eq!(parse_datetime("2019-01-02 05:10:20.1151"), Some(expected));
+    assert_eq!(parse_datetime("2019-01-02T05:10:20.1151"), Some(expected));
+    assert_eq!(parse_datetime("2019-01-02T05:10:20.1151Z"), Some(expected));
+    assert_eq!(
+        parse_datetime("2019-01-02 07:10:20.1151+02:00"),
+        Some(expected)
+    );
+    assert_eq!(
+        parse_datetime("2019-01-02T00:10:20.1151-05:00"),
+        Some(expected)
+    );
+
+    assert_eq!(
+        parse_datetime("2019-01-02 05:10"),
+        Some(NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 0))
+    );
+
+    assert_eq!(
+        parse_datetime("2019-01-02 05:10:20"),
+        Some(NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20))
+    );
+
+    assert_eq!(parse_datetime("2019-01-02"), None);
+    assert_eq!(parse_datetime("05:10:20"), None);
+    assert_eq!(parse_datetime("2019-01-02 05:10:20 UTC"), None);
+}
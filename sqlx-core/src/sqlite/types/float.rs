@@ -1,5 +1,6 @@
 use crate::decode::Decode;
 use crate::encode::Encode;
+use crate::error::UnexpectedNullError;
 use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
 use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
 use crate::types::Type;
@@ -18,6 +19,10 @@ impl Encode<Sqlite> for f32 {
 
 impl<'a> Decode<'a, Sqlite> for f32 {
     fn decode(value: SqliteValue<'a>) -> crate::Result<Sqlite, f32> {
+        if value.is_null() {
+            return Err(crate::Error::decode(UnexpectedNullError));
+        }
+
         Ok(value.double() as f32)
     }
 }
@@ -36,6 +41,10 @@ impl Encode<Sqlite> for f64 {
 
 impl<'a> Decode<'a, Sqlite> for f64 {
     fn decode(value: SqliteValue<'a>) -> crate::Result<Sqlite, f64> {
+        if value.is_null() {
+            return Err(crate::Error::decode(UnexpectedNullError));
+        }
+
         Ok(value.double())
     }
 }
@@ -1,8 +1,61 @@
+use std::convert::TryFrom;
+use std::num::{NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU8};
+
 use crate::decode::Decode;
 use crate::encode::Encode;
+use crate::error::UnexpectedNullError;
 use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
 use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
 use crate::types::Type;
+use crate::Error;
+
+// SQLite stores every INTEGER as 64 bits so narrower types are range-checked rather than truncated
+fn decode_int<T: TryFrom<i64>>(value: SqliteValue<'_>, name: &str) -> crate::Result<Sqlite, T> {
+    if value.is_null() {
+        return Err(Error::decode(UnexpectedNullError));
+    }
+
+    let int = value.int64();
+
+    T::try_from(int)
+        .map_err(|_| Error::Decode(format!("INTEGER {} is out of range for {}", int, name).into()))
+}
+
+impl Type<Sqlite> for i8 {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo::new(SqliteType::Integer, SqliteTypeAffinity::Integer)
+    }
+}
+
+impl Encode<Sqlite> for i8 {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(SqliteArgumentValue::Int((*self).into()));
+    }
+}
+
+impl<'a> Decode<'a, Sqlite> for i8 {
+    fn decode(value: SqliteValue<'a>) -> crate::Result<Sqlite, i8> {
+        decode_int(value, "i8")
+    }
+}
+
+impl Type<Sqlite> for i16 {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo::new(SqliteType::Integer, SqliteTypeAffinity::Integer)
+    }
+}
+
+impl Encode<Sqlite> for i16 {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(SqliteArgumentValue::Int((*self).into()));
+    }
+}
+
+impl<'a> Decode<'a, Sqlite> for i16 {
+    fn decode(value: SqliteValue<'a>) -> crate::Result<Sqlite, i16> {
+        decode_int(value, "i16")
+    }
+}
 
 impl Type<Sqlite> for i32 {
     fn type_info() -> SqliteTypeInfo {
@@ -18,7 +71,7 @@ impl Encode<Sqlite> for i32 {
 
 impl<'a> Decode<'a, Sqlite> for i32 {
     fn decode(value: SqliteValue<'a>) -> crate::Result<Sqlite, i32> {
-        Ok(value.int())
+        decode_int(value, "i32")
     }
 }
 
@@ -36,6 +89,100 @@ impl Encode<Sqlite> for i64 {
 
 impl<'a> Decode<'a, Sqlite> for i64 {
     fn decode(value: SqliteValue<'a>) -> crate::Result<Sqlite, i64> {
-        Ok(value.int64())
+        decode_int(value, "i64")
     }
 }
+
+impl Type<Sqlite> for u8 {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo::new(SqliteType::Integer, SqliteTypeAffinity::Integer)
+    }
+}
+
+impl Encode<Sqlite> for u8 {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(SqliteArgumentValue::Int((*self).into()));
+    }
+}
+
+impl<'a> Decode<'a, Sqlite> for u8 {
+    fn decode(value: SqliteValue<'a>) -> crate::Result<Sqlite, u8> {
+        decode_int(value, "u8")
+    }
+}
+
+impl Type<Sqlite> for u16 {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo::new(SqliteType::Integer, SqliteTypeAffinity::Integer)
+    }
+}
+
+impl Encode<Sqlite> for u16 {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(SqliteArgumentValue::Int((*self).into()));
+    }
+}
+
+impl<'a> Decode<'a, Sqlite> for u16 {
+    fn decode(value: SqliteValue<'a>) -> crate::Result<Sqlite, u16> {
+        decode_int(value, "u16")
+    }
+}
+
+impl Type<Sqlite> for u32 {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo::new(SqliteType::Integer, SqliteTypeAffinity::Integer)
+    }
+}
+
+impl Encode<Sqlite> for u32 {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(SqliteArgumentValue::Int64((*self).into()));
+    }
+}
+
+impl<'a> Decode<'a, Sqlite> for u32 {
+    fn decode(value: SqliteValue<'a>) -> crate::Result<Sqlite, u32> {
+        decode_int(value, "u32")
+    }
+}
+
+// The `NonZero` integers are stored like the integer they wrap; decoding a zero is an error
+
+macro_rules! impl_non_zero {
+    ($($ty:ident($int:ty)),*) => {
+        $(
+            impl Type<Sqlite> for $ty {
+                fn type_info() -> SqliteTypeInfo {
+                    <$int as Type<Sqlite>>::type_info()
+                }
+            }
+
+            impl Encode<Sqlite> for $ty {
+                fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+                    <$int as Encode<Sqlite>>::encode(&self.get(), values)
+                }
+            }
+
+            impl<'a> Decode<'a, Sqlite> for $ty {
+                fn decode(value: SqliteValue<'a>) -> crate::Result<Sqlite, $ty> {
+                    $ty::new(<$int as Decode<Sqlite>>::decode(value)?).ok_or_else(|| {
+                        Error::Decode(
+                            concat!("INTEGER 0 is out of range for ", stringify!($ty)).into(),
+                        )
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero!(
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroU8(u8),
+    NonZeroU16(u16),
+    NonZeroU32(u32)
+);
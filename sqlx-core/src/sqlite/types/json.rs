@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
+use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
+use crate::types::{Json, Type};
+use crate::Error;
+
+// <https://www.sqlite.org/json1.html>
+
+// SQLite has no JSON type; JSON is stored as TEXT and is understood by the `json()`
+// and `json_extract()` family of functions
+
+impl<T> Type<Sqlite> for Json<T> {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo::new(SqliteType::Text, SqliteTypeAffinity::Text)
+    }
+}
+
+impl<T> Encode<Sqlite> for Json<T>
+where
+    T: Serialize,
+{
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        let json = serde_json::to_string(&self.0)
+            .expect("failed to serialize json for encoding to database");
+
+        values.push(SqliteArgumentValue::Text(json));
+    }
+}
+
+impl<'de, T> Decode<'de, Sqlite> for Json<T>
+where
+    T: 'de,
+    T: Deserialize<'de>,
+{
+    fn decode(value: SqliteValue<'de>) -> crate::Result<Sqlite, Self> {
+        let s = <&str as Decode<Sqlite>>::decode(value)?;
+
+        serde_json::from_str(s).map(Json).map_err(Error::decode)
+    }
+}
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fmt::{self, Display};
 
 use crate::decode::Decode;
@@ -5,12 +6,19 @@ use crate::sqlite::value::SqliteValue;
 use crate::sqlite::Sqlite;
 use crate::types::TypeInfo;
 
+mod any;
 mod bool;
 mod bytes;
 mod float;
 mod int;
 mod str;
 
+#[cfg(feature = "chrono")]
+mod chrono;
+
+#[cfg(feature = "json")]
+mod json;
+
 // https://www.sqlite.org/c3ref/c_blob.html
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub(crate) enum SqliteType {
@@ -40,23 +48,46 @@ pub struct SqliteTypeInfo {
 }
 
 impl SqliteTypeInfo {
-    fn new(r#type: SqliteType, affinity: SqliteTypeAffinity) -> Self {
+    pub(crate) fn new(r#type: SqliteType, affinity: SqliteTypeAffinity) -> Self {
         Self {
             r#type,
             affinity: Some(affinity),
         }
     }
+
+    // The type of a column from its declared type in `CREATE TABLE`, if it is one we know
+    //
+    // This follows the rules SQLite uses to pick the affinity of a column so that e.g.
+    // `VARCHAR(255)`, `UNSIGNED BIG INT` and `DOUBLE PRECISION` are understood; a column
+    // with NUMERIC affinity (e.g. `DECIMAL(10, 5)` or `DATETIME`) may hold any kind of value
+    // and is left unknown.
+    // https://www.sqlite.org/datatype3.html#determination_of_column_affinity
+    pub(crate) fn from_decltype(decl: &str) -> Option<Self> {
+        let decl = decl.to_ascii_lowercase();
+
+        if decl == "bool" || decl == "boolean" {
+            return Some(Self::new(SqliteType::Boolean, SqliteTypeAffinity::Numeric));
+        }
+
+        let (r#type, affinity) = if decl.contains("int") {
+            (SqliteType::Integer, SqliteTypeAffinity::Integer)
+        } else if decl.contains("char") || decl.contains("clob") || decl.contains("text") {
+            (SqliteType::Text, SqliteTypeAffinity::Text)
+        } else if decl.contains("blob") {
+            (SqliteType::Blob, SqliteTypeAffinity::Blob)
+        } else if decl.contains("real") || decl.contains("floa") || decl.contains("doub") {
+            (SqliteType::Float, SqliteTypeAffinity::Real)
+        } else {
+            return None;
+        };
+
+        Some(Self::new(r#type, affinity))
+    }
 }
 
 impl Display for SqliteTypeInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self.r#type {
-            SqliteType::Text => "TEXT",
-            SqliteType::Boolean => "BOOLEAN",
-            SqliteType::Integer => "INTEGER",
-            SqliteType::Float => "DOUBLE",
-            SqliteType::Blob => "BLOB",
-        })
+        f.write_str(&self.name())
     }
 }
 
@@ -64,6 +95,16 @@ impl TypeInfo for SqliteTypeInfo {
     fn compatible(&self, other: &Self) -> bool {
         self.r#type == other.r#type || self.affinity == other.affinity
     }
+
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(match self.r#type {
+            SqliteType::Text => "TEXT",
+            SqliteType::Boolean => "BOOLEAN",
+            SqliteType::Integer => "INTEGER",
+            SqliteType::Float => "DOUBLE",
+            SqliteType::Blob => "BLOB",
+        })
+    }
 }
 
 impl<'de, T> Decode<'de, Sqlite> for Option<T>
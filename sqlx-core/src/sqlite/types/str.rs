@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::error::UnexpectedNullError;
@@ -17,6 +19,12 @@ impl Type<Sqlite> for String {
     }
 }
 
+impl Type<Sqlite> for Cow<'_, str> {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+}
+
 impl Encode<Sqlite> for str {
     fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
         // TODO: look into a way to remove this allocation
@@ -30,10 +38,19 @@ impl Encode<Sqlite> for String {
     }
 }
 
+impl Encode<Sqlite> for Cow<'_, str> {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        <str as Encode<Sqlite>>::encode(self, values)
+    }
+}
+
 impl<'de> Decode<'de, Sqlite> for &'de str {
     fn decode(value: SqliteValue<'de>) -> crate::Result<Sqlite, &'de str> {
         value
             .text()
+            .map_err(|err| {
+                crate::Error::Decode(format!("TEXT value is not valid UTF-8: {}", err).into())
+            })?
             .ok_or_else(|| crate::Error::decode(UnexpectedNullError))
     }
 }
@@ -43,3 +60,10 @@ impl<'de> Decode<'de, Sqlite> for String {
         <&str as Decode<Sqlite>>::decode(value).map(ToOwned::to_owned)
     }
 }
+
+// The value is always borrowed from the row; use `into_owned` to keep it past the row
+impl<'de> Decode<'de, Sqlite> for Cow<'de, str> {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<Sqlite, Cow<'de, str>> {
+        <&str as Decode<Sqlite>>::decode(value).map(Cow::Borrowed)
+    }
+}
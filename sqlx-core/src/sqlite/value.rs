@@ -1,14 +1,17 @@
 use core::slice;
 
-use std::ffi::CStr;
-use std::str::from_utf8_unchecked;
+use std::os::raw::c_int;
+use std::str::{from_utf8, Utf8Error};
 
 use libsqlite3_sys::{
     sqlite3_column_blob, sqlite3_column_bytes, sqlite3_column_double, sqlite3_column_int,
-    sqlite3_column_int64, sqlite3_column_text, sqlite3_column_type, SQLITE_NULL,
+    sqlite3_column_int64, sqlite3_column_text, sqlite3_column_type, SQLITE_BLOB, SQLITE_FLOAT,
+    SQLITE_INTEGER, SQLITE_NULL, SQLITE_TEXT,
 };
 
 use crate::sqlite::statement::Statement;
+use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
+use crate::sqlite::SqliteTypeInfo;
 
 pub struct SqliteValue<'c> {
     pub(super) index: i32,
@@ -21,11 +24,35 @@ pub struct SqliteValue<'c> {
 // These routines return information about a single column of the current result row of a query.
 
 impl<'c> SqliteValue<'c> {
+    fn type_code(&self) -> c_int {
+        #[allow(unsafe_code)]
+        unsafe {
+            sqlite3_column_type(self.statement.handle(), self.index)
+        }
+    }
+
     /// Returns true if the value should be intrepreted as NULL.
     pub(super) fn is_null(&self) -> bool {
-        #[allow(unsafe_code)]
-        let type_code = unsafe { sqlite3_column_type(self.statement.handle(), self.index) };
-        type_code == SQLITE_NULL
+        self.type_code() == SQLITE_NULL
+    }
+
+    /// The type that the value is stored as, or `None` if the value is NULL.
+    ///
+    /// SQLite is dynamically typed so this is the type of this value and not of its column;
+    /// e.g., a column declared as `INTEGER` may hold TEXT. A dynamic [`Decode`] can use this
+    /// to choose how to decode the value.
+    ///
+    /// [`Decode`]: crate::decode::Decode
+    pub fn type_info(&self) -> Option<SqliteTypeInfo> {
+        let (r#type, affinity) = match self.type_code() {
+            SQLITE_INTEGER => (SqliteType::Integer, SqliteTypeAffinity::Integer),
+            SQLITE_FLOAT => (SqliteType::Float, SqliteTypeAffinity::Real),
+            SQLITE_TEXT => (SqliteType::Text, SqliteTypeAffinity::Text),
+            SQLITE_BLOB => (SqliteType::Blob, SqliteTypeAffinity::Blob),
+            _ => return None,
+        };
+
+        Some(SqliteTypeInfo::new(r#type, affinity))
     }
 
     /// Returns the 32-bit INTEGER result.
@@ -52,18 +79,20 @@ impl<'c> SqliteValue<'c> {
         }
     }
 
-    /// Returns the UTF-8 TEXT result.
-    pub(super) fn text(&self) -> Option<&'c str> {
+    /// Returns the TEXT result, which SQLite does not guarantee to be valid UTF-8.
+    pub(super) fn text(&self) -> Result<Option<&'c str>, Utf8Error> {
         #[allow(unsafe_code)]
-        unsafe {
-            let ptr = sqlite3_column_text(self.statement.handle(), self.index) as *const i8;
+        let ptr = unsafe { sqlite3_column_text(self.statement.handle(), self.index) };
 
-            if ptr.is_null() {
-                None
-            } else {
-                Some(from_utf8_unchecked(CStr::from_ptr(ptr).to_bytes()))
-            }
+        if ptr.is_null() {
+            return Ok(None);
         }
+
+        // the text may contain NUL characters so the length is used rather than the terminator
+        #[allow(unsafe_code)]
+        let bytes = unsafe { slice::from_raw_parts(ptr as *const u8, self.bytes()) };
+
+        from_utf8(bytes).map(Some)
     }
 
     fn bytes(&self) -> usize {
@@ -1,13 +1,14 @@
+use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 
 use futures_core::future::BoxFuture;
 
-use crate::connection::Connection;
+use crate::connection::{Connection, QueryLogger};
 use crate::database::Database;
 use crate::database::HasCursor;
 use crate::describe::Describe;
 use crate::executor::{Execute, Executor, RefExecutor};
-use crate::runtime::spawn;
+use crate::runtime::{can_spawn, spawn};
 
 /// Represents an in-progress database transaction.
 ///
@@ -16,6 +17,12 @@ use crate::runtime::spawn;
 /// goes out-of-scope, [`rollback`] is called. In other words, [`rollback`] is called on `drop`
 /// if the transaction is still in-progress.
 ///
+/// The rollback on `drop` is done in the background and errors are ignored. If it succeeds, the
+/// connection is dropped in turn, e.g., a [`PoolConnection`] is returned to its pool; otherwise
+/// it is closed. Where no task can be spawned, e.g., outside of a Tokio runtime, the connection
+/// is closed without a rollback instead of panicking; a [`PoolConnection`] is not returned to
+/// its pool with the transaction still open.
+///
 /// ```rust,ignore
 /// // Acquire a new connection and immediately begin a transaction
 /// let mut tx = pool.begin().await?;
@@ -31,6 +38,7 @@ use crate::runtime::spawn;
 ///
 /// [`commit`]: #method.commit
 /// [`rollback`]: #method.rollback
+/// [`PoolConnection`]: crate::pool::PoolConnection
 // Transaction<PoolConnection<PgConnection>>
 // Transaction<PgConnection>
 pub struct Transaction<C>
@@ -39,6 +47,8 @@ where
 {
     inner: Option<C>,
     depth: u32,
+    // Restores the connection after the transaction, e.g., when it was made read-only
+    end: Option<&'static str>,
 }
 
 impl<C> Transaction<C>
@@ -57,9 +67,39 @@ where
         Ok(Self {
             inner: Some(inner),
             depth: depth + 1,
+            end: None,
         })
     }
 
+    async fn with_options(mut inner: C, options: Options) -> crate::Result<C::Database, Self> {
+        // an invalid combination of options is rejected before anything is sent
+        let (begin, end) = <C::Database as Database>::begin_transaction(&options)?;
+
+        inner.execute(&*begin).await?;
+
+        Ok(Self {
+            inner: Some(inner),
+            depth: 1,
+            end,
+        })
+    }
+
+    /// Returns a builder to begin a transaction with options, e.g., one that is read-only.
+    ///
+    /// ```rust,ignore
+    /// let mut tx = Transaction::builder()
+    ///     .read_only(true)
+    ///     .isolation_level(IsolationLevel::RepeatableRead)
+    ///     .begin(conn)
+    ///     .await?;
+    /// ```
+    pub fn builder() -> Builder<C> {
+        Builder {
+            phantom: PhantomData,
+            options: Options::default(),
+        }
+    }
+
     /// Creates a new save point in the current transaction and returns
     /// a new `Transaction` object to manage its scope.
     pub async fn begin(self) -> crate::Result<C::Database, Transaction<Transaction<C>>> {
@@ -73,7 +113,24 @@ where
         let depth = self.depth;
 
         if depth == 1 {
-            inner.execute("COMMIT").await?;
+            // A `COMMIT` that fails can leave the transaction open, e.g., on SQLite for a
+            // deferred foreign key; it is rolled back as if dropped so the connection is not
+            // reused in the middle of it, or with the transaction's settings
+            if let Err(error) = inner.execute("COMMIT").await {
+                if rollback(&mut inner, depth, self.end).await.is_err() {
+                    inner.discard();
+                }
+
+                return Err(error);
+            }
+
+            if let Some(end) = self.end {
+                if let Err(error) = inner.execute(end).await {
+                    inner.discard();
+
+                    return Err(error);
+                }
+            }
         } else {
             let stmt = format!("RELEASE SAVEPOINT _sqlx_savepoint_{}", depth - 1);
 
@@ -87,17 +144,103 @@ where
     /// Returns the inner connection or transaction.
     pub async fn rollback(mut self) -> crate::Result<C::Database, C> {
         let mut inner = self.inner.take().expect(ERR_FINALIZED);
-        let depth = self.depth;
 
-        if depth == 1 {
-            inner.execute("ROLLBACK").await?;
-        } else {
-            let stmt = format!("ROLLBACK TO SAVEPOINT _sqlx_savepoint_{}", depth - 1);
+        rollback(&mut inner, self.depth, self.end).await?;
 
-            inner.execute(&*stmt).await?;
+        Ok(inner)
+    }
+}
+
+async fn rollback<C>(
+    inner: &mut C,
+    depth: u32,
+    end: Option<&'static str>,
+) -> crate::Result<C::Database, ()>
+where
+    C: Connection,
+{
+    if depth == 1 {
+        inner.execute("ROLLBACK").await?;
+
+        if let Some(end) = end {
+            inner.execute(end).await?;
         }
+    } else {
+        let stmt = format!("ROLLBACK TO SAVEPOINT _sqlx_savepoint_{}", depth - 1);
 
-        Ok(inner)
+        inner.execute(&*stmt).await?;
+    }
+
+    Ok(())
+}
+
+/// The isolation level of a transaction.
+///
+/// Which levels a database supports, and how it implements them, differs; e.g., **SQLite**
+/// transactions are always `Serializable`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl IsolationLevel {
+    pub(crate) fn as_sql(self) -> &'static str {
+        match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        }
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Options {
+    pub read_only: bool,
+    pub isolation_level: Option<IsolationLevel>,
+}
+
+/// Builder for a [`Transaction`] with options.
+///
+/// The options apply to a transaction begun on a connection and not to a save point.
+pub struct Builder<C>
+where
+    C: Connection,
+{
+    phantom: PhantomData<C>,
+    options: Options,
+}
+
+impl<C> Builder<C>
+where
+    C: Connection,
+{
+    /// If true, the transaction can only read data; a statement that writes fails.
+    ///
+    /// **Postgres** and **MySQL** begin the transaction as `READ ONLY`. **SQLite** sets
+    /// `PRAGMA query_only` for the duration of the transaction.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.options.read_only = read_only;
+        self
+    }
+
+    /// Set the isolation level of the transaction instead of using that of the session.
+    ///
+    /// **SQLite** only accepts [`IsolationLevel::Serializable`].
+    pub fn isolation_level(mut self, level: IsolationLevel) -> Self {
+        self.options.isolation_level = Some(level);
+        self
+    }
+
+    /// Begins the transaction on the connection.
+    ///
+    /// An invalid combination of options for the database is an error and nothing is sent.
+    pub async fn begin(self, conn: C) -> crate::Result<C::Database, Transaction<C>> {
+        Transaction::with_options(conn, self.options).await
     }
 }
 
@@ -127,6 +270,11 @@ impl<C> Connection for Transaction<C>
 where
     C: Connection,
 {
+    // Begins a save point even when only known to be a `Connection`
+    fn begin(self) -> BoxFuture<'static, crate::Result<C::Database, Transaction<Self>>> {
+        Box::pin(Transaction::new(self.depth, self))
+    }
+
     // Close is equivalent to
     fn close(mut self) -> BoxFuture<'static, crate::Result<C::Database, ()>> {
         Box::pin(async move {
@@ -155,6 +303,19 @@ where
     fn ping(&mut self) -> BoxFuture<'_, crate::Result<C::Database, ()>> {
         self.deref_mut().ping()
     }
+
+    #[doc(hidden)]
+    #[inline]
+    fn query_logger(&mut self) -> &mut QueryLogger {
+        self.deref_mut().query_logger()
+    }
+
+    #[doc(hidden)]
+    fn discard(&mut self) {
+        if let Some(inner) = &mut self.inner {
+            inner.discard();
+        }
+    }
 }
 
 impl<DB, C> Executor for Transaction<C>
@@ -214,9 +375,21 @@ where
 {
     fn drop(&mut self) {
         if self.depth > 0 {
-            if let Some(inner) = self.inner.take() {
+            if let Some(mut inner) = self.inner.take() {
+                if !can_spawn() {
+                    // Neither the transaction nor the connection can be ended here
+                    inner.discard();
+                    return;
+                }
+
+                let depth = self.depth;
+                let end = self.end;
+
                 spawn(async move {
-                    let _ = inner.close().await;
+                    // A connection that could not be rolled back is not reused
+                    if rollback(&mut inner, depth, end).await.is_err() {
+                        let _ = inner.close().await;
+                    }
                 });
             }
         }
@@ -1,5 +1,6 @@
 //! Traits linking Rust types to SQL types.
 
+use std::borrow::Cow;
 use std::fmt::{Debug, Display};
 
 use crate::database::Database;
@@ -8,10 +9,14 @@ use crate::database::Database;
 #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
 pub use uuid::Uuid;
 
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+pub use bytes::Bytes;
+
 #[cfg(feature = "chrono")]
 #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
 pub mod chrono {
-    pub use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    pub use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 }
 
 #[cfg(feature = "time")]
@@ -24,6 +29,10 @@ pub mod time {
 #[cfg_attr(docsrs, doc(cfg(feature = "bigdecimal")))]
 pub use bigdecimal::BigDecimal;
 
+#[cfg(feature = "rust_decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust_decimal")))]
+pub use rust_decimal::Decimal;
+
 #[cfg(feature = "ipnetwork")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ipnetwork")))]
 pub mod ipnetwork {
@@ -35,10 +44,50 @@ pub mod ipnetwork {
 #[derive(Debug, PartialEq)]
 pub struct Json<T>(pub T);
 
+/// A value of any type, for rows whose types are not known ahead of time.
+///
+/// With SQLite, `AnyValue` can be decoded like any other type. With Postgres, the type of a
+/// value is not known to [`Decode`] so it is read with [`PgRow::try_get_any`] instead.
+///
+/// [`Decode`]: crate::decode::Decode
+/// [`PgRow::try_get_any`]: crate::postgres::PgRow::try_get_any
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Bytes(Vec<u8>),
+
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    Json(serde_json::Value),
+
+    #[cfg(feature = "uuid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+    Uuid(Uuid),
+
+    /// A value of any other type, as it was received from the database, with the object
+    /// identifier (OID) of its type.
+    Other {
+        oid: u32,
+        bytes: Vec<u8>,
+    },
+}
+
 pub trait TypeInfo: Debug + Display + Clone {
     /// Compares type information to determine if `other` is compatible at the Rust level
     /// with `self`.
     fn compatible(&self, other: &Self) -> bool;
+
+    /// The name of the type as the database knows it, e.g., `INT4` or `TEXT`.
+    ///
+    /// A type whose name is not known is named after its identifier like it is displayed,
+    /// e.g., `OID 16385` for a Postgres type that was only given by its object identifier.
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(self.to_string())
+    }
 }
 
 /// Indicates that a SQL type is supported for a database.
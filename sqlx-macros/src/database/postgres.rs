@@ -5,6 +5,7 @@ impl_database_ext! {
         i16,
         i32,
         i64,
+        u32,
         f32,
         f64,
 
@@ -40,6 +41,9 @@ impl_database_ext! {
         #[cfg(feature = "bigdecimal")]
         sqlx::types::BigDecimal,
 
+        #[cfg(feature = "rust_decimal")]
+        sqlx::types::Decimal,
+
         #[cfg(feature = "ipnetwork")]
         sqlx::types::ipnetwork::IpNetwork,
 
@@ -49,6 +53,7 @@ impl_database_ext! {
         Vec<i16> | &[i16],
         Vec<i32> | &[i32],
         Vec<i64> | &[i64],
+        Vec<u32> | &[u32],
         Vec<f32> | &[f32],
         Vec<f64> | &[f64],
     },
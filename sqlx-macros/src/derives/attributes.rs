@@ -34,6 +34,7 @@ pub enum RenameAll {
 pub struct SqlxContainerAttributes {
     pub transparent: bool,
     pub postgres_oid: Option<u32>,
+    pub rename: Option<String>,
     pub rename_all: Option<RenameAll>,
     pub repr: Option<Ident>,
 }
@@ -46,6 +47,7 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
     let mut transparent = None;
     let mut postgres_oid = None;
     let mut repr = None;
+    let mut rename = None;
     let mut rename_all = None;
 
     for attr in input {
@@ -75,6 +77,12 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                                 try_set!(rename_all, val, value)
                             }
 
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("rename") => try_set!(rename, val.value(), value),
+
                             Meta::List(list) if list.path.is_ident("postgres") => {
                                 for value in list.nested.iter() {
                                     match value {
@@ -115,6 +123,7 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
         transparent: transparent.unwrap_or(false),
         postgres_oid,
         repr,
+        rename,
         rename_all,
     })
 }
@@ -167,6 +176,12 @@ pub fn check_transparent_attributes(input: &DeriveInput, field: &Field) -> syn::
         input
     );
 
+    assert_attribute!(
+        attributes.rename.is_none(),
+        "unexpected #[sqlx(rename = ..)]",
+        input
+    );
+
     assert_attribute!(
         attributes.rename_all.is_none(),
         "unexpected #[sqlx(rename_all = ..)]",
@@ -213,6 +228,12 @@ pub fn check_weak_enum_attributes(
 
     assert_attribute!(attributes.repr.is_some(), "expected #[repr(..)]", input);
 
+    assert_attribute!(
+        attributes.rename.is_none(),
+        "unexpected #[sqlx(rename = ..)]",
+        input
+    );
+
     assert_attribute!(
         attributes.rename_all.is_none(),
         "unexpected #[sqlx(c = ..)]",
@@ -238,10 +259,11 @@ pub fn check_strong_enum_attributes(
 ) -> syn::Result<SqlxContainerAttributes> {
     let attributes = check_enum_attributes(input)?;
 
+    // without an OID, the Postgres type is looked up by name
     #[cfg(feature = "postgres")]
     assert_attribute!(
-        attributes.postgres_oid.is_some(),
-        "expected #[sqlx(postgres(oid = ..))]",
+        attributes.postgres_oid.is_none() || attributes.rename.is_none(),
+        "unexpected #[sqlx(rename = ..)] with #[sqlx(postgres(oid = ..))]",
         input
     );
 
@@ -262,10 +284,11 @@ pub fn check_struct_attributes<'a>(
         input
     );
 
+    // without an OID, the Postgres type is looked up by name
     #[cfg(feature = "postgres")]
     assert_attribute!(
-        attributes.postgres_oid.is_some(),
-        "expected #[sqlx(postgres(oid = ..))]",
+        attributes.postgres_oid.is_none() || attributes.rename.is_none(),
+        "unexpected #[sqlx(rename = ..)] with #[sqlx(postgres(oid = ..))]",
         input
     );
 
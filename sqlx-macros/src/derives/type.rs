@@ -119,11 +119,17 @@ fn expand_derive_has_sql_type_strong_enum(
     }
 
     if cfg!(feature = "postgres") {
-        let oid = attributes.postgres_oid.unwrap();
+        let type_info = if let Some(oid) = attributes.postgres_oid {
+            quote!(sqlx::postgres::PgTypeInfo::with_oid(#oid))
+        } else {
+            let name = attributes.rename.unwrap_or_else(|| ident.to_string());
+            quote!(sqlx::postgres::PgTypeInfo::with_name(#name))
+        };
+
         tts.extend(quote!(
             impl sqlx::Type< sqlx::Postgres > for #ident {
                 fn type_info() -> sqlx::postgres::PgTypeInfo {
-                    sqlx::postgres::PgTypeInfo::with_oid(#oid)
+                    #type_info
                 }
             }
         ));
@@ -152,11 +158,17 @@ fn expand_derive_has_sql_type_struct(
     let mut tts = proc_macro2::TokenStream::new();
 
     if cfg!(feature = "postgres") {
-        let oid = attributes.postgres_oid.unwrap();
+        let type_info = if let Some(oid) = attributes.postgres_oid {
+            quote!(sqlx::postgres::PgTypeInfo::with_oid(#oid))
+        } else {
+            let name = attributes.rename.unwrap_or_else(|| ident.to_string());
+            quote!(sqlx::postgres::PgTypeInfo::with_name(#name))
+        };
+
         tts.extend(quote!(
             impl sqlx::types::Type< sqlx::Postgres > for #ident {
                 fn type_info() -> sqlx::postgres::PgTypeInfo {
-                    sqlx::postgres::PgTypeInfo::with_oid(#oid)
+                    #type_info
                 }
             }
         ));
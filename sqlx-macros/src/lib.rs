@@ -11,6 +11,9 @@ use quote::quote;
 #[cfg(feature = "runtime-async-std")]
 use async_std::task::block_on;
 
+#[cfg(feature = "runtime-smol")]
+use smol::run as block_on;
+
 use url::Url;
 
 type Error = Box<dyn std::error::Error>;
@@ -1,11 +1,34 @@
-#[cfg(not(any(feature = "runtime-tokio", feature = "runtime-async-std")))]
-compile_error!("one of 'runtime-async-std' or 'runtime-tokio' features must be enabled");
+#[cfg(not(any(
+    feature = "runtime-tokio",
+    feature = "runtime-async-std",
+    feature = "runtime-smol"
+)))]
+compile_error!(
+    "one of 'runtime-async-std', 'runtime-tokio' or 'runtime-smol' features must be enabled"
+);
 
-#[cfg(all(feature = "runtime-tokio", feature = "runtime-async-std"))]
-compile_error!("only one of 'runtime-async-std' or 'runtime-tokio' features must be enabled");
+#[cfg(any(
+    all(feature = "runtime-tokio", feature = "runtime-async-std"),
+    all(feature = "runtime-tokio", feature = "runtime-smol"),
+    all(feature = "runtime-async-std", feature = "runtime-smol")
+))]
+compile_error!(
+    "only one of 'runtime-async-std', 'runtime-tokio' or 'runtime-smol' features must be enabled"
+);
 
 #[cfg(feature = "runtime-async-std")]
 pub(crate) use async_std::fs;
 
 #[cfg(feature = "runtime-tokio")]
 pub(crate) use tokio::fs;
+
+// smol has no file system API; the macros block on a single future so the file is read in place
+#[cfg(feature = "runtime-smol")]
+pub(crate) mod fs {
+    use std::io;
+    use std::path::Path;
+
+    pub(crate) async fn read_to_string(path: impl AsRef<Path>) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+}
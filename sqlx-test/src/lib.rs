@@ -164,7 +164,7 @@ macro_rules! MySql_query_for_test_prepared_type {
 #[macro_export]
 macro_rules! Sqlite_query_for_test_prepared_type {
     () => {
-        "SELECT {0} is ?, cast(? as text) as _1, {0} as _2, ? as _3"
+        "SELECT {0} is ?, quote(?) as _1, {0} as _2, ? as _3"
     };
 }
 
@@ -1,7 +1,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub use sqlx_core::arguments;
-pub use sqlx_core::connection::{Connect, Connection};
+pub use sqlx_core::connection::{Connect, Connection, QueryLog};
 pub use sqlx_core::cursor::Cursor;
 pub use sqlx_core::database::{self, Database};
 pub use sqlx_core::executor::{self, Execute, Executor};
@@ -9,7 +9,7 @@ pub use sqlx_core::pool::{self, Pool};
 pub use sqlx_core::query::{self, query, Query};
 pub use sqlx_core::query_as::{query_as, QueryAs};
 pub use sqlx_core::row::{self, FromRow, Row};
-pub use sqlx_core::transaction::Transaction;
+pub use sqlx_core::transaction::{self, Transaction};
 
 #[doc(hidden)]
 pub use sqlx_core::describe;
@@ -32,6 +32,10 @@ pub use sqlx_core::postgres::{self, PgConnection, PgPool, Postgres};
 #[cfg_attr(docsrs, doc(cfg(feature = "sqlite")))]
 pub use sqlx_core::sqlite::{self, Sqlite, SqliteConnection, SqlitePool};
 
+#[cfg(all(feature = "any", any(feature = "postgres", feature = "sqlite")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "any")))]
+pub use sqlx_core::any::{self, Any, AnyConnection, AnyPool};
+
 #[cfg(feature = "macros")]
 #[doc(hidden)]
 pub extern crate sqlx_macros;
@@ -84,4 +88,7 @@ pub mod prelude {
 
     #[cfg(feature = "sqlite")]
     pub use super::sqlite::SqliteQueryAs;
+
+    #[cfg(all(feature = "any", any(feature = "postgres", feature = "sqlite")))]
+    pub use super::any::AnyQueryAs;
 }
@@ -0,0 +1,177 @@
+use futures::TryStreamExt;
+use sqlx::any::AnyQueryAs;
+use sqlx::types::AnyValue;
+use sqlx::{Any, AnyConnection, AnyPool, Connect, Connection, Executor, Row};
+use sqlx_test::new;
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects() -> anyhow::Result<()> {
+    Ok(new::<Any>().await?.ping().await?)
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fails_to_connect_with_an_unsupported_scheme() -> anyhow::Result<()> {
+    let err = AnyConnection::connect("mongodb://localhost/sqlx")
+        .await
+        .err()
+        .unwrap();
+
+    assert!(matches!(err, sqlx::Error::Configuration(_)), "{:?}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    for index in 1..=10_i32 {
+        let affected = sqlx::query("INSERT INTO users (id) VALUES ($1)")
+            .bind(index)
+            .execute(&mut conn)
+            .await?;
+
+        assert_eq!(affected, 1);
+    }
+
+    let sum: i64 = sqlx::query("SELECT id FROM users")
+        .try_map(|row: sqlx::any::AnyRow| row.try_get::<i32, _>(0))
+        .fetch(&mut conn)
+        .try_fold(0_i64, |acc, x| async move { Ok(acc + x as i64) })
+        .await?;
+
+    assert_eq!(sum, 55);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_round_trips_values() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    let (int, text, bytes): (i64, String, Vec<u8>) = sqlx::query_as("SELECT $1, $2, $3")
+        .bind(10_i64)
+        .bind("Hello, World")
+        .bind(vec![0xDE_u8, 0xAD, 0xBE, 0xEF])
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(int, 10);
+    assert_eq!(text, "Hello, World");
+    assert_eq!(bytes, [0xDE, 0xAD, 0xBE, 0xEF]);
+
+    let row = sqlx::query("SELECT $1 AS ratio, $2 AS flag")
+        .bind(1.5_f64)
+        .bind(true)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.try_get::<f64, _>("ratio")?, 1.5);
+    assert!(row.try_get::<bool, _>("flag")?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_round_trips_null() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    let row = sqlx::query("SELECT NULL").fetch_one(&mut conn).await?;
+
+    assert_eq!(row.try_get::<AnyValue, _>(0)?, AnyValue::Null);
+    assert_eq!(row.try_get::<Option<i64>, _>(0)?, None);
+    assert!(row.try_get::<i64, _>(0).is_err());
+
+    let (value,): (Option<i64>,) = sqlx::query_as("SELECT $1")
+        .bind(Some(5_i64))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, Some(5));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_from_a_pool() -> anyhow::Result<()> {
+    let pool = AnyPool::builder()
+        .max_size(2)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let rows: Vec<(i64, String)> =
+        sqlx::query_as("SELECT 1, 'one' UNION ALL SELECT 2, 'two' ORDER BY 1")
+            .fetch_all(&pool)
+            .await?;
+
+    assert_eq!(rows, vec![(1, "one".to_owned()), (2, "two".to_owned())]);
+
+    let row = sqlx::query("SELECT $1 AS id")
+        .bind(7_i64)
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(row.try_get::<i64, _>("id")?, 7);
+    assert!(row.try_get::<i64, _>("name").is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_commits_and_rolls_back_a_transaction() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE names (name TEXT)")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO names (name) VALUES ($1)")
+        .bind("kept")
+        .execute(&mut tx)
+        .await?;
+
+    let mut conn = tx.commit().await?;
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO names (name) VALUES ($1)")
+        .bind("discarded")
+        .execute(&mut tx)
+        .await?;
+
+    let mut conn = tx.rollback().await?;
+
+    let names: Vec<(String,)> = sqlx::query_as("SELECT name FROM names")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(names, vec![("kept".to_owned(),)]);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_to_sqlite() -> anyhow::Result<()> {
+    let mut conn = AnyConnection::connect("sqlite::memory:").await?;
+
+    let (value,): (String,) = sqlx::query_as("SELECT $1")
+        .bind("in memory")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, "in memory");
+
+    Ok(())
+}
@@ -1,3 +1,6 @@
+CREATE EXTENSION IF NOT EXISTS hstore;
+CREATE EXTENSION IF NOT EXISTS ltree;
+
 CREATE TABLE accounts (
     id              BIGSERIAL PRIMARY KEY,
     created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
@@ -6,9 +9,16 @@ CREATE TABLE accounts (
     score           DOUBLE PRECISION
 );
 
+CREATE TYPE mood AS ENUM ('sad', 'ok', 'happy');
+
 -- https://www.postgresql.org/docs/current/rowtypes.html#ROWTYPES-DECLARING
 CREATE TYPE inventory_item AS (
     name            TEXT,
     supplier_id     INT,
     price           BIGINT
 );
+
+CREATE TYPE address AS (
+    street          TEXT,
+    city            TEXT
+);
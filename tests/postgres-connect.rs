@@ -0,0 +1,256 @@
+//! Tests for the options of a Postgres connection.
+
+use std::time::{Duration, Instant};
+
+use sqlx::postgres::{PgHostsError, PgQueryAs, PgSqlState};
+use sqlx::{Connect, Executor, PgConnection};
+
+#[cfg(unix)]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_over_a_unix_socket() -> anyhow::Result<()> {
+    // the socket is only found if the server is local
+    let dir = match ["/var/run/postgresql", "/tmp"]
+        .iter()
+        .find(|dir| std::path::Path::new(dir).join(".s.PGSQL.5432").exists())
+    {
+        Some(dir) => dir,
+        None => return Ok(()),
+    };
+
+    for url in &[
+        format!("postgres:///postgres?host={}", dir),
+        format!("postgres://{}/postgres", dir.replace('/', "%2F")),
+    ] {
+        let mut conn = PgConnection::connect(&**url).await?;
+
+        // a Unix domain socket has no client address
+        let (addr,): (Option<String>,) = sqlx::query_as("SELECT inet_client_addr()::text")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(addr, None);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_sets_the_application_name() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn = PgConnection::connect(&*format!(
+        "{}{}application_name=sqlx%20test",
+        url, separator
+    ))
+    .await?;
+
+    let (name,): (String,) = sqlx::query_as("SHOW application_name")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(name, "sqlx test");
+
+    assert!(
+        PgConnection::connect(&*format!("{}{}application_name=a%00b", url, separator))
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_sets_the_statement_timeout() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn =
+        PgConnection::connect(&*format!("{}{}statement_timeout=100ms", url, separator)).await?;
+
+    let start = Instant::now();
+
+    match conn.execute("SELECT pg_sleep(10)").await {
+        Err(sqlx::Error::Database(err)) => {
+            assert_eq!(err.sql_state(), Some(PgSqlState::QueryCanceled));
+        }
+
+        res => panic!("expected the statement to time out; got {:?}", res),
+    }
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    // only the statement is canceled
+    let (timeout,): (String,) = sqlx::query_as("SHOW statement_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(timeout, "100ms");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_cancels_a_running_statement() -> anyhow::Result<()> {
+    #[cfg(feature = "runtime-async-std")]
+    use async_std::task::{sleep, spawn};
+
+    #[cfg(feature = "runtime-tokio")]
+    use tokio::{task::spawn, time::delay_for as sleep};
+
+    let mut conn = PgConnection::connect(dotenv::var("DATABASE_URL")?).await?;
+    let token = conn.cancel_token();
+
+    let start = Instant::now();
+
+    let cancel = spawn(async move {
+        sleep(Duration::from_millis(200)).await;
+        token.cancel().await
+    });
+
+    match conn.execute("SELECT pg_sleep(10)").await {
+        Err(sqlx::Error::Database(err)) => {
+            assert_eq!(err.sql_state(), Some(PgSqlState::QueryCanceled));
+        }
+
+        res => panic!("expected the statement to be canceled; got {:?}", res),
+    }
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    #[cfg(feature = "runtime-async-std")]
+    cancel.await?;
+
+    #[cfg(feature = "runtime-tokio")]
+    cancel.await??;
+
+    // the connection is still usable
+    let (value,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_tracks_parameter_status() -> anyhow::Result<()> {
+    let mut conn = PgConnection::connect(dotenv::var("DATABASE_URL")?).await?;
+
+    let (version,): (String,) = sqlx::query_as("SHOW server_version")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(conn.parameter_status("server_version"), Some(&*version));
+
+    let (major, _) = conn.server_version().unwrap();
+    assert!(major >= 9);
+
+    assert_eq!(conn.parameter_status("TimeZone"), Some("UTC"));
+
+    conn.execute("SET TIME ZONE 'America/New_York'").await?;
+
+    assert_eq!(conn.parameter_status("TimeZone"), Some("America/New_York"));
+    assert_eq!(conn.parameter_status("not_a_parameter"), None);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_closes_the_least_recently_used_statement() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn =
+        PgConnection::connect(&*format!("{}{}statement_cache_capacity=2", url, separator)).await?;
+
+    for query in &["SELECT $1::int4", "SELECT $1::int4 + 1", "SELECT $1::int4"] {
+        let _: (i32,) = sqlx::query_as(query).bind(10).fetch_one(&mut conn).await?;
+    }
+
+    assert_eq!(conn.cached_statements_size(), 2);
+
+    // preparing this query evicts `SELECT $1::int4 + 1`
+    let statements: Vec<(String,)> = sqlx::query_as("SELECT statement FROM pg_prepared_statements")
+        .fetch_all(&mut conn)
+        .await?;
+
+    let statements: Vec<_> = statements.into_iter().map(|(s,)| s).collect();
+
+    assert_eq!(statements.len(), 2);
+    assert!(statements.iter().any(|s| s == "SELECT $1::int4"));
+    assert!(!statements.iter().any(|s| s == "SELECT $1::int4 + 1"));
+    assert_eq!(conn.cached_statements_size(), 2);
+
+    let mut conn =
+        PgConnection::connect(&*format!("{}{}statement_cache_capacity=0", url, separator)).await?;
+
+    let (value,): (i32,) = sqlx::query_as("SELECT $1::int4")
+        .bind(10)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 10);
+    assert_eq!(conn.cached_statements_size(), 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fails_over_to_the_next_host() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+
+    // nothing listens on port 1 so the first host is skipped
+    let authority = url.find("://").unwrap() + 3;
+    let hosts = url[authority..]
+        .find('@')
+        .map_or(authority, |i| authority + i + 1);
+
+    let url = format!("{}127.0.0.1:1,{}", &url[..hosts], &url[hosts..]);
+
+    let mut conn = PgConnection::connect(&*url).await?;
+
+    let (value,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    // the primary accepts read-write sessions
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn = PgConnection::connect(&*format!(
+        "{}{}target_session_attrs=read-write",
+        url, separator
+    ))
+    .await?;
+
+    let (read_only,): (String,) = sqlx::query_as("SHOW transaction_read_only")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(read_only, "off");
+
+    // every host is unreachable
+    let err = PgConnection::connect("postgres://127.0.0.1:1,127.0.0.1:2/postgres")
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("127.0.0.1:2"));
+
+    let hosts = match err {
+        sqlx::Error::Io(ref err) => err
+            .get_ref()
+            .and_then(|err| err.downcast_ref::<PgHostsError>())
+            .unwrap(),
+
+        err => panic!("expected an IO error; got {:?}", err),
+    };
+
+    let ports: Vec<u16> = hosts.errors().iter().map(|(_, port, _)| *port).collect();
+    assert_eq!(ports, [1, 2]);
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+//! Tests for `COPY` through `PgConnection::copy_in` and `PgConnection::copy_out`.
+
+use futures::TryStreamExt;
+use sqlx::postgres::PgQueryAs;
+use sqlx::types::Bytes;
+use sqlx::{Executor, PgConnection, Postgres};
+use sqlx_test::new;
+
+async fn table(conn: &mut PgConnection, name: &str) -> anyhow::Result<()> {
+    conn.execute(&*format!(
+        "CREATE TEMPORARY TABLE {} (id INTEGER PRIMARY KEY, name TEXT)",
+        name
+    ))
+    .await?;
+
+    Ok(())
+}
+
+async fn count(conn: &mut PgConnection, table: &str) -> anyhow::Result<i64> {
+    let (count,): (i64,) = sqlx::query_as(&format!("SELECT COUNT(*) FROM {}", table))
+        .fetch_one(conn)
+        .await?;
+
+    Ok(count)
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_copies_in_text() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    table(&mut conn, "copy_in_text").await?;
+
+    let mut copy = conn
+        .copy_in("COPY copy_in_text (id, name) FROM STDIN")
+        .await?;
+
+    // chunks do not have to line up with rows
+    copy.send(b"1\tAlice\n2\tB").await?;
+    copy.send("ob\n3\t\\N\n").await?;
+
+    assert_eq!(copy.finish().await?, 3);
+
+    let (name,): (Option<String>,) = sqlx::query_as("SELECT name FROM copy_in_text WHERE id = 2")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(name.as_deref(), Some("Bob"));
+    assert_eq!(count(&mut conn, "copy_in_text").await?, 3);
+
+    let (name,): (Option<String>,) = sqlx::query_as("SELECT name FROM copy_in_text WHERE id = 3")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(name, None);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_copies_in_many_rows() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    table(&mut conn, "copy_in_many").await?;
+
+    let mut copy = conn
+        .copy_in("COPY copy_in_many FROM STDIN (FORMAT csv)")
+        .await?;
+
+    for id in 0..10_000 {
+        copy.send(format!("{},\"name, {}\"\n", id, id)).await?;
+    }
+
+    assert_eq!(copy.finish().await?, 10_000);
+    assert_eq!(count(&mut conn, "copy_in_many").await?, 10_000);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_invalid_copy_data() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    table(&mut conn, "copy_in_invalid").await?;
+
+    let mut copy = conn.copy_in("COPY copy_in_invalid FROM STDIN").await?;
+    copy.send(b"1\tAlice\nnot a number\tBob\n").await?;
+
+    let err = copy.finish().await.unwrap_err().to_string();
+    assert!(err.contains("invalid input syntax"), "{}", err);
+
+    // the connection is usable again and nothing was copied
+    assert_eq!(count(&mut conn, "copy_in_invalid").await?, 0);
+
+    // not a COPY FROM STDIN
+    assert!(conn.copy_in("SELECT 1").await.is_err());
+    assert!(conn
+        .copy_in("COPY copy_in_missing FROM STDIN")
+        .await
+        .is_err());
+    assert_eq!(count(&mut conn, "copy_in_invalid").await?, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_aborts_copy_in() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    table(&mut conn, "copy_in_abort").await?;
+
+    let mut copy = conn.copy_in("COPY copy_in_abort FROM STDIN").await?;
+    copy.send(b"1\tAlice\n").await?;
+    copy.abort("changed my mind").await?;
+
+    assert_eq!(count(&mut conn, "copy_in_abort").await?, 0);
+
+    // dropping the copy aborts it as well
+    let mut copy = conn.copy_in("COPY copy_in_abort FROM STDIN").await?;
+    copy.send(b"1\tAlice\n").await?;
+    drop(copy);
+
+    assert_eq!(count(&mut conn, "copy_in_abort").await?, 0);
+
+    // the query API rejects a COPY FROM STDIN instead of waiting for data forever
+    assert!(conn.execute("COPY copy_in_abort FROM STDIN").await.is_err());
+    assert_eq!(count(&mut conn, "copy_in_abort").await?, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_copies_out_text() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    table(&mut conn, "copy_out_text").await?;
+
+    conn.execute("INSERT INTO copy_out_text (id, name) VALUES (1, 'Alice'), (2, NULL)")
+        .await?;
+
+    let chunks: Vec<Bytes> = conn
+        .copy_out("COPY copy_out_text (id, name) TO STDOUT")
+        .try_collect()
+        .await?;
+
+    assert_eq!(chunks.concat(), b"1\tAlice\n2\t\\N\n");
+
+    let chunks: Vec<Bytes> = conn
+        .copy_out("COPY (SELECT name FROM copy_out_text WHERE id = 1) TO STDOUT WITH (FORMAT csv)")
+        .try_collect()
+        .await?;
+
+    assert_eq!(chunks, [Bytes::from_static(b"Alice\n")]);
+
+    // the output of a COPY TO STDOUT through the query API is discarded
+    assert_eq!(conn.execute("COPY copy_out_text TO STDOUT").await?, 2);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_errors_during_copy_out() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // fails on the third row with a division by zero
+    let mut copy =
+        conn.copy_out("COPY (SELECT 1 / (3 - i) FROM generate_series(1, 5) i) TO STDOUT");
+    let mut rows = 0;
+
+    let error = loop {
+        match copy.try_next().await {
+            Ok(Some(_)) => rows += 1,
+            Ok(None) => panic!("expected the copy to fail"),
+            Err(error) => break error,
+        }
+    };
+
+    drop(copy);
+
+    assert!(rows < 3);
+    assert!(error.to_string().contains("division by zero"), "{}", error);
+
+    // not a COPY TO STDOUT
+    assert!(conn.copy_out("SELECT 1").try_next().await.is_err());
+
+    // a copy that is dropped early is discarded and the connection is usable again
+    let mut copy = conn.copy_out("COPY (SELECT generate_series(1, 10000)) TO STDOUT");
+    assert_eq!(copy.try_next().await?, Some(Bytes::from_static(b"1\n")));
+    drop(copy);
+
+    let (value,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
@@ -28,16 +28,26 @@ enum Strong {
     Three,
 }
 
-// TODO: Figure out a good solution for custom type testing
-// Records must map to a custom type
-// Note that all types are types in Postgres
-// #[derive(PartialEq, Debug, sqlx::Type)]
-// #[sqlx(postgres(oid = ?))]
-// struct InventoryItem {
-//     name: String,
-//     supplier_id: Option<i32>,
-//     price: Option<i64>
-// }
+// Without an OID, "strong" enums are matched to a custom enum type by name
+// CREATE TYPE mood AS ENUM ('sad', 'ok', 'happy');
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "mood")]
+#[sqlx(rename_all = "lowercase")]
+enum Mood {
+    Sad,
+    Ok,
+    Happy,
+}
+
+// Without an OID, records are matched to a composite type by name; fields are encoded and
+// decoded in order of declaration
+// CREATE TYPE address AS (street TEXT, city TEXT);
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename = "address")]
+struct Address {
+    street: String,
+    city: Option<String>,
+}
 
 test_type!(transparent(
     Postgres,
@@ -62,29 +72,65 @@ test_type!(strong_enum(
     "'four'::text" == Strong::Three
 ));
 
-// TODO: Figure out a good solution for custom type testing
-// test_type!(record_pg_config(
-//     Postgres,
-//     InventoryItem,
-//     "(SELECT ROW('fuzzy dice', 42, 199)::inventory_item)"
-//         == InventoryItem {
-//             name: "fuzzy dice".to_owned(),
-//             supplier_id: Some(42),
-//             price: Some(199),
-//         },
-//     "(SELECT '(\"fuuzy dice\",,)'::pg_config)"
-//         == InventoryItem {
-//             name: "fuzzy dice".to_owned(),
-//             supplier_id: None,
-//             price: None,
-//         },
-//     "(SELECT '(\"\",,2350)'::pg_config)"
-//         == InventoryItem {
-//             name: "".to_owned(),
-//             supplier_id: None,
-//             price: Some(2350)
-//         }
-// ));
+test_type!(mood_enum(
+    Postgres,
+    Mood,
+    "'sad'::mood" == Mood::Sad,
+    "'ok'::mood" == Mood::Ok,
+    "'happy'::mood" == Mood::Happy
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_unknown_enum_label_fails_to_decode() -> anyhow::Result<()> {
+    use sqlx::prelude::*;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let res: Result<(Mood,), _> = sqlx::query_as("SELECT 'angry'::text")
+        .fetch_one(&mut conn)
+        .await;
+
+    match res {
+        Err(sqlx::Error::Decode(err)) => assert!(err.to_string().contains("\"angry\"")),
+        res => panic!("expected a decode error, got {:?}", res),
+    }
+
+    Ok(())
+}
+
+test_type!(address_record(
+    Postgres,
+    Address,
+    "SELECT {0}::address is not distinct from $1::address, $2::address::text as _1, {0} as _2, $3::address as _3",
+    "ROW('1 Main St', 'Springfield')::address"
+        == Address {
+            street: "1 Main St".to_owned(),
+            city: Some("Springfield".to_owned()),
+        },
+    "'(\"\",)'::address"
+        == Address {
+            street: "".to_owned(),
+            city: None,
+        }
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_null_field_of_record_fails_to_decode() -> anyhow::Result<()> {
+    use sqlx::prelude::*;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // `street` is not an `Option`
+    let res: Result<(Address,), _> = sqlx::query_as("SELECT ROW(NULL, 'Springfield')::address")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
 
 #[cfg(feature = "macros")]
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
@@ -125,7 +171,7 @@ async fn test_from_row() -> anyhow::Result<()> {
     .bind(1_i32)
     .fetch(&mut conn);
 
-    let account = RefAccount::from_row(cursor.next().await?.unwrap())?;
+    let account = RefAccount::from_row(&cursor.next().await?.unwrap())?;
 
     assert_eq!(account.id, 1);
     assert_eq!(account.name, "Herp Derpinson");
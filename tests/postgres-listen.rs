@@ -0,0 +1,132 @@
+//! Tests for `LISTEN` and `NOTIFY` through `PgListener`.
+
+use futures::{future, TryStreamExt};
+use sqlx::postgres::{PgListener, PgQueryAs};
+use sqlx::{Executor, Postgres};
+use sqlx_test::new;
+
+async fn listener() -> anyhow::Result<PgListener> {
+    Ok(PgListener::new(&dotenv::var("DATABASE_URL")?).await?)
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_receives_notifications() -> anyhow::Result<()> {
+    let mut listener = listener().await?;
+
+    // a channel name is an identifier and is quoted as given
+    listener.listen("sqlx.Test-Channel").await?;
+
+    let mut conn = new::<Postgres>().await?;
+    conn.execute(r#"NOTIFY "sqlx.Test-Channel", 'hello'"#)
+        .await?;
+
+    let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let notification = listener.recv().await?;
+
+    assert_eq!(notification.channel(), "sqlx.Test-Channel");
+    assert_eq!(notification.payload(), "hello");
+    assert_eq!(notification.process_id(), pid as u32);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_streams_notifications_from_many_channels() -> anyhow::Result<()> {
+    let mut listener = listener().await?;
+    listener
+        .listen_all(vec!["sqlx_stream_0", "sqlx_stream_1"])
+        .await?;
+
+    listener.unlisten("sqlx_stream_1").await?;
+
+    let mut conn = new::<Postgres>().await?;
+    conn.execute(
+        r#"
+NOTIFY sqlx_stream_1, 'ignored';
+NOTIFY sqlx_stream_0, 'first';
+NOTIFY sqlx_stream_0;
+        "#,
+    )
+    .await?;
+
+    let mut stream = listener.into_stream();
+
+    let notification = stream.try_next().await?.unwrap();
+    assert_eq!(notification.channel(), "sqlx_stream_0");
+    assert_eq!(notification.payload(), "first");
+
+    let notification = stream.try_next().await?.unwrap();
+    assert_eq!(notification.channel(), "sqlx_stream_0");
+    assert_eq!(notification.payload(), "");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_buffers_notifications_received_by_queries() -> anyhow::Result<()> {
+    let mut listener = listener().await?;
+    listener.listen("sqlx_buffered").await?;
+
+    let mut conn = new::<Postgres>().await?;
+    conn.execute("NOTIFY sqlx_buffered, 'while busy'").await?;
+
+    // the notification arrives while the listener is used to run a query
+    listener.execute("SELECT pg_sleep(0.2)").await?;
+
+    let notification = listener.recv().await?;
+    assert_eq!(notification.payload(), "while busy");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_resubscribes_after_the_connection_is_lost() -> anyhow::Result<()> {
+    let mut listener = listener().await?;
+    listener.listen("sqlx_reconnect").await?;
+
+    let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&mut listener)
+        .await?;
+
+    let mut conn = new::<Postgres>().await?;
+    sqlx::query("SELECT pg_terminate_backend($1)")
+        .bind(pid)
+        .execute(&mut conn)
+        .await?;
+
+    // keep notifying until the new connection has subscribed again
+    let notify = Box::pin(async {
+        loop {
+            conn.execute("SELECT pg_sleep(0.1); NOTIFY sqlx_reconnect, 'again'")
+                .await?;
+        }
+
+        #[allow(unreachable_code)]
+        Ok::<_, sqlx::Error<Postgres>>(())
+    });
+
+    let notification = match future::select(Box::pin(listener.recv()), notify).await {
+        future::Either::Left((notification, _)) => notification?.payload().to_owned(),
+        future::Either::Right((res, _)) => {
+            res?;
+            unreachable!()
+        }
+    };
+
+    assert_eq!(notification, "again");
+
+    let (new_pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&mut listener)
+        .await?;
+
+    assert_ne!(pid, new_pid);
+
+    Ok(())
+}
@@ -22,7 +22,7 @@ async fn test_query() -> anyhow::Result<()> {
 async fn test_no_result() -> anyhow::Result<()> {
     let mut conn = connect().await?;
 
-    let _ = sqlx::query!("DELETE FROM pg_enum")
+    let _ = sqlx::query!("DELETE FROM accounts WHERE false")
         .execute(&mut conn)
         .await?;
 
@@ -212,6 +212,21 @@ async fn test_array_from_slice() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_query_oid() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let result = sqlx::query!("SELECT oid FROM pg_type WHERE typname = 'oid'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let oid: u32 = result.oid;
+    assert_eq!(oid, 26);
+
+    Ok(())
+}
+
 async fn connect() -> anyhow::Result<PgConnection> {
     let _ = dotenv::dotenv();
     let _ = env_logger::try_init();
@@ -0,0 +1,58 @@
+//! Tests for receiving notices through `PgConnection::on_notice`.
+
+use std::sync::{Arc, Mutex};
+
+use sqlx::postgres::PgQueryAs;
+use sqlx::{Executor, Postgres};
+use sqlx_test::new;
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_receives_notices() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let notices = Arc::new(Mutex::new(Vec::new()));
+
+    // nothing is received before there is a handler
+    conn.execute("DO $$ BEGIN RAISE NOTICE 'unheard'; END $$")
+        .await?;
+
+    let received = Arc::clone(&notices);
+    conn.on_notice(move |notice| {
+        received.lock().unwrap().push(format!(
+            "{} {} {} {:?}",
+            notice.severity(),
+            notice.code(),
+            notice.message(),
+            notice.hint()
+        ));
+    });
+
+    conn.execute(
+        r#"
+CREATE FUNCTION pg_temp.raise_notices(n INTEGER) RETURNS INTEGER AS $$
+BEGIN
+    RAISE NOTICE 'hello %', n;
+    RAISE WARNING 'careful' USING HINT = 'slow down';
+    RETURN n;
+END
+$$ LANGUAGE plpgsql
+        "#,
+    )
+    .await?;
+
+    let (n,): (i32,) = sqlx::query_as("SELECT pg_temp.raise_notices($1)")
+        .bind(42_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(n, 42);
+    assert_eq!(
+        *notices.lock().unwrap(),
+        [
+            "NOTICE 00000 hello 42 None",
+            "WARNING 01000 careful Some(\"slow down\")"
+        ]
+    );
+
+    Ok(())
+}
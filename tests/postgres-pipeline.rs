@@ -0,0 +1,117 @@
+//! Tests for pipelined queries through `PgConnection::pipeline`.
+
+use sqlx::postgres::{PgQueryAs, PgSqlState};
+use sqlx::{Executor, PgConnection, Postgres};
+use sqlx_test::new;
+
+async fn count(conn: &mut PgConnection, query: &str) -> anyhow::Result<i64> {
+    let (count,): (i64,) = sqlx::query_as(query).fetch_one(conn).await?;
+
+    Ok(count)
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_pipelines_queries_in_one_round_trip() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE pipelined (id INTEGER, txid BIGINT)")
+        .await?;
+
+    // every [Sync] ends the implicit transaction of the queries before it, so the queries
+    // that are sent in the same round-trip share a transaction id
+    for id in 0..5 {
+        sqlx::query("INSERT INTO pipelined VALUES ($1, txid_current())")
+            .bind(id)
+            .execute(&mut conn)
+            .await?;
+    }
+
+    assert_eq!(
+        count(&mut conn, "SELECT COUNT(DISTINCT txid) FROM pipelined").await?,
+        5
+    );
+
+    conn.execute("TRUNCATE pipelined").await?;
+
+    let mut pipeline = conn.pipeline();
+
+    for id in 0..5 {
+        pipeline.push(sqlx::query("INSERT INTO pipelined VALUES ($1, txid_current())").bind(id));
+    }
+
+    pipeline.push("UPDATE pipelined SET id = id + 10 WHERE id < 2");
+
+    assert_eq!(pipeline.execute().await?, [1, 1, 1, 1, 1, 2]);
+
+    assert_eq!(
+        count(&mut conn, "SELECT COUNT(DISTINCT txid) FROM pipelined").await?,
+        1
+    );
+
+    assert_eq!(
+        count(&mut conn, "SELECT SUM(id)::int8 FROM pipelined").await?,
+        30
+    );
+
+    assert_eq!(conn.pipeline().execute().await?, Vec::<u64>::new());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_skips_queries_after_an_error() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE pipelined_error (id INTEGER)")
+        .await?;
+
+    let res = conn
+        .pipeline()
+        .push(sqlx::query("INSERT INTO pipelined_error VALUES ($1)").bind(1))
+        .push(sqlx::query("SELECT 1 / $1").bind(0))
+        .push(sqlx::query("INSERT INTO pipelined_error VALUES ($1)").bind(2))
+        .execute()
+        .await;
+
+    match res {
+        Err(sqlx::Error::Database(err)) => {
+            assert_eq!(err.sql_state(), Some(PgSqlState::DivisionByZero));
+            assert_eq!(err.query(), Some("SELECT 1 / $1"));
+        }
+
+        res => panic!("expected the pipeline to fail; got {:?}", res),
+    }
+
+    // the queries ran in a transaction that was rolled back
+    assert_eq!(
+        count(&mut conn, "SELECT COUNT(*) FROM pipelined_error").await?,
+        0
+    );
+
+    // a statement that failed to prepare, and those after it, are prepared again
+    let res = conn
+        .pipeline()
+        .push(sqlx::query("SELECT $1::int4 + 1").bind(1))
+        .push("SELEC 1")
+        .push(sqlx::query("SELECT $1::int4 + 2").bind(1))
+        .execute()
+        .await;
+
+    match res {
+        Err(sqlx::Error::Database(err)) => {
+            assert_eq!(err.sql_state(), Some(PgSqlState::SyntaxError));
+        }
+
+        res => panic!("expected the pipeline to fail; got {:?}", res),
+    }
+
+    for (query, expected) in &[("SELECT $1::int4 + 1", 2), ("SELECT $1::int4 + 2", 3)] {
+        let (value,): (i32,) = sqlx::query_as(query).bind(1).fetch_one(&mut conn).await?;
+
+        assert_eq!(value, *expected);
+    }
+
+    Ok(())
+}
@@ -69,3 +69,94 @@ SELECT id, text FROM _sqlx_test_postgres_5112;
 
     Ok(())
 }
+
+/// Test that the text of a failing query is attached to the error from the database,
+/// whether it fails on execution or part way through its rows.
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_error_includes_query() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let err = conn.execute("SELEC 1").await.unwrap_err();
+
+    match &err {
+        sqlx::Error::Database(err) => assert_eq!(err.query(), Some("SELEC 1")),
+        err => panic!("expected a database error; got {:?}", err),
+    }
+
+    assert!(err.to_string().ends_with("; query: SELEC 1"), "{}", err);
+
+    let query = "SELECT 1 / (2 - i) FROM generate_series(1, 3) i";
+    let mut cursor = conn.fetch(query);
+
+    let err = loop {
+        if let Err(err) = cursor.next().await {
+            break err;
+        }
+    };
+
+    assert!(err.to_string().contains(query), "{}", err);
+
+    Ok(())
+}
+
+/// Test that a script of several statements runs in one simple query, without preparing
+/// any of them, and that the rows of its last statement are returned.
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_create_insert_select_script() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor = conn.fetch(
+        "
+CREATE TEMPORARY TABLE _sqlx_test_postgres_script (id INT NOT NULL);
+INSERT INTO _sqlx_test_postgres_script (id) VALUES (1), (2), (3);
+SELECT id FROM _sqlx_test_postgres_script ORDER BY id;
+    ",
+    );
+
+    let mut ids = Vec::new();
+
+    while let Some(row) = cursor.next().await? {
+        ids.push(row.try_get::<i32, _>("id")?);
+    }
+
+    assert_eq!(ids, [1, 2, 3]);
+
+    Ok(())
+}
+
+/// Test that an error in the middle of a script is returned, that the statements after it
+/// are not run, and that the connection can be used again afterwards.
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_error_in_script() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE _sqlx_test_postgres_script_error (id INT NOT NULL)")
+        .await?;
+
+    let err = conn
+        .execute(
+            "
+INSERT INTO _sqlx_test_postgres_script_error (id) VALUES (1);
+SELECT 1 / 0;
+INSERT INTO _sqlx_test_postgres_script_error (id) VALUES (2);
+    ",
+        )
+        .await
+        .unwrap_err();
+
+    match &err {
+        sqlx::Error::Database(err) => assert_eq!(err.code(), Some("22012")),
+        err => panic!("expected a database error; got {:?}", err),
+    }
+
+    // the statements of a simple query run in one implicit transaction, which is rolled back
+    let mut cursor = conn.fetch("SELECT COUNT(*) FROM _sqlx_test_postgres_script_error");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<i64, _>(0)?, 0);
+
+    Ok(())
+}
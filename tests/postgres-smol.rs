@@ -0,0 +1,56 @@
+//! Smoke test of the `runtime-smol` feature; smol has no test attribute so each test runs
+//! its own executor.
+
+extern crate smol_ as smol;
+
+use std::time::Duration;
+
+use sqlx::postgres::{PgPool, PgQueryAs};
+use sqlx::{Connection, Executor, Postgres};
+use sqlx_test::new;
+
+#[test]
+fn it_connects() -> anyhow::Result<()> {
+    smol::run(async {
+        let mut conn = new::<Postgres>().await?;
+
+        let (value,): (i32,) = sqlx::query_as("SELECT $1")
+            .bind(1_i32)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(value, 1);
+
+        conn.close().await?;
+
+        Ok(())
+    })
+}
+
+#[test]
+fn it_uses_a_pool() -> anyhow::Result<()> {
+    smol::run(async {
+        // the reaper is a spawned task that sleeps between runs
+        let pool = PgPool::builder()
+            .max_size(1)
+            .connect_timeout(Duration::from_secs(1))
+            .idle_timeout(Duration::from_secs(1))
+            .build(&dotenv::var("DATABASE_URL")?)
+            .await?;
+
+        let mut conn = pool.acquire().await?;
+        conn.execute("SELECT 1").await?;
+
+        // waiting for the only connection times out
+        assert!(pool.acquire().await.is_err());
+
+        drop(conn);
+
+        let (value,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(&pool).await?;
+        assert_eq!(value, 1);
+
+        pool.close().await;
+
+        Ok(())
+    })
+}
@@ -4,10 +4,14 @@ use std::sync::atomic::{AtomicU32, Ordering};
 
 use sqlx::decode::Decode;
 use sqlx::encode::Encode;
-use sqlx::postgres::types::raw::{PgNumeric, PgNumericSign, PgRecordDecoder, PgRecordEncoder};
+use sqlx::postgres::types::raw::{PgRecordDecoder, PgRecordEncoder};
+use sqlx::postgres::types::{
+    PgBits, PgCheckedInt, PgLsn, PgLtree, PgMacAddr, PgMacAddr8, PgMoney, PgNumeric, PgNumericSign,
+    PgPoint, PgRange, PgTsQuery, PgTsVector, RangeBound,
+};
 use sqlx::postgres::{PgQueryAs, PgTypeInfo, PgValue};
 use sqlx::{Cursor, Executor, Postgres, Row, Type};
-use sqlx_test::{new, test_prepared_type, test_type};
+use sqlx_test::{new, test_prepared_type, test_type, test_unprepared_type};
 
 test_type!(null(
     Postgres,
@@ -15,6 +19,32 @@ test_type!(null(
     "NULL" == None::<i16>
 ));
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_encode_none_as_null() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE nullable (id INT4 PRIMARY KEY, value TEXT)")
+        .await?;
+
+    sqlx::query("INSERT INTO nullable (id, value) VALUES ($1, $2), ($3, $4)")
+        .bind(1_i32)
+        .bind(None::<String>)
+        .bind(2_i32)
+        .bind(Some("two"))
+        .execute(&mut conn)
+        .await?;
+
+    let rows: Vec<(i32, bool)> =
+        sqlx::query_as("SELECT id, value IS NULL FROM nullable ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(rows, [(1, true), (2, false)]);
+
+    Ok(())
+}
+
 test_type!(bool(
     Postgres,
     bool,
@@ -22,10 +52,24 @@ test_type!(bool(
     "true::boolean" == true
 ));
 
+test_type!(char(
+    Postgres,
+    i8,
+    "'A'::\"char\"" == 65_i8,
+    "'\\377'::\"char\"" == -1_i8
+));
+
 test_type!(i16(Postgres, i16, "821::smallint" == 821_i16));
 test_type!(i32(Postgres, i32, "94101::int" == 94101_i32));
 test_type!(i64(Postgres, i64, "9358295312::bigint" == 9358295312_i64));
 
+test_type!(oid(
+    Postgres,
+    u32,
+    "'pg_class'::regclass::oid" == 1259_u32,
+    "4294967295::oid" == u32::max_value()
+));
+
 test_type!(f32(Postgres, f32, "9419.122::real" == 9419.122_f32));
 test_type!(f64(
     Postgres,
@@ -33,6 +77,29 @@ test_type!(f64(
     "939399419.1225182::double precision" == 939399419.1225182_f64
 ));
 
+test_type!(non_zero_i32(
+    Postgres,
+    std::num::NonZeroI32,
+    "-5::int4" == std::num::NonZeroI32::new(-5).unwrap(),
+    "2147483647::int4" == std::num::NonZeroI32::new(i32::max_value()).unwrap()
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_zero_fails_to_decode_as_non_zero() -> anyhow::Result<()> {
+    use std::num::{NonZeroI64, NonZeroU16};
+
+    let mut conn = new::<Postgres>().await?;
+
+    let res: Result<(NonZeroI64,), _> = sqlx::query_as("SELECT 0::int8").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    let res: Result<(NonZeroU16,), _> = sqlx::query_as("SELECT 0::int4").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
 test_type!(string(
     Postgres,
     String,
@@ -40,6 +107,93 @@ test_type!(string(
     "''" == ""
 ));
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_cow_str() -> anyhow::Result<()> {
+    use sqlx::postgres::PgRow;
+    use std::borrow::Cow;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // an owned and a borrowed value are both encoded as TEXT
+    let mut cursor = sqlx::query("SELECT $1 || $2")
+        .bind(Cow::<str>::Owned("Hello, ".to_owned()))
+        .bind(Cow::Borrowed("World"))
+        .fetch(&mut conn);
+
+    let row: PgRow = cursor.next().await?.unwrap();
+    let value: Cow<str> = row.try_get(0)?;
+
+    // the value is borrowed from the row
+    assert!(matches!(value, Cow::Borrowed("Hello, World")));
+
+    // and may be kept past it once owned
+    let value = value.into_owned();
+    drop(cursor);
+
+    assert_eq!(value, "Hello, World");
+
+    // the text format of the simple query protocol
+    let mut cursor = conn.fetch("SELECT 'Grüße'::text");
+    let row: PgRow = cursor.next().await?.unwrap();
+    let value: Cow<str> = row.try_get(0)?;
+
+    assert!(matches!(value, Cow::Borrowed("Grüße")));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_decode_any() -> anyhow::Result<()> {
+    use sqlx::postgres::PgRow;
+    use sqlx::types::AnyValue;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let query = "SELECT NULL::int4, TRUE, 2::int2, 3::int4, 4::int8, 1.5::float4, 2.5::float8, \
+                 'text'::text, '\\xDEAD'::bytea, 26::oid";
+
+    // the binary format of prepared queries and the text format of simple queries
+    for &prepared in &[true, false] {
+        let mut cursor = if prepared {
+            sqlx::query(query).fetch(&mut conn)
+        } else {
+            conn.fetch(query)
+        };
+
+        let row: PgRow = cursor.next().await?.unwrap();
+        let values = (0..row.len())
+            .map(|index| row.try_get_any(index))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // a type without a variant of its own is kept as it was received
+        let bytes = if prepared {
+            vec![0, 0, 0, 26]
+        } else {
+            b"26".to_vec()
+        };
+
+        assert_eq!(
+            values,
+            [
+                AnyValue::Null,
+                AnyValue::Bool(true),
+                AnyValue::Int(2),
+                AnyValue::Int(3),
+                AnyValue::Int(4),
+                AnyValue::Float(1.5),
+                AnyValue::Float(2.5),
+                AnyValue::Text("text".to_owned()),
+                AnyValue::Bytes(vec![0xDE, 0xAD]),
+                AnyValue::Other { oid: 26, bytes },
+            ]
+        );
+    }
+
+    Ok(())
+}
+
 test_type!(bytea(
     Postgres,
     Vec<u8>,
@@ -51,8 +205,383 @@ test_type!(bytea(
         == vec![0_u8, 0, 0, 0, 0x52]
 ));
 
-// PgNumeric only works on the wire protocol
-test_prepared_type!(numeric(
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_prepared_bytea_slice() -> anyhow::Result<()> {
+    use sqlx::postgres::PgRow;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let blob: Vec<u8> = (0..=255).cycle().take(1 << 20).collect();
+
+    // the slice borrows from the row without a copy
+    let mut cursor = sqlx::query("SELECT $1::bytea").bind(&blob).fetch(&mut conn);
+    let row: PgRow = cursor.next().await?.unwrap();
+    let value: &[u8] = row.try_get(0)?;
+
+    assert_eq!(value, &*blob);
+
+    drop(cursor);
+
+    // a BYTEA in the text format of the simple query protocol is hex-encoded and can't be
+    // borrowed
+    let mut cursor = conn.fetch("SELECT '\\x00ff'::bytea");
+    let row: PgRow = cursor.next().await?.unwrap();
+
+    assert!(matches!(
+        row.try_get::<&[u8], _>(0),
+        Err(sqlx::Error::Decode(_))
+    ));
+    assert_eq!(row.try_get::<Vec<u8>, _>(0)?, [0x00, 0xFF]);
+
+    Ok(())
+}
+
+#[cfg(feature = "bytes")]
+test_type!(bytea_bytes(
+    Postgres,
+    sqlx::types::Bytes,
+    "E'\\\\xDEADBEEF'::bytea" == sqlx::types::Bytes::from_static(&[0xDE, 0xAD, 0xBE, 0xEF]),
+    "E'\\\\x'::bytea" == sqlx::types::Bytes::new(),
+    "E'\\\\x00DE0000AD00'::bytea" == sqlx::types::Bytes::from_static(&[0, 0xDE, 0, 0, 0xAD, 0])
+));
+
+test_type!(std_duration(
+    Postgres,
+    std::time::Duration,
+    "INTERVAL '1 day 2 hours'" == std::time::Duration::from_secs(26 * 3600),
+    "INTERVAL '0.5 seconds'" == std::time::Duration::from_millis(500),
+    "INTERVAL '0'" == std::time::Duration::from_secs(0)
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_interval_with_months_fails_to_decode() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res: Result<(std::time::Duration,), _> = sqlx::query_as("SELECT INTERVAL '1 month'")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
+test_type!(money(
+    Postgres,
+    PgMoney,
+    "'$1,234.56'::money" == PgMoney(123456),
+    "'-12.5'::money" == PgMoney(-1250),
+    "'0'::money" == PgMoney(0)
+));
+
+test_type!(u16(
+    Postgres,
+    u16,
+    "0::int4" == 0_u16,
+    "65535::int4" == u16::MAX
+));
+
+test_type!(u32(
+    Postgres,
+    u32,
+    "0::int8" == 0_u32,
+    "4294967295::int8" == u32::MAX
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_decodes_integers_of_other_widths() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (narrow, wide): (PgCheckedInt<i32>, PgCheckedInt<i64>) =
+        sqlx::query_as("SELECT 2147483647::int8, 32767::int2")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(narrow, PgCheckedInt(i32::MAX));
+    assert_eq!(wide, PgCheckedInt(32767));
+
+    let res = sqlx::query_as::<_, (PgCheckedInt<i32>,)>("SELECT 2147483648::int8")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    let res = sqlx::query_as::<_, (PgCheckedInt<i16>,)>("SELECT -32769::int4")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    // without `PgCheckedInt`, an integer is only decoded from a column of its own width
+    let res = sqlx::query_as::<_, (i32,)>("SELECT 1::int8")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_checks_the_range_of_unsigned_integers() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (value,): (u32,) = sqlx::query_as("SELECT 4294967295::int8")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, u32::MAX);
+
+    let res = sqlx::query_as::<_, (u32,)>("SELECT 4294967296::int8")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    let res = sqlx::query_as::<_, (u16,)>("SELECT 65536::int4")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    let res = sqlx::query_as::<_, (u16,)>("SELECT -1::int4")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    let res = sqlx::query_as::<_, (u32,)>("SELECT $1::int8")
+        .bind(-1_i64)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    // 4 bytes of an INT4 are not read as an OID
+    let res = sqlx::query_as::<_, (u32,)>("SELECT $1::int4")
+        .bind(-1_i32)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+test_type!(macaddr(
+    Postgres,
+    PgMacAddr,
+    "'08:00:2b:01:02:03'::macaddr" == PgMacAddr([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03])
+));
+
+test_type!(macaddr8(
+    Postgres,
+    PgMacAddr8,
+    "'08:00:2b:01:02:03:04:05'::macaddr8"
+        == PgMacAddr8([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03, 0x04, 0x05])
+));
+
+test_type!(bits(
+    Postgres,
+    PgBits,
+    "B'101'" == "101".parse::<PgBits>().unwrap(),
+    "B'10110011'::varbit" == PgBits::from_bytes(&[0b1011_0011], 8),
+    "B'101100111'::bit(9)" == "101100111".parse::<PgBits>().unwrap(),
+    "''::varbit" == PgBits::new()
+));
+
+test_type!(ip_addr(
+    Postgres,
+    std::net::IpAddr,
+    "'127.0.0.1'::inet" == std::net::IpAddr::from([127, 0, 0, 1]),
+    "'::ffff:1.2.3.0'::inet" == "::ffff:1.2.3.0".parse::<std::net::IpAddr>().unwrap(),
+    "'10.0.0.1/32'::cidr" == std::net::IpAddr::from([10, 0, 0, 1])
+));
+
+test_type!(ipv4_addr(
+    Postgres,
+    std::net::Ipv4Addr,
+    "'8.8.8.8'::inet" == std::net::Ipv4Addr::new(8, 8, 8, 8),
+    "'0.0.0.0'::inet" == std::net::Ipv4Addr::UNSPECIFIED
+));
+
+test_type!(ipv6_addr(
+    Postgres,
+    std::net::Ipv6Addr,
+    "'2001:4f8:3:ba::1'::inet" == "2001:4f8:3:ba::1".parse::<std::net::Ipv6Addr>().unwrap(),
+    "'::1'::inet" == std::net::Ipv6Addr::LOCALHOST
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rejects_a_network_as_an_ip_addr() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res = sqlx::query_as::<_, (std::net::IpAddr,)>("SELECT '8.8.8.8/24'::inet")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    let res = sqlx::query_as::<_, (std::net::IpAddr,)>("SELECT '2001:4f8:3:ba::/64'::cidr")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    // an IPv6 address is not an IPv4 address
+    let res = sqlx::query_as::<_, (std::net::Ipv4Addr,)>("SELECT '::1'::inet")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+test_type!(pg_lsn(
+    Postgres,
+    PgLsn,
+    "'16/B374D848'::pg_lsn" == PgLsn(0x16_B374_D848),
+    "'0/0'::pg_lsn" == PgLsn(0)
+));
+
+// TSVECTOR and TSQUERY can only be decoded
+test_unprepared_type!(tsvector(
+    Postgres,
+    PgTsVector,
+    "to_tsvector('english', 'the quick fox')" == PgTsVector("'fox':3 'quick':2".to_owned())
+));
+
+test_unprepared_type!(tsquery(
+    Postgres,
+    PgTsQuery,
+    "to_tsquery('english', 'fox & !dog')" == PgTsQuery("'fox' & !'dog'".to_owned())
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_prepared_fts_matches_text_output() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    for tsvector in &[
+        "to_tsvector('english', 'the quick fox')",
+        "'a:1A,2B b:3C,4 it''s back\\\\slash'::tsvector",
+        "''::tsvector",
+    ] {
+        let query = format!("SELECT {0}, {0}::text", tsvector);
+        let (value, text): (PgTsVector, String) =
+            sqlx::query_as(&query).fetch_one(&mut conn).await?;
+
+        assert_eq!(value.0, text);
+    }
+
+    for tsquery in &[
+        "to_tsquery('english', 'fox & !dog')",
+        "'a:AB & (b:* | !c) <-> d <3> (e <-> f)'::tsquery",
+        "'!(a & b) | (c <-> (d <-> e))'::tsquery",
+        "''::tsquery",
+    ] {
+        let query = format!("SELECT {0}, {0}::text", tsquery);
+        let (value, text): (PgTsQuery, String) =
+            sqlx::query_as(&query).fetch_one(&mut conn).await?;
+
+        assert_eq!(value.0, text);
+    }
+
+    Ok(())
+}
+
+// POINT has no equality operator; `~=` is "same as"
+test_type!(point(
+    Postgres,
+    PgPoint,
+    "SELECT {0} ~= $1, $2::text as _1, {0} as _2, $3 as _3",
+    "'(1.5, 2.5)'::point" == PgPoint { x: 1.5, y: 2.5 },
+    "'(-0.25, 0)'::point" == PgPoint { x: -0.25, y: 0.0 }
+));
+
+test_type!(point_vec(
+    Postgres,
+    Vec<PgPoint>,
+    "SELECT {0}::text = $1::text, $2::text as _1, {0} as _2, $3 as _3",
+    "'{\"(1.5,2.5)\",\"(3,4)\"}'::point[]"
+        == vec![PgPoint { x: 1.5, y: 2.5 }, PgPoint { x: 3.0, y: 4.0 }]
+));
+
+// discrete ranges are always returned by Postgres in the `[lower,upper)` form
+test_type!(int4range(
+    Postgres,
+    PgRange<i32>,
+    "'empty'::int4range" == PgRange::<i32>::Empty,
+    "'[1,10)'::int4range" == PgRange::new(RangeBound::Inclusive(1), RangeBound::Exclusive(10)),
+    "'(,5]'::int4range" == PgRange::new(RangeBound::Unbounded, RangeBound::Exclusive(6)),
+    "'[3,)'::int4range" == PgRange::new(RangeBound::Inclusive(3), RangeBound::Unbounded),
+    "'(,)'::int4range" == PgRange::<i32>::new(RangeBound::Unbounded, RangeBound::Unbounded)
+));
+
+test_type!(int8range(
+    Postgres,
+    PgRange<i64>,
+    "'empty'::int8range" == PgRange::<i64>::Empty,
+    "'[-5,5000000000)'::int8range"
+        == PgRange::new(RangeBound::Inclusive(-5_i64), RangeBound::Exclusive(5_000_000_000_i64)),
+    "'(,5]'::int8range" == PgRange::new(RangeBound::Unbounded, RangeBound::Exclusive(6_i64))
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_encode_inclusive_upper_range() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (is_equal,): (bool,) = sqlx::query_as("SELECT $1 = '(,5]'::int8range")
+        .bind(PgRange::new(
+            RangeBound::Unbounded,
+            RangeBound::Inclusive(5_i64),
+        ))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(is_equal);
+
+    Ok(())
+}
+
+// HSTORE has no fixed OID so the bind parameters need to be cast for Postgres to infer it
+test_type!(hstore(
+    Postgres,
+    std::collections::HashMap<String, Option<String>>,
+    "SELECT {0} is not distinct from $1::hstore, $2::hstore::text as _1, {0} as _2, $3::hstore as _3",
+    "'a=>1, b=>NULL'::hstore"
+        == vec![("a".to_owned(), Some("1".to_owned())), ("b".to_owned(), None)]
+            .into_iter()
+            .collect::<std::collections::HashMap<_, _>>(),
+    "''::hstore" == std::collections::HashMap::<String, Option<String>>::new()
+));
+
+// LTREE has no fixed OID so the bind parameters need to be cast for Postgres to infer it
+test_type!(ltree(
+    Postgres,
+    PgLtree,
+    "SELECT {0} = $1::ltree, $2::ltree::text as _1, {0} as _2, $3::ltree as _3",
+    "'Top.Science.Astronomy'::ltree" == PgLtree::new("Top.Science.Astronomy").unwrap(),
+    "''::ltree" == PgLtree::new("").unwrap()
+));
+
+// XML has no equality operator and must be cast explicitly from TEXT
+test_type!(xml(
+    Postgres,
+    String,
+    "SELECT {0}::text is not distinct from $1, $2::text as _1, {0} as _2, $3::xml as _3",
+    "'<a/>'::xml" == "<a/>",
+    "'<a><b>text</b></a>'::xml" == "<a><b>text</b></a>"
+));
+
+test_type!(numeric(
     Postgres,
     PgNumeric,
     "0::numeric"
@@ -111,9 +640,32 @@ test_prepared_type!(numeric(
             scale: 2,
             digits: vec![12, 3400]
         },
+    "(-12.34)::numeric"
+        == PgNumeric::Number {
+            sign: PgNumericSign::Negative,
+            weight: 0,
+            scale: 2,
+            digits: vec![12, 3400]
+        },
+    "1.5000::numeric"
+        == PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            weight: 0,
+            scale: 4,
+            digits: vec![1, 5000]
+        },
     "'NaN'::numeric" == PgNumeric::NotANumber,
 ));
 
+test_type!(numeric_as_f64(
+    Postgres,
+    f64,
+    "(-12.34)::numeric" == -12.34_f64,
+    "1.5000::numeric" == 1.5_f64,
+    "10000.0001::numeric" == 10000.0001_f64,
+    "0.00::numeric" == 0_f64
+));
+
 #[cfg(feature = "bigdecimal")]
 test_type!(decimal(
     Postgres,
@@ -126,6 +678,63 @@ test_type!(decimal(
     "12345.6789::numeric" == "12345.6789".parse::<sqlx::types::BigDecimal>().unwrap(),
 ));
 
+#[cfg(feature = "bigdecimal")]
+test_type!(decimal_vec(
+    Postgres,
+    Vec<sqlx::types::BigDecimal>,
+    "'{1, 0.01234, -12345.6789}'::numeric[]"
+        == vec![
+            "1".parse::<sqlx::types::BigDecimal>().unwrap(),
+            "0.01234".parse::<sqlx::types::BigDecimal>().unwrap(),
+            "-12345.6789".parse::<sqlx::types::BigDecimal>().unwrap()
+        ],
+    "'{}'::numeric[]" == Vec::<sqlx::types::BigDecimal>::new()
+));
+
+#[cfg(feature = "rust_decimal")]
+test_type!(rust_decimal(
+    Postgres,
+    sqlx::types::Decimal,
+    "1::numeric" == "1".parse::<sqlx::types::Decimal>().unwrap(),
+    "10000::numeric" == "10000".parse::<sqlx::types::Decimal>().unwrap(),
+    "0.1::numeric" == "0.1".parse::<sqlx::types::Decimal>().unwrap(),
+    "(-0.01234)::numeric" == "-0.01234".parse::<sqlx::types::Decimal>().unwrap(),
+    "12345.6789::numeric" == "12345.6789".parse::<sqlx::types::Decimal>().unwrap(),
+    "1.5000::numeric" == "1.5000".parse::<sqlx::types::Decimal>().unwrap(),
+    "79228162514264337593543950335::numeric"
+        == "79228162514264337593543950335"
+            .parse::<sqlx::types::Decimal>()
+            .unwrap(),
+));
+
+#[cfg(feature = "rust_decimal")]
+test_type!(rust_decimal_vec(
+    Postgres,
+    Vec<sqlx::types::Decimal>,
+    "'{1, -0.01234, 1.5000}'::numeric[]"
+        == vec![
+            "1".parse::<sqlx::types::Decimal>().unwrap(),
+            "-0.01234".parse::<sqlx::types::Decimal>().unwrap(),
+            "1.5000".parse::<sqlx::types::Decimal>().unwrap()
+        ]
+));
+
+#[cfg(feature = "rust_decimal")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_rust_decimal_overflow_fails_to_decode() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res: Result<(sqlx::types::Decimal,), _> =
+        sqlx::query_as("SELECT 79228162514264337593543950336::numeric")
+            .fetch_one(&mut conn)
+            .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
 #[cfg(feature = "uuid")]
 test_type!(uuid(
     Postgres,
@@ -136,6 +745,18 @@ test_type!(uuid(
         == sqlx::types::Uuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap()
 ));
 
+#[cfg(feature = "uuid")]
+test_type!(uuid_vec(
+    Postgres,
+    Vec<sqlx::types::Uuid>,
+    "'{b731678f-636f-4135-bc6f-19440c13bd19,00000000-0000-0000-0000-000000000000}'::uuid[]"
+        == vec![
+            sqlx::types::Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19").unwrap(),
+            sqlx::types::Uuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap()
+        ],
+    "'{}'::uuid[]" == Vec::<sqlx::types::Uuid>::new()
+));
+
 #[cfg(feature = "ipnetwork")]
 test_type!(ipnetwork(
     Postgres,
@@ -168,7 +789,7 @@ test_type!(ipnetwork(
 
 #[cfg(feature = "chrono")]
 mod chrono {
-    use sqlx::types::chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    use sqlx::types::chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 
     use super::*;
 
@@ -191,6 +812,25 @@ mod chrono {
         "'2019-01-02 05:10:20'::timestamp" == NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20)
     ));
 
+    test_type!(chrono_date_time_vec(
+        Postgres,
+        Vec<NaiveDateTime>,
+        "'{\"2019-01-02 05:10:20\", \"2020-03-04 10:20:30.5\"}'::timestamp[]"
+            == vec![
+                NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20),
+                NaiveDate::from_ymd(2020, 3, 4).and_hms_milli(10, 20, 30, 500)
+            ],
+        "'{}'::timestamp[]" == Vec::<NaiveDateTime>::new()
+    ));
+
+    test_type!(chrono_duration(
+        Postgres,
+        Duration,
+        "INTERVAL '1 day 2 hours'" == Duration::hours(26),
+        "INTERVAL '-1 day 00:00:01'" == Duration::seconds(1 - 86_400),
+        "INTERVAL '-00:00:00.000001'" == Duration::microseconds(-1)
+    ));
+
     test_type!(chrono_date_time_tz(
         Postgres,
         DateTime::<Utc>,
@@ -212,13 +852,16 @@ mod time_tests {
         Postgres,
         Date,
         "DATE '2001-01-05'" == date!(2001 - 1 - 5),
-        "DATE '2050-11-23'" == date!(2050 - 11 - 23)
+        "DATE '2050-11-23'" == date!(2050 - 11 - 23),
+        "DATE '1999-12-31'" == date!(1999 - 12 - 31)
     ));
 
     test_type!(time_time(
         Postgres,
         Time,
-        "TIME '05:10:20.115100'" == time!(5:10:20.115100)
+        "TIME '05:10:20.115100'" == time!(5:10:20.115100),
+        "TIME '05:10:20'" == time!(5:10:20),
+        "TIME '23:59:59.999999'" == time!(23:59:59.999999)
     ));
 
     test_type!(time_date_time(
@@ -226,7 +869,8 @@ mod time_tests {
         PrimitiveDateTime,
         "TIMESTAMP '2019-01-02 05:10:20'" == date!(2019 - 1 - 2).with_time(time!(5:10:20)),
         "TIMESTAMP '2019-01-02 05:10:20.115100'"
-            == date!(2019 - 1 - 2).with_time(time!(5:10:20.115100))
+            == date!(2019 - 1 - 2).with_time(time!(5:10:20.115100)),
+        "TIMESTAMP '1999-12-31 23:59:59.5'" == date!(1999 - 12 - 31).with_time(time!(23:59:59.5))
     ));
 
     test_type!(time_timestamp(
@@ -235,7 +879,11 @@ mod time_tests {
         "TIMESTAMPTZ '2019-01-02 05:10:20.115100'"
             == date!(2019 - 1 - 2)
                 .with_time(time!(5:10:20.115100))
-                .assume_utc()
+                .assume_utc(),
+        "TIMESTAMPTZ '2019-01-02 07:10:20+02'"
+            == date!(2019 - 1 - 2).with_time(time!(5:10:20)).assume_utc(),
+        "TIMESTAMPTZ '2019-01-02 00:10:20-05'"
+            == date!(2019 - 1 - 2).with_time(time!(5:10:20)).assume_utc()
     ));
 }
 
@@ -255,6 +903,86 @@ test_type!(bool_opt_vec(Postgres, Vec<Option<bool>>,
     "ARRAY[NULL, true, NULL, false]::bool[]" == vec![None, Some(true), None, Some(false)],
 ));
 
+// multidimensional arrays can be decoded but not encoded
+test_unprepared_type!(i32_vec_2d(Postgres, Vec<Vec<i32>>,
+    "ARRAY[[1, 2, 3], [4, 5, 6]]::int[][]" == vec![vec![1, 2, 3], vec![4, 5, 6]],
+    "'{}'::int[][]" == Vec::<Vec<i32>>::new(),
+));
+
+test_unprepared_type!(str_vec_2d(Postgres, Vec<Vec<String>>,
+    r#"ARRAY[['a', '{b}'], ['"c,', 'd\']]::text[][]"#
+        == vec![vec!["a", "{b}"], vec!["\"c,", "d\\"]],
+));
+
+// the lower bounds of an array are not preserved
+test_unprepared_type!(str_vec_lower_bound(Postgres, Vec<String>,
+    "'[2:4]={a,b,c}'::text[]" == vec!["a", "b", "c"],
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_prepared_multidimensional_array() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let rec: (Vec<Vec<i32>>, Vec<Vec<i32>>, Vec<Vec<Vec<Option<i32>>>>) = sqlx::query_as(
+        "SELECT ARRAY[[1, 2, 3], [4, 5, 6]]::int[][], '{}'::int[][], '{{{1},{NULL}}}'::int[][][]",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(rec.0, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert!(rec.1.is_empty());
+    assert_eq!(rec.2, vec![vec![vec![Some(1)], vec![None]]]);
+
+    // the lower bounds of an array are not preserved
+    let rec: (Vec<String>, Vec<Vec<i32>>) =
+        sqlx::query_as("SELECT '[2:4]={a,b,c}'::text[], '[0:1][-1:0]={{1,2},{3,4}}'::int[][]")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(rec.0, vec!["a", "b", "c"]);
+    assert_eq!(rec.1, vec![vec![1, 2], vec![3, 4]]);
+
+    // decoding into a `Vec` with fewer dimensions than the array is an error
+    let res: Result<(Vec<i32>,), _> = sqlx::query_as("SELECT ARRAY[[1, 2], [3, 4]]::int[][]")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
+test_type!(i32_opt_vec(Postgres, Vec<Option<i32>>,
+    "'{1,NULL,3}'::int[]" == vec![Some(1), None, Some(3)],
+));
+
+test_type!(str_opt_vec(Postgres, Vec<Option<String>>,
+    "ARRAY[NULL, 'NULL']::text[]" == vec![None, Some("NULL".to_owned())],
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_array_with_nulls_fails_to_decode_without_option() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res: Result<(Vec<i32>,), _> = sqlx::query_as("SELECT '{1,NULL,3}'::int[]")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    let mut cursor = conn.fetch("SELECT '{1,NULL,3}'::int[]");
+    let row = cursor.next().await?.unwrap();
+
+    assert!(matches!(
+        row.try_get::<Vec<i32>, _>(0),
+        Err(sqlx::Error::Decode(_))
+    ));
+
+    Ok(())
+}
+
 test_type!(f32_vec(Postgres, Vec<f32>,
     "ARRAY[0.0, 1.0, 3.14, 1.234, -0.002, 100000.0]::real[]" == vec![0.0_f32, 1.0, 3.14, 1.234, -0.002, 100000.0],
 ));
@@ -365,6 +1093,56 @@ async fn test_unprepared_anonymous_record() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_anonymous_records_of_many_fields() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // 3 fields
+    let rec: ((i32, String, bool),) = sqlx::query_as("SELECT ROW(1, 'two', true)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(rec.0, (1, "two".to_owned(), true));
+
+    let mut cursor = conn.fetch("SELECT ROW(1, 'two', true)");
+    let row = cursor.next().await?.unwrap();
+    let rec: (i32, String, bool) = row.get(0);
+
+    assert_eq!(rec, (1, "two".to_owned(), true));
+
+    // 6 fields
+    let rec: ((i16, i32, i64, f64, Option<String>, String),) =
+        sqlx::query_as("SELECT ROW(1::int2, 2, 3::int8, 4.5::float8, NULL::text, 'six')")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(rec.0, (1, 2, 3, 4.5, None, "six".to_owned()));
+
+    let mut cursor = conn.fetch("SELECT ROW(1::int2, 2, 3::int8, 4.5::float8, NULL::text, 'six')");
+    let row = cursor.next().await?.unwrap();
+    let rec: (i16, i32, i64, f64, Option<String>, String) = row.get(0);
+
+    assert_eq!(rec, (1, 2, 3, 4.5, None, "six".to_owned()));
+
+    // 12 fields
+    let rec: ((i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32),) =
+        sqlx::query_as("SELECT ROW(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(rec.0, (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12));
+
+    // too few fields in the record
+    let res: Result<((i32, i32, i32),), _> = sqlx::query_as("SELECT ROW(1, 2)")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn test_unprepared_anonymous_record_arrays() -> anyhow::Result<()> {
@@ -583,6 +1361,75 @@ mod json {
         "'{\"name\":\"Joe\",\"age\":33}'::json" == PgJson(Friend { name: "Joe".to_string(), age: 33 })
     ));
 
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_json_struct_round_trip() -> anyhow::Result<()> {
+        use sqlx::postgres::PgQueryAs;
+
+        let mut conn = new::<Postgres>().await?;
+
+        conn.execute("CREATE TEMPORARY TABLE friends (json JSON NOT NULL, jsonb JSONB NOT NULL)")
+            .await?;
+
+        let friend = || Friend {
+            name: "Joe".to_string(),
+            age: 33,
+        };
+
+        sqlx::query("INSERT INTO friends (json, jsonb) VALUES ($1, $2)")
+            .bind(Json(friend()))
+            .bind(Json(friend()))
+            .execute(&mut conn)
+            .await?;
+
+        // a `JSON` is decoded as is and a `JSONB` without its version byte
+        let (json, jsonb): (Json<Friend>, Json<Friend>) =
+            sqlx::query_as("SELECT json, jsonb FROM friends")
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(json.0, friend());
+        assert_eq!(jsonb.0, friend());
+
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_json_value_round_trip() -> anyhow::Result<()> {
+        use sqlx::postgres::PgQueryAs;
+
+        let mut conn = new::<Postgres>().await?;
+
+        conn.execute("CREATE TEMPORARY TABLE documents (json JSON NOT NULL, jsonb JSONB NOT NULL)")
+            .await?;
+
+        let document = json!({ "title": "Hello", "tags": ["a", "b"], "draft": false });
+
+        sqlx::query("INSERT INTO documents (json, jsonb) VALUES ($1, $2)")
+            .bind(&document)
+            .bind(&document)
+            .execute(&mut conn)
+            .await?;
+
+        let (json, jsonb): (JsonValue, JsonValue) =
+            sqlx::query_as("SELECT json, jsonb FROM documents")
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(json, document);
+        assert_eq!(jsonb, document);
+
+        // and from the text format of the simple query protocol
+        let mut cursor = conn.fetch("SELECT json, jsonb FROM documents");
+        let row: PgRow = cursor.next().await?.unwrap();
+
+        assert_eq!(row.get::<JsonValue, _>("json"), document);
+        assert_eq!(row.get::<JsonValue, _>("jsonb"), document);
+
+        Ok(())
+    }
+
     #[cfg_attr(feature = "runtime-async-std", async_std::test)]
     #[cfg_attr(feature = "runtime-tokio", tokio::test)]
     async fn test_prepared_jsonb_raw_value() -> anyhow::Result<()> {
@@ -597,3 +1444,133 @@ mod json {
         Ok(())
     }
 }
+
+#[test]
+fn it_names_types() {
+    use sqlx::types::TypeInfo;
+
+    assert_eq!(<i32 as Type<Postgres>>::type_info().name(), "INT4");
+    assert_eq!(<String as Type<Postgres>>::type_info().name(), "TEXT");
+    assert_eq!(<bool as Type<Postgres>>::type_info().name(), "BOOL");
+    assert_eq!(<Vec<i64> as Type<Postgres>>::type_info().name(), "INT8[]");
+
+    // a type that is only known by its object identifier
+    assert_eq!(PgTypeInfo::with_oid(16385).name(), "OID 16385");
+    assert_eq!(PgTypeInfo::with_name("hstore").name(), "hstore");
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_resolves_the_name_of_a_type() -> anyhow::Result<()> {
+    use sqlx::types::TypeInfo;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let (oid,): (i32,) = sqlx::query_as("SELECT oid::int4 FROM pg_type WHERE typname = 'hstore'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let type_info = PgTypeInfo::with_oid(oid as u32);
+
+    assert_eq!(type_info.name(), format!("OID {}", oid));
+
+    let resolved = conn.resolve_type(&type_info).await?;
+
+    assert_eq!(resolved.name(), "HSTORE");
+    assert_eq!(resolved.to_string(), "HSTORE");
+    assert!(resolved.compatible(&type_info));
+
+    // the second lookup is answered from the cache of the connection
+    let resolved = conn.resolve_type(&type_info).await?;
+
+    assert_eq!(resolved.name(), "HSTORE");
+
+    // a type that is already named is left as is
+    let resolved = conn.resolve_type(&PgTypeInfo::with_name("hstore")).await?;
+
+    assert_eq!(resolved.name(), "hstore");
+
+    Ok(())
+}
+
+#[test]
+fn it_creates_type_info_from_a_name() {
+    use sqlx::types::TypeInfo;
+
+    let int4 = PgTypeInfo::from_name("int4").unwrap();
+
+    assert_eq!(int4.name(), "INT4");
+    assert!(int4.compatible(&<i32 as Type<Postgres>>::type_info()));
+
+    assert!(PgTypeInfo::from_name("INTEGER")
+        .unwrap()
+        .compatible(&<i32 as Type<Postgres>>::type_info()));
+
+    assert!(PgTypeInfo::from_name("text")
+        .unwrap()
+        .compatible(&<String as Type<Postgres>>::type_info()));
+
+    assert_eq!(
+        PgTypeInfo::from_name("timestamptz").unwrap().name(),
+        "TIMESTAMPTZ"
+    );
+    assert_eq!(
+        PgTypeInfo::from_name("double precision").unwrap().name(),
+        "FLOAT8"
+    );
+
+    // arrays are named after their elements
+    let int4_array = PgTypeInfo::from_name("_int4").unwrap();
+
+    assert_eq!(int4_array.name(), "INT4[]");
+    assert!(int4_array.compatible(&<Vec<i32> as Type<Postgres>>::type_info()));
+    assert!(PgTypeInfo::from_name("text[]")
+        .unwrap()
+        .compatible(&<Vec<String> as Type<Postgres>>::type_info()));
+
+    // names that are not built-in types are left for a lookup in the catalog
+    assert!(PgTypeInfo::from_name("hstore").is_none());
+    assert!(PgTypeInfo::from_name("int4[][]").is_none());
+    assert!(PgTypeInfo::from_name("").is_none());
+}
+
+#[test]
+fn it_accepts_a_string_for_the_text_types() {
+    use sqlx::types::TypeInfo;
+
+    let string = <String as Type<Postgres>>::type_info();
+    let strings = <Vec<String> as Type<Postgres>>::type_info();
+
+    for name in &["text", "varchar", "bpchar", "name"] {
+        let ty = PgTypeInfo::from_name(name).unwrap();
+        let array = PgTypeInfo::from_name(&format!("{}[]", name)).unwrap();
+
+        assert!(string.compatible(&ty), "{}", name);
+        assert!(ty.compatible(&string), "{}", name);
+
+        assert!(strings.compatible(&array), "{}[]", name);
+        assert!(array.compatible(&strings), "{}[]", name);
+    }
+
+    // `CITEXT` is created by an extension and is only known by its name
+    assert!(string.compatible(&PgTypeInfo::with_name("citext")));
+    assert!(PgTypeInfo::with_name("citext").compatible(&string));
+
+    assert!(!string.compatible(&<i32 as Type<Postgres>>::type_info()));
+    assert!(!strings.compatible(&string));
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_a_string_to_a_name() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (name,): (String,) = sqlx::query_as("SELECT typname FROM pg_type WHERE typname = $1")
+        .bind("int4".to_owned())
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(name, "int4");
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use futures::TryStreamExt;
-use sqlx::postgres::{PgPool, PgQueryAs, PgRow};
-use sqlx::{Connection, Executor, Postgres, Row};
+use sqlx::postgres::{PgPool, PgQueryAs, PgRow, PgSqlState};
+use sqlx::transaction::IsolationLevel;
+use sqlx::{Connection, Cursor, Executor, Postgres, Row, Transaction};
 use sqlx_test::new;
 use std::time::Duration;
 
@@ -248,6 +249,279 @@ async fn it_can_rollback_nested_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_begins_a_read_only_transaction() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE _sqlx_read_only (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut tx = Transaction::builder()
+        .read_only(true)
+        .isolation_level(IsolationLevel::RepeatableRead)
+        .begin(conn)
+        .await?;
+
+    let (read_only, level): (String, String) = sqlx::query_as(
+        "SELECT current_setting('transaction_read_only'), current_setting('transaction_isolation')",
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    assert_eq!(read_only, "on");
+    assert_eq!(level, "repeatable read");
+
+    match tx
+        .execute("INSERT INTO _sqlx_read_only (id) VALUES (1)")
+        .await
+    {
+        Err(sqlx::Error::Database(err)) => {
+            assert_eq!(err.sql_state(), Some(PgSqlState::ReadOnlySqlTransaction));
+        }
+
+        res => panic!("expected the write to fail; got {:?}", res),
+    }
+
+    let mut conn = tx.rollback().await?;
+
+    // the next transaction is not read-only
+    let mut tx = conn.begin().await?;
+
+    tx.execute("INSERT INTO _sqlx_read_only (id) VALUES (1)")
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rolls_back_a_dropped_transaction() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE _sqlx_dropped_tx (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO _sqlx_dropped_tx (id) VALUES ($1)")
+        .bind(1_i32)
+        .execute(&mut tx)
+        .await?;
+
+    drop(tx);
+
+    // the connection is returned to the pool once the transaction is rolled back
+    let mut conn = pool.acquire().await?;
+
+    let (other_pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(pid, other_pid);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_dropped_tx")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    // a dropped save point rolls back the whole transaction
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO _sqlx_dropped_tx (id) VALUES ($1)")
+        .bind(2_i32)
+        .execute(&mut tx)
+        .await?;
+
+    let mut tx = tx.begin().await?;
+
+    sqlx::query("INSERT INTO _sqlx_dropped_tx (id) VALUES ($1)")
+        .bind(3_i32)
+        .execute(&mut tx)
+        .await?;
+
+    drop(tx);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_dropped_tx")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+// `tokio::spawn` panics outside of the runtime, e.g., on another thread, so the rollback of a
+// transaction that is dropped there cannot be spawned
+#[cfg(feature = "runtime-tokio")]
+#[tokio::test]
+async fn it_closes_a_transaction_dropped_outside_of_the_runtime() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut tx = pool.begin().await?;
+
+    let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&mut tx)
+        .await?;
+
+    std::thread::spawn(move || drop(tx)).join().unwrap();
+
+    // the connection is not returned to the pool with the transaction still open
+    assert_eq!(pool.size(), 0);
+
+    let mut conn = pool.acquire().await?;
+
+    let (other_pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_ne!(pid, other_pid);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_enforces_the_max_size_of_the_pool() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .connect_timeout(Duration::from_millis(500))
+        .max_size(2)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let first = pool.acquire().await?;
+    let _second = pool.acquire().await?;
+
+    assert_eq!(pool.size(), 2);
+
+    // no connection is released before the timeout
+    match pool.acquire().await {
+        Err(sqlx::Error::PoolTimedOut(_)) => {}
+        res => panic!("expected a timeout, got {:?}", res.map(|_| ())),
+    }
+
+    drop(first);
+
+    let _third = pool.acquire().await?;
+
+    assert_eq!(pool.size(), 2);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_recycles_connections_past_their_max_lifetime() -> anyhow::Result<()> {
+    use sqlx_core::runtime::sleep;
+
+    let pool = PgPool::builder()
+        .max_size(1)
+        .max_lifetime(Duration::from_millis(200))
+        .test_on_acquire(true)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let (first,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&pool)
+        .await?;
+
+    // a connection is reused within its lifetime
+    let (second,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(first, second);
+
+    sleep(Duration::from_millis(500)).await;
+
+    // and replaced by a new one after it
+    let (third,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_ne!(first, third);
+    assert_eq!(pool.size(), 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_runs_after_connect_on_new_connections() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let connects = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&connects);
+
+    let pool = PgPool::builder()
+        .max_size(1)
+        .after_connect(move |conn| {
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            Box::pin(async move {
+                conn.execute("SET application_name = 'sqlx_after_connect'")
+                    .await?;
+
+                Ok(())
+            })
+        })
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    for _ in 0..3 {
+        let (name,): (String,) = sqlx::query_as("SHOW application_name")
+            .fetch_one(&pool)
+            .await?;
+
+        assert_eq!(name, "sqlx_after_connect");
+    }
+
+    // the connection is reused so the callback only ran once
+    assert_eq!(connects.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fails_to_acquire_if_after_connect_fails() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .after_connect(|conn| {
+            Box::pin(async move {
+                conn.execute("SELECT * FROM table_that_does_not_exist")
+                    .await?;
+
+                Ok(())
+            })
+        })
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    assert!(pool.acquire().await.is_err());
+
+    // the failed connection is not kept
+    assert_eq!(pool.size(), 0);
+
+    Ok(())
+}
+
 // run with `cargo test --features postgres -- --ignored --nocapture pool_smoke_test`
 #[ignore]
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
@@ -363,3 +637,440 @@ async fn test_describe() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_describes_the_nullability_of_columns() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let _ = conn
+        .execute(
+            r#"
+        CREATE TEMP TABLE describe_nullability (
+            required text not null,
+            optional text
+        )
+    "#,
+        )
+        .await?;
+
+    let describe = conn
+        .describe(
+            "SELECT required, optional, required || optional AS joined, 1 AS one \
+             FROM describe_nullability",
+        )
+        .await?;
+
+    assert_eq!(describe.result_columns[0].non_null, Some(true));
+    assert_eq!(describe.result_columns[1].non_null, Some(false));
+
+    // expressions are not columns of a table
+    assert!(describe.result_columns[2].table_id.is_none());
+    assert_eq!(describe.result_columns[2].non_null, None);
+    assert_eq!(describe.result_columns[3].non_null, None);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_describes_a_query_with_parameters() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let _ = conn
+        .execute(
+            r#"
+        CREATE TEMP TABLE describe_params (
+            id int8 primary key,
+            name text not null
+        )
+    "#,
+        )
+        .await?;
+
+    let describe = conn
+        .describe("SELECT id, name FROM describe_params WHERE id = $1 OR name = $2")
+        .await?;
+
+    assert_eq!(describe.param_types.len(), 2);
+    assert_eq!(
+        describe.param_types[0].as_ref().unwrap().type_name(),
+        "INT8"
+    );
+    assert_eq!(
+        describe.param_types[1].as_ref().unwrap().type_name(),
+        "TEXT"
+    );
+
+    assert_eq!(describe.result_columns.len(), 2);
+    assert_eq!(describe.result_columns[0].name.as_deref(), Some("id"));
+    assert_eq!(describe.result_columns[1].name.as_deref(), Some("name"));
+
+    // describing does not execute the query
+    let describe = conn
+        .describe("INSERT INTO describe_params (id, name) VALUES ($1, $2)")
+        .await?;
+
+    assert_eq!(describe.param_types.len(), 2);
+    assert_eq!(describe.result_columns.len(), 0);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM describe_params")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    // a query that fails to prepare keeps failing with its own error
+    for _ in 0..2 {
+        let res = conn.describe("SELECT * FROM describe_params_missing").await;
+
+        assert!(res
+            .unwrap_err()
+            .to_string()
+            .contains("describe_params_missing"));
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_at_most_one_row() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let row = sqlx::query("SELECT * FROM generate_series(1, 0)")
+        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
+        .fetch_at_most_one(&mut conn)
+        .await?;
+
+    assert_eq!(row, None);
+
+    let row = sqlx::query("SELECT * FROM generate_series(1, 1)")
+        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
+        .fetch_at_most_one(&mut conn)
+        .await?;
+
+    assert_eq!(row, Some(1));
+
+    let res = sqlx::query("SELECT * FROM generate_series(1, 3)")
+        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
+        .fetch_at_most_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::TooManyRows)), "{:?}", res);
+
+    let res: Result<Option<(i32,)>, _> = sqlx::query_as("SELECT * FROM generate_series(1, 3)")
+        .fetch_at_most_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::TooManyRows)), "{:?}", res);
+
+    // the rest of the rows are discarded
+    let (value,): (i32,) = sqlx::query_as("SELECT * FROM generate_series(1, 1)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_gets_columns_that_may_not_exist() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor = sqlx::query("SELECT 1::int4 AS id, NULL::text AS name").fetch(&mut conn);
+    let row: PgRow = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get_opt::<i32, _>("id")?, Some(1));
+    assert_eq!(row.try_get_opt::<i32, _>(0)?, Some(1));
+
+    // a NULL column is distinct from a missing one
+    assert_eq!(row.try_get_opt::<Option<String>, _>("name")?, Some(None));
+    assert_eq!(row.try_get_opt::<Option<String>, _>("email")?, None);
+    assert_eq!(row.try_get_opt::<Option<String>, _>(2)?, None);
+
+    // a value that cannot be decoded is still an error
+    assert!(row.try_get_opt::<i32, _>("name").is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_describes_the_columns_of_a_row() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor =
+        sqlx::query("SELECT 1::int4 AS id, 'Alice'::text AS name, 1 + 1").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+
+    let columns: Vec<_> = row
+        .columns()
+        .iter()
+        .map(|column| (column.name(), column.ordinal(), column.type_oid()))
+        .collect();
+
+    // `INT4` is 23 and `TEXT` is 25
+    assert_eq!(
+        columns,
+        [(Some("id"), 0, 23), (Some("name"), 1, 25), (None, 2, 23)]
+    );
+
+    assert_eq!(row.columns()[0].type_info().oid(), 23);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_rows_in_chunks() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY SEQUENCE _sqlx_chunks")
+        .await?;
+
+    // every row is returned
+    let mut cursor = sqlx::query("SELECT i FROM generate_series(1, 100000) i")
+        .fetch(&mut conn)
+        .chunk_size(1000);
+
+    let mut sum = 0_i64;
+    let mut rows = 0;
+
+    while let Some(row) = cursor.next().await? {
+        sum += row.get::<i32, _>(0) as i64;
+        rows += 1;
+    }
+
+    assert_eq!(rows, 100000);
+    assert_eq!(sum, 100000 * 100001 / 2);
+
+    // the server only produces the rows of one chunk ahead of the rows that are processed, so
+    // the rows that are buffered are bounded by the chunk size however large the result is
+    let mut cursor = sqlx::query("SELECT nextval('_sqlx_chunks') FROM generate_series(1, 1000000)")
+        .fetch(&mut conn)
+        .chunk_size(100);
+
+    for expected in 1..=150_i64 {
+        let row = cursor.next().await?.unwrap();
+        assert_eq!(row.get::<i64, _>(0), expected);
+    }
+
+    // the rest of the rows are never produced once the cursor is dropped
+    drop(cursor);
+
+    let (next,): (i64,) = sqlx::query_as("SELECT nextval('_sqlx_chunks')")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(next, 201);
+
+    // an error in a later chunk leaves the connection usable
+    let mut cursor = sqlx::query("SELECT 1 / (150 - i) FROM generate_series(1, 200) i")
+        .fetch(&mut conn)
+        .chunk_size(100);
+
+    let mut res = Ok(());
+
+    loop {
+        match cursor.next().await {
+            Ok(Some(_)) => {}
+            Ok(None) => break,
+            Err(err) => {
+                res = Err(err);
+                break;
+            }
+        }
+    }
+
+    assert!(res.is_err());
+    drop(cursor);
+
+    let (value,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_counts_the_rows_affected_by_a_modification() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let affected = conn
+        .execute("CREATE TEMPORARY TABLE _sqlx_affected (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    assert_eq!(affected, 0);
+
+    let affected =
+        sqlx::query("INSERT INTO _sqlx_affected (id) SELECT * FROM generate_series(1, $1)")
+            .bind(5_i32)
+            .execute(&mut conn)
+            .await?;
+
+    assert_eq!(affected, 5);
+
+    let affected = sqlx::query("UPDATE _sqlx_affected SET id = id + 10 WHERE id > $1")
+        .bind(2_i32)
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(affected, 3);
+
+    let affected = sqlx::query("DELETE FROM _sqlx_affected WHERE id > $1")
+        .bind(10_i32)
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(affected, 3);
+
+    let affected = conn.execute("DELETE FROM _sqlx_affected").await?;
+
+    assert_eq!(affected, 2);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_decodes_the_rows_returned_by_a_modification() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE _sqlx_returning (id SERIAL PRIMARY KEY, name TEXT NOT NULL UNIQUE)",
+    )
+    .await?;
+
+    let (id,): (i32,) =
+        sqlx::query_as("INSERT INTO _sqlx_returning (name) VALUES ($1) RETURNING id")
+            .bind("Alice")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(id, 1);
+
+    let users: Vec<(i32, String)> =
+        sqlx::query_as("INSERT INTO _sqlx_returning (name) VALUES ($1), ($2) RETURNING id, name")
+            .bind("Bob")
+            .bind("Carol")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(users, vec![(2, "Bob".to_owned()), (3, "Carol".to_owned())]);
+
+    let users: Vec<(i32, String)> = sqlx::query_as(
+        "UPDATE _sqlx_returning SET name = UPPER(name) WHERE id > $1 RETURNING id, name",
+    )
+    .bind(1_i32)
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(users, vec![(2, "BOB".to_owned()), (3, "CAROL".to_owned())]);
+
+    // the second row violates the unique constraint so nothing is inserted
+    let res: Result<Vec<(i32,)>, _> =
+        sqlx::query_as("INSERT INTO _sqlx_returning (name) VALUES ('Dave'), ('Dave') RETURNING id")
+            .fetch_all(&mut conn)
+            .await;
+
+    assert!(res.is_err());
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_returning")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_calls_the_query_hook() -> anyhow::Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    let queries = Arc::new(Mutex::new(Vec::new()));
+    let mut conn = new::<Postgres>().await?;
+
+    let log = Arc::clone(&queries);
+    conn.on_query(move |query| {
+        let query = (query.sql().to_owned(), query.arguments(), query.elapsed());
+        log.lock().unwrap().push(query);
+    });
+
+    conn.execute("SELECT pg_sleep(0.01)").await?;
+
+    // the cursor is dropped after the first row is fetched
+    let (value,): (i32,) = sqlx::query_as("SELECT $1 + $2")
+        .bind(1_i32)
+        .bind(2_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 3);
+
+    {
+        let queries = queries.lock().unwrap();
+
+        assert_eq!(queries.len(), 2);
+
+        assert_eq!(queries[0].0, "SELECT pg_sleep(0.01)");
+        assert_eq!(queries[0].1, 0);
+        assert!(queries[0].2 >= Duration::from_millis(10));
+
+        // the values of the arguments are never passed to the hook
+        assert_eq!(queries[1].0, "SELECT $1 + $2");
+        assert_eq!(queries[1].1, 2);
+        assert!(queries[1].2 > Duration::from_secs(0));
+    }
+
+    // a pool sets the hook on each of its connections
+    let log = Arc::clone(&queries);
+    let pool = PgPool::builder()
+        .max_size(1)
+        .on_query(move |query| {
+            log.lock()
+                .unwrap()
+                .push((query.sql().to_owned(), 0, query.elapsed()))
+        })
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    sqlx::query("SELECT 'pooled'").execute(&pool).await?;
+
+    assert!(queries
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(sql, _, _)| sql == "SELECT 'pooled'"));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_sends_the_arguments_of_repeated_queries() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // the buffers of the arguments and the messages are reused between these queries;
+    // the large values are past what is kept and the short ones follow them
+    for len in &[0_usize, 10, 100_000, 3, 1_000_000, 1, 0] {
+        let text = "x".repeat(*len);
+
+        let (value, length, id): (String, i32, i64) =
+            sqlx::query_as("SELECT $1::text, length($1::text), $2::int8")
+                .bind(&text)
+                .bind(*len as i64)
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(value, text);
+        assert_eq!(length as usize, *len);
+        assert_eq!(id as usize, *len);
+    }
+
+    Ok(())
+}
@@ -12,6 +12,7 @@ async fn macro_select() -> anyhow::Result<()> {
 
     assert_eq!(1, account.id);
     assert_eq!("Herp Derpinson", account.name);
+    assert!(account.is_active);
 
     Ok(())
 }
@@ -30,6 +31,7 @@ async fn macro_select_bind() -> anyhow::Result<()> {
 
     assert_eq!(1, account.id);
     assert_eq!("Herp Derpinson", account.name);
+    assert!(account.is_active);
 
     Ok(())
 }
@@ -52,6 +54,7 @@ async fn test_query_as_raw() -> anyhow::Result<()> {
 
     assert_eq!(1, account.id);
     assert_eq!("Herp Derpinson", account.name);
+    assert!(account.is_active);
 
     Ok(())
 }
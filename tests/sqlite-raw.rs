@@ -42,10 +42,11 @@ SELECT id, text FROM _sqlx_test;
 
     let row = cursor.next().await?.unwrap();
 
-    let id: i64 = row.try_get("id")?;
+    // `INT PRIMARY KEY` is not an alias of the rowid; the id is left NULL
+    let id: Option<i64> = row.try_get("id")?;
     let text: &str = row.try_get("text")?;
 
-    assert_eq!(0, id);
+    assert_eq!(None, id);
     assert_eq!("this is a test", text);
 
     Ok(())
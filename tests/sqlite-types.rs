@@ -1,5 +1,6 @@
+use sqlx::sqlite::SqliteQueryAs;
 use sqlx::Sqlite;
-use sqlx_test::test_type;
+use sqlx_test::{new, test_type};
 
 test_type!(null(
     Sqlite,
@@ -7,12 +8,139 @@ test_type!(null(
     "NULL" == None::<i32>
 ));
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_encode_none_as_null() -> anyhow::Result<()> {
+    use sqlx::Executor;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE nullable (id INTEGER PRIMARY KEY, value TEXT)")
+        .await?;
+
+    sqlx::query("INSERT INTO nullable (id, value) VALUES (?, ?), (?, ?)")
+        .bind(1_i32)
+        .bind(None::<String>)
+        .bind(2_i32)
+        .bind(Some("two"))
+        .execute(&mut conn)
+        .await?;
+
+    let rows: Vec<(i32, bool)> =
+        sqlx::query_as("SELECT id, value IS NULL FROM nullable ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(rows, [(1, true), (2, false)]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_decode_null() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let row: (Option<i64>, Option<f64>, Option<bool>, Option<String>) =
+        sqlx::query_as("SELECT ?, ?, ?, ?")
+            .bind(None::<i64>)
+            .bind(None::<f64>)
+            .bind(None::<bool>)
+            .bind(None::<String>)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(row, (None, None, None, None));
+
+    // NULL must not be read as zero or false
+    let res: Result<(i32,), _> = sqlx::query_as("SELECT NULL").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    let res: Result<(i64,), _> = sqlx::query_as("SELECT NULL").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    let res: Result<(f64,), _> = sqlx::query_as("SELECT NULL").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    let res: Result<(bool,), _> = sqlx::query_as("SELECT NULL").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    let res: Result<(String,), _> = sqlx::query_as("SELECT NULL").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
 test_type!(bool(Sqlite, bool, "FALSE" == false, "TRUE" == true));
 
+test_type!(i8(Sqlite, i8, "-128" == i8::min_value(), "127" == 127_i8));
+
+test_type!(i16(
+    Sqlite,
+    i16,
+    "300" == 300_i16,
+    "-32768" == i16::min_value()
+));
+
 test_type!(i32(Sqlite, i32, "94101" == 94101_i32));
 
 test_type!(i64(Sqlite, i64, "9358295312" == 9358295312_i64));
 
+test_type!(u8(Sqlite, u8, "255" == 255_u8));
+
+test_type!(u16(Sqlite, u16, "65535" == 65535_u16));
+
+test_type!(u32(Sqlite, u32, "4294967295" == 4294967295_u32));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_integer_overflow_fails_to_decode() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let res: Result<(i8,), _> = sqlx::query_as("SELECT 300").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    let res: Result<(u32,), _> = sqlx::query_as("SELECT -1").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    let res: Result<(i32,), _> = sqlx::query_as("SELECT 4294967296")
+        .fetch_one(&mut conn)
+        .await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
+test_type!(non_zero_i64(
+    Sqlite,
+    std::num::NonZeroI64,
+    "-1" == std::num::NonZeroI64::new(-1).unwrap(),
+    "9223372036854775807" == std::num::NonZeroI64::new(i64::max_value()).unwrap()
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_zero_fails_to_decode_as_non_zero() -> anyhow::Result<()> {
+    use std::num::{NonZeroI32, NonZeroU8};
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let res: Result<(NonZeroI32,), _> = sqlx::query_as("SELECT 0").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    let res: Result<(NonZeroU8,), _> = sqlx::query_as("SELECT ?")
+        .bind(0_i32)
+        .fetch_one(&mut conn)
+        .await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    // NULL is not zero
+    let res: (Option<NonZeroI32>,) = sqlx::query_as("SELECT NULL").fetch_one(&mut conn).await?;
+    assert_eq!(res.0, None);
+
+    Ok(())
+}
+
 // NOTE: This behavior can be surprising. Floating-point parameters are widening to double which can
 //       result in strange rounding.
 test_type!(f32(
@@ -31,9 +159,55 @@ test_type!(string(
     Sqlite,
     String,
     "'this is foo'" == "this is foo",
-    "''" == ""
+    "''" == "",
+    "'Grüße, 世界 🦀'" == "Grüße, 世界 🦀",
+    "'a' || char(0) || 'b'" == "a\0b"
 ));
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_cow_str() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqliteRow;
+    use sqlx::{Cursor, Row};
+    use std::borrow::Cow;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    // an owned and a borrowed value are both encoded as TEXT
+    let mut cursor = sqlx::query("SELECT ? || ?")
+        .bind(Cow::<str>::Owned("Grüße, ".to_owned()))
+        .bind(Cow::Borrowed("世界"))
+        .fetch(&mut conn);
+
+    let row: SqliteRow = cursor.next().await?.unwrap();
+    let value: Cow<str> = row.try_get(0)?;
+
+    // the value is borrowed from the row
+    assert!(matches!(value, Cow::Borrowed("Grüße, 世界")));
+
+    // and may be kept past it once owned
+    let value = value.into_owned();
+    drop(cursor);
+
+    assert_eq!(value, "Grüße, 世界");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_invalid_utf8_fails_to_decode() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let res: Result<(String,), _> = sqlx::query_as("SELECT CAST(X'C328' AS TEXT)")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
 test_type!(bytes(
     Sqlite,
     Vec<u8>,
@@ -42,5 +216,221 @@ test_type!(bytes(
     "X''"
         == Vec::<u8>::new(),
     "X'0000000052'"
-        == vec![0_u8, 0, 0, 0, 0x52]
+        == vec![0_u8, 0, 0, 0, 0x52],
+    "X'DE00AD0000BE00'"
+        == vec![0xDE_u8, 0, 0xAD, 0, 0, 0xBE, 0]
+));
+
+test_type!(bytes_opt(
+    Sqlite,
+    Option<Vec<u8>>,
+    "X''" == Some(Vec::<u8>::new()),
+    "NULL" == None::<Vec<u8>>
+));
+
+#[cfg(feature = "bytes")]
+test_type!(bytes_bytes(
+    Sqlite,
+    sqlx::types::Bytes,
+    "X'DEADBEEF'" == sqlx::types::Bytes::from_static(&[0xDE, 0xAD, 0xBE, 0xEF]),
+    "X''" == sqlx::types::Bytes::new(),
+    "X'DE00AD0000BE00'" == sqlx::types::Bytes::from_static(&[0xDE, 0, 0xAD, 0, 0, 0xBE, 0])
 ));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_null_bytes_fails_to_decode() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let res: Result<(Vec<u8>,), _> = sqlx::query_as("SELECT NULL").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+mod chrono {
+    use super::*;
+    use sqlx::types::chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+    use sqlx_test::test_unprepared_type;
+
+    test_type!(chrono_date(
+        Sqlite,
+        NaiveDate,
+        "'2001-01-05'" == NaiveDate::from_ymd(2001, 1, 5),
+        "'2050-11-23'" == NaiveDate::from_ymd(2050, 11, 23)
+    ));
+
+    test_type!(chrono_date_time(
+        Sqlite,
+        NaiveDateTime,
+        "'2019-01-02 05:10:20'" == NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20),
+        "'2019-01-02 05:10:20.115100'"
+            == NaiveDate::from_ymd(2019, 1, 2).and_hms_micro(5, 10, 20, 115_100)
+    ));
+
+    test_type!(chrono_date_time_utc(
+        Sqlite,
+        DateTime<Utc>,
+        "'1996-12-20 00:39:57'"
+            == DateTime::<Utc>::from_utc(NaiveDate::from_ymd(1996, 12, 20).and_hms(0, 39, 57), Utc)
+    ));
+
+    // the separator and offset of ISO 8601 are accepted when decoding
+    test_unprepared_type!(chrono_date_time_iso8601(
+        Sqlite,
+        NaiveDateTime,
+        "'2019-01-02T05:10:20.1151Z'"
+            == NaiveDate::from_ymd(2019, 1, 2).and_hms_micro(5, 10, 20, 115_100),
+        "'2019-01-02T07:10:20+02:00'" == NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20),
+        "datetime(0, 'unixepoch')" == NaiveDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0)
+    ));
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use serde_json::{json, Value as JsonValue};
+    use sqlx::types::Json;
+    use sqlx::Executor;
+
+    test_type!(json(
+        Sqlite,
+        Json<JsonValue>,
+        "'\"Hello, World\"'" == Json(json!("Hello, World")),
+        "'\"😎\"'" == Json(json!("😎")),
+        "'[\"Hello\",\"World!\"]'" == Json(json!(["Hello", "World!"]))
+    ));
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct Friend {
+        name: String,
+        age: u32,
+    }
+
+    test_type!(json_struct(Sqlite, Json<Friend>,
+        "'{\"name\":\"Joe\",\"age\":33}'" == Json(Friend { name: "Joe".to_string(), age: 33 })
+    ));
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_json_struct_round_trip() -> anyhow::Result<()> {
+        let mut conn = new::<Sqlite>().await?;
+
+        conn.execute(
+            "CREATE TEMPORARY TABLE friends (id INTEGER PRIMARY KEY, friend TEXT NOT NULL)",
+        )
+        .await?;
+
+        sqlx::query("INSERT INTO friends (id, friend) VALUES (?, ?)")
+            .bind(1_i32)
+            .bind(Json(Friend {
+                name: "Joe".to_string(),
+                age: 33,
+            }))
+            .execute(&mut conn)
+            .await?;
+
+        let (friend, age): (Json<Friend>, i32) = sqlx::query_as(
+            "SELECT friend, json_extract(friend, '$.age') FROM friends WHERE id = 1",
+        )
+        .fetch_one(&mut conn)
+        .await?;
+
+        assert_eq!(
+            friend,
+            Json(Friend {
+                name: "Joe".to_string(),
+                age: 33
+            })
+        );
+        assert_eq!(age, 33);
+
+        // text that is not JSON fails to decode
+        let res: Result<(Json<Friend>,), _> =
+            sqlx::query_as("SELECT 'Joe'").fetch_one(&mut conn).await;
+        assert!(matches!(res, Err(sqlx::Error::Decode(_))));
+
+        Ok(())
+    }
+}
+
+#[test]
+fn it_names_types() {
+    use sqlx::types::{Type, TypeInfo};
+
+    assert_eq!(<i32 as Type<Sqlite>>::type_info().name(), "INTEGER");
+    assert_eq!(<String as Type<Sqlite>>::type_info().name(), "TEXT");
+    assert_eq!(<bool as Type<Sqlite>>::type_info().name(), "BOOLEAN");
+    assert_eq!(<f64 as Type<Sqlite>>::type_info().name(), "DOUBLE");
+    assert_eq!(<Vec<u8> as Type<Sqlite>>::type_info().name(), "BLOB");
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_gets_the_type_of_a_value() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqliteRow;
+    use sqlx::types::TypeInfo;
+    use sqlx::{Cursor, Executor, Row};
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE dynamic (id INTEGER PRIMARY KEY, value INTEGER)")
+        .await?;
+
+    conn.execute(
+        "INSERT INTO dynamic (id, value) VALUES (1, 10), (2, 1.5), (3, 'ten'), (4, X'0A'), (5, NULL)",
+    )
+    .await?;
+
+    let mut cursor = sqlx::query("SELECT value FROM dynamic ORDER BY id").fetch(&mut conn);
+    let mut types = Vec::new();
+
+    while let Some(row) = cursor.next().await? {
+        let row: SqliteRow = row;
+        let value = row.try_get_raw(0)?;
+
+        types.push(value.type_info().map(|ty| ty.name().into_owned()));
+    }
+
+    // the type is that of each value and not of the column it is in
+    assert_eq!(
+        types,
+        [
+            Some("INTEGER".to_owned()),
+            Some("DOUBLE".to_owned()),
+            Some("TEXT".to_owned()),
+            Some("BLOB".to_owned()),
+            None
+        ]
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_decode_any() -> anyhow::Result<()> {
+    use sqlx::types::AnyValue;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let row: (AnyValue, AnyValue, AnyValue, AnyValue, AnyValue) =
+        sqlx::query_as("SELECT NULL, 10, 1.5, 'text', X'DEAD'")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(
+        row,
+        (
+            AnyValue::Null,
+            AnyValue::Int(10),
+            AnyValue::Float(1.5),
+            AnyValue::Text("text".to_owned()),
+            AnyValue::Bytes(vec![0xDE, 0xAD])
+        )
+    );
+
+    Ok(())
+}
@@ -1,5 +1,10 @@
 use futures::TryStreamExt;
-use sqlx::{sqlite::SqliteQueryAs, Connect, Connection, Executor, Sqlite, SqliteConnection};
+use sqlx::error::DatabaseError;
+use sqlx::{
+    sqlite::{SqlitePool, SqliteQueryAs, SqliteRow},
+    transaction::IsolationLevel,
+    Connect, Connection, Cursor, Executor, Row, Sqlite, SqliteConnection, Transaction,
+};
 use sqlx_test::new;
 
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
@@ -8,6 +13,23 @@ async fn it_connects() -> anyhow::Result<()> {
     Ok(new::<Sqlite>().await?.ping().await?)
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_pings() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.ping().await?;
+
+    // the statement of the ping does not replace one being used
+    let mut cursor = sqlx::query("SELECT 1 UNION ALL SELECT 2").fetch(&mut conn);
+    assert!(cursor.next().await?.is_some());
+    drop(cursor);
+
+    conn.ping().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn it_fails_to_connect() -> anyhow::Result<()> {
@@ -22,6 +44,42 @@ async fn it_fails_to_connect() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rejects_a_path_with_nul() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join("sqlx\0nul.db");
+
+    let err = SqliteConnection::connect(&*format!("sqlite://{}", path.display()))
+        .await
+        .err()
+        .unwrap()
+        .to_string();
+
+    assert!(err.contains("contains a NUL byte"), "{}", err);
+
+    // the path is percent-decoded
+    assert!(SqliteConnection::connect("sqlite:///tmp/sqlx%00nul.db")
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_opens_a_path_with_spaces() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("sqlx spaces {}.db", std::process::id()));
+
+    let conn = SqliteConnection::connect(&*format!("sqlite://{}", path.display())).await?;
+    conn.close().await?;
+
+    assert!(path.exists());
+
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn it_fails_to_parse() -> anyhow::Result<()> {
@@ -199,3 +257,921 @@ CREATE TEMPORARY TABLE describe_test (
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_describes_the_columns_of_a_query() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let _ = conn
+        .execute(
+            r#"
+CREATE TEMPORARY TABLE users (
+    id UNSIGNED BIG INT PRIMARY KEY,
+    name NVARCHAR(255) NOT NULL
+)
+            "#,
+        )
+        .await?;
+
+    let describe = conn.describe("SELECT id, name FROM users").await?;
+
+    assert_eq!(describe.result_columns.len(), 2);
+
+    assert_eq!(describe.result_columns[0].name.as_deref(), Some("id"));
+    assert_eq!(
+        describe.result_columns[0]
+            .type_info
+            .as_ref()
+            .unwrap()
+            .to_string(),
+        "INTEGER"
+    );
+
+    assert_eq!(describe.result_columns[1].name.as_deref(), Some("name"));
+    assert_eq!(
+        describe.result_columns[1]
+            .type_info
+            .as_ref()
+            .unwrap()
+            .to_string(),
+        "TEXT"
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_opens_read_only() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("sqlx-read-only-{}.db", std::process::id()));
+    let url = format!("sqlite://{}", path.display());
+
+    let mut conn = SqliteConnection::connect(&*url).await?;
+    conn.execute("CREATE TABLE IF NOT EXISTS users (id INTEGER PRIMARY KEY)")
+        .await?;
+    conn.execute("INSERT INTO users (id) VALUES (1)").await?;
+    conn.close().await?;
+
+    let mut conn = SqliteConnection::connect(&*format!("{}?mode=ro&cache=private", url)).await?;
+
+    let (count,): (i32,) = sqlx::query_as("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1);
+    assert!(conn
+        .execute("INSERT INTO users (id) VALUES (2)")
+        .await
+        .is_err());
+
+    conn.close().await?;
+
+    // the file must exist to be opened without `mode=rwc`
+    let missing = format!("{}-missing", url);
+    assert!(SqliteConnection::connect(&*format!("{}?mode=ro", missing))
+        .await
+        .is_err());
+    assert!(SqliteConnection::connect(&*format!("{}?mode=rw", missing))
+        .await
+        .is_err());
+
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_opens_a_rollback_journal_database_read_only() -> anyhow::Result<()> {
+    let path =
+        std::env::temp_dir().join(format!("sqlx-read-only-delete-{}.db", std::process::id()));
+    let url = format!("sqlite://{}", path.display());
+
+    let mut conn = SqliteConnection::connect(&*format!("{}?journal_mode=DELETE", url)).await?;
+    conn.execute("CREATE TABLE IF NOT EXISTS users (id INTEGER PRIMARY KEY)")
+        .await?;
+    conn.execute("INSERT INTO users (id) VALUES (1)").await?;
+    conn.close().await?;
+
+    let mut conn = SqliteConnection::connect(&*format!("{}?mode=ro", url)).await?;
+
+    let (journal_mode,): (String,) = sqlx::query_as("PRAGMA journal_mode")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let (count,): (i32,) = sqlx::query_as("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(journal_mode, "delete");
+    assert_eq!(count, 1);
+
+    conn.close().await?;
+
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rejects_invalid_open_flags() -> anyhow::Result<()> {
+    let err = SqliteConnection::connect("sqlite::memory:?mode=bogus")
+        .await
+        .err()
+        .unwrap()
+        .to_string();
+
+    assert!(err.contains("unknown `mode` value: \"bogus\""), "{}", err);
+
+    let err = SqliteConnection::connect("sqlite::memory:?cache=bogus")
+        .await
+        .err()
+        .unwrap()
+        .to_string();
+
+    assert!(err.contains("unknown `cache` value: \"bogus\""), "{}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_waits_for_busy_timeout() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("sqlx-busy-timeout-{}.db", std::process::id()));
+
+    // a shared cache reports table locks as `SQLITE_LOCKED`, which is never retried
+    let url = format!("sqlite://{}?cache=private", path.display());
+
+    let mut conn = SqliteConnection::connect(&*url).await?;
+    conn.execute("CREATE TABLE IF NOT EXISTS users (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    // hold the write lock
+    conn.execute("BEGIN IMMEDIATE").await?;
+    conn.execute("INSERT INTO users DEFAULT VALUES").await?;
+
+    let mut waiting = SqliteConnection::connect(&*format!("{}&busy_timeout=500", url)).await?;
+    let start = std::time::Instant::now();
+
+    assert!(waiting
+        .execute("INSERT INTO users DEFAULT VALUES")
+        .await
+        .is_err());
+    assert!(start.elapsed() >= std::time::Duration::from_millis(500));
+
+    let mut failing = SqliteConnection::connect(&*url).await?;
+    let start = std::time::Instant::now();
+
+    match failing.execute("INSERT INTO users DEFAULT VALUES").await {
+        Err(sqlx::Error::Database(err)) => assert!(err.is_retryable(), "{}", err),
+        res => panic!("expected a busy error; got {:?}", res),
+    }
+
+    assert!(start.elapsed() < std::time::Duration::from_millis(500));
+
+    conn.execute("ROLLBACK").await?;
+
+    // the lock is released
+    assert_eq!(
+        waiting.execute("INSERT INTO users DEFAULT VALUES").await?,
+        1
+    );
+
+    let _ = std::fs::remove_file(&path);
+
+    assert!(
+        SqliteConnection::connect("sqlite::memory:?busy_timeout=soon")
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_limits_the_statement_cache() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:?statement_cache_capacity=2").await?;
+
+    for (i, query) in ["SELECT ?", "SELECT ? + 1", "SELECT ? + 2", "SELECT ?"]
+        .iter()
+        .enumerate()
+    {
+        let (value,): (i32,) = sqlx::query_as(query).bind(10).fetch_one(&mut conn).await?;
+
+        assert_eq!(value, 10 + (i as i32 % 3));
+        assert!(conn.cached_statements_size() <= 2);
+    }
+
+    assert_eq!(conn.cached_statements_size(), 2);
+
+    let mut conn = SqliteConnection::connect("sqlite::memory:?statement_cache_capacity=0").await?;
+
+    let (value,): (i32,) = sqlx::query_as("SELECT ?")
+        .bind(10)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 10);
+    assert_eq!(conn.cached_statements_size(), 0);
+
+    assert!(
+        SqliteConnection::connect("sqlite::memory:?statement_cache_capacity=-1")
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_sets_journal_mode_and_synchronous() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("sqlx-journal-mode-{}.db", std::process::id()));
+    let url = format!("sqlite://{}", path.display());
+
+    let mut conn =
+        SqliteConnection::connect(&*format!("{}?journal_mode=MEMORY&synchronous=full", url))
+            .await?;
+
+    let (journal_mode,): (String,) = sqlx::query_as("PRAGMA journal_mode")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let (synchronous,): (i32,) = sqlx::query_as("PRAGMA synchronous")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(journal_mode, "memory");
+    assert_eq!(synchronous, 2);
+
+    conn.close().await?;
+
+    // WAL and NORMAL by default
+    let mut conn = SqliteConnection::connect(&*url).await?;
+
+    let (journal_mode,): (String,) = sqlx::query_as("PRAGMA journal_mode")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let (synchronous,): (i32,) = sqlx::query_as("PRAGMA synchronous")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(journal_mode, "wal");
+    assert_eq!(synchronous, 1);
+
+    conn.close().await?;
+
+    let _ = std::fs::remove_file(&path);
+
+    let err = SqliteConnection::connect("sqlite::memory:?journal_mode=WAL;DROP")
+        .await
+        .err()
+        .unwrap()
+        .to_string();
+
+    assert!(err.contains("unknown `journal_mode` value"), "{}", err);
+
+    assert!(
+        SqliteConnection::connect("sqlite::memory:?synchronous=sometimes")
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_enforces_foreign_keys() -> anyhow::Result<()> {
+    async fn insert_orphan(url: &str) -> anyhow::Result<sqlx::Result<Sqlite, u64>> {
+        let mut conn = SqliteConnection::connect(url).await?;
+
+        conn.execute(
+            r#"
+CREATE TEMPORARY TABLE parents (id INTEGER PRIMARY KEY);
+CREATE TEMPORARY TABLE children (parent_id INTEGER NOT NULL REFERENCES parents (id));
+            "#,
+        )
+        .await?;
+
+        Ok(conn
+            .execute("INSERT INTO children (parent_id) VALUES (1)")
+            .await)
+    }
+
+    assert!(insert_orphan("sqlite::memory:?foreign_keys=true")
+        .await?
+        .is_err());
+
+    assert_eq!(
+        insert_orphan("sqlite::memory:?foreign_keys=false").await??,
+        1
+    );
+
+    assert!(
+        SqliteConnection::connect("sqlite::memory:?foreign_keys=yes")
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_loads_extensions_only_when_enabled() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let err = conn
+        .load_extension("sqlx_does_not_exist", None)
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("extension loading is disabled"), "{}", err);
+    assert!(!err.contains("invalid connection URL"), "{}", err);
+
+    let mut conn = SqliteConnection::connect("sqlite::memory:?load_extension=true").await?;
+
+    // the error from SQLite is passed through
+    let err = conn
+        .load_extension("sqlx_does_not_exist", Some("sqlite3_extension_init"))
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("sqlx_does_not_exist"), "{}", err);
+
+    // loading is disabled again for SQL
+    assert!(conn
+        .execute("SELECT load_extension('sqlx_does_not_exist')")
+        .await
+        .unwrap_err()
+        .to_string()
+        .contains("not authorized"));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes_a_batch() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let rows = conn
+        .execute_batch(
+            "CREATE TABLE batch_users (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+             CREATE INDEX batch_users_name ON batch_users (name);
+             INSERT INTO batch_users (name) VALUES ('Alice'), ('Bob');",
+        )
+        .await?;
+
+    assert_eq!(rows, 2);
+
+    let (count,): (i32,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM sqlite_master WHERE name IN ('batch_users', 'batch_users_name')",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(count, 2);
+
+    // the script stops at the first error
+    let err = conn
+        .execute_batch(
+            "INSERT INTO batch_users (name) VALUES ('Carol');
+             INSERT INTO batch_users (name) VALUES (NULL);
+             INSERT INTO batch_users (name) VALUES ('Dave');",
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("NOT NULL constraint failed"), "{}", err);
+
+    let (count,): (i32,) = sqlx::query_as("SELECT COUNT(*) FROM batch_users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_shares_a_named_in_memory_database() -> anyhow::Result<()> {
+    let url = "sqlite:file:sqlx-shared-memory?mode=memory&cache=shared";
+
+    let mut conn = SqliteConnection::connect(url).await?;
+    conn.execute("CREATE TABLE users (id INTEGER PRIMARY KEY)")
+        .await?;
+    conn.execute("INSERT INTO users (id) VALUES (1)").await?;
+
+    let mut other = SqliteConnection::connect(url).await?;
+
+    let (count,): (i32,) = sqlx::query_as("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut other)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    // `:memory:` is always a new, private database
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+    conn.execute("CREATE TABLE users (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut other = SqliteConnection::connect("sqlite::memory:").await?;
+    assert!(other.execute("SELECT * FROM users").await.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_opens_uri_filenames() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("sqlx-uri-{}.db", std::process::id()));
+
+    let mut conn = SqliteConnection::connect(&*format!("sqlite://{}", path.display())).await?;
+    conn.execute("CREATE TABLE IF NOT EXISTS users (id INTEGER PRIMARY KEY)")
+        .await?;
+    conn.close().await?;
+
+    let url = format!("sqlite:file:{}", path.display());
+
+    let mut conn = SqliteConnection::connect(&*format!("{}?mode=ro", url)).await?;
+    assert!(conn
+        .execute("INSERT INTO users (id) VALUES (1)")
+        .await
+        .is_err());
+
+    // `vfs` is only known to SQLite
+    let err = SqliteConnection::connect(&*format!("{}?vfs=sqlx-does-not-exist", url))
+        .await
+        .err()
+        .unwrap()
+        .to_string();
+
+    assert!(err.contains("no such vfs"), "{}", err);
+
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    // begin
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("INSERT INTO users (id) VALUES (?)")
+        .bind(50_i32)
+        .execute(&mut tx)
+        .await?;
+
+    // begin once more, as a save point
+    let mut tx = tx.begin().await?;
+
+    sqlx::query("INSERT INTO users (id) VALUES (?)")
+        .bind(10_i32)
+        .execute(&mut tx)
+        .await?;
+
+    // never mind, rollback to the save point
+    let mut tx = tx.rollback().await?;
+
+    let (count,): (i32,) = sqlx::query_as("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut tx)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    // a save point that is released is kept by the outer transaction
+    let mut tx = tx.begin().await?;
+
+    sqlx::query("INSERT INTO users (id) VALUES (?)")
+        .bind(20_i32)
+        .execute(&mut tx)
+        .await?;
+
+    let mut conn = tx.commit().await?.commit().await?;
+
+    let (count,): (i32,) = sqlx::query_as("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_begins_a_save_point_through_connection() -> anyhow::Result<()> {
+    // `Connection::begin` on a transaction must not issue another `BEGIN`
+    async fn begin<C: Connection>(conn: C) -> sqlx::Result<C::Database, sqlx::Transaction<C>> {
+        conn.begin().await
+    }
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let tx = begin(conn).await?;
+    let mut tx = begin(tx).await?;
+
+    tx.execute("INSERT INTO users DEFAULT VALUES").await?;
+
+    let mut tx = tx.rollback().await?;
+
+    let (count,): (i32,) = sqlx::query_as("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut tx)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_begins_a_read_only_transaction() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE read_only_users (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut tx = Transaction::builder().read_only(true).begin(conn).await?;
+
+    let err = tx
+        .execute("INSERT INTO read_only_users DEFAULT VALUES")
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("readonly"), "{}", err);
+
+    let mut conn = tx.commit().await?;
+
+    // the connection can write again once the transaction has ended
+    conn.execute("INSERT INTO read_only_users DEFAULT VALUES")
+        .await?;
+
+    // SQLite transactions are always serializable
+    let err = Transaction::builder()
+        .isolation_level(IsolationLevel::ReadCommitted)
+        .begin(conn)
+        .await
+        .err()
+        .unwrap()
+        .to_string();
+
+    assert!(err.contains("unsupported isolation level"), "{}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rolls_back_a_dropped_transaction() -> anyhow::Result<()> {
+    let pool = SqlitePool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE dropped_tx (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+
+    tx.execute("INSERT INTO dropped_tx DEFAULT VALUES").await?;
+
+    drop(tx);
+
+    // the connection, and its temporary table, is returned to the pool once the transaction
+    // is rolled back
+    let (count,): (i32,) = sqlx::query_as("SELECT COUNT(*) FROM dropped_tx")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    let mut tx = pool.begin().await?;
+
+    tx.execute("INSERT INTO dropped_tx DEFAULT VALUES").await?;
+
+    tx.commit().await?;
+
+    let (count,): (i32,) = sqlx::query_as("SELECT COUNT(*) FROM dropped_tx")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rolls_back_a_transaction_that_fails_to_commit() -> anyhow::Result<()> {
+    let pool = SqlitePool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    conn.execute(
+        r#"
+PRAGMA foreign_keys = ON;
+CREATE TEMPORARY TABLE failed_commit_parents (id INTEGER PRIMARY KEY);
+CREATE TEMPORARY TABLE failed_commit_children (
+    parent_id INTEGER REFERENCES failed_commit_parents (id) DEFERRABLE INITIALLY DEFERRED
+);
+            "#,
+    )
+    .await?;
+
+    let mut tx = conn.begin().await?;
+
+    // the deferred foreign key is only checked by `COMMIT`, which fails and leaves the
+    // transaction open
+    tx.execute("INSERT INTO failed_commit_children (parent_id) VALUES (1)")
+        .await?;
+
+    assert!(tx.commit().await.is_err());
+
+    // the connection is returned to the pool once the transaction is rolled back
+    let (count,): (i32,) = sqlx::query_as("SELECT COUNT(*) FROM failed_commit_children")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    pool.begin().await?.commit().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_at_most_one_row() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let row = sqlx::query("SELECT 1 WHERE 0")
+        .try_map(|row: SqliteRow| row.try_get::<i32, _>(0))
+        .fetch_at_most_one(&mut conn)
+        .await?;
+
+    assert_eq!(row, None);
+
+    let row = sqlx::query("SELECT 1")
+        .try_map(|row: SqliteRow| row.try_get::<i32, _>(0))
+        .fetch_at_most_one(&mut conn)
+        .await?;
+
+    assert_eq!(row, Some(1));
+
+    let res = sqlx::query("SELECT 1 UNION ALL SELECT 2 UNION ALL SELECT 3")
+        .try_map(|row: SqliteRow| row.try_get::<i32, _>(0))
+        .fetch_at_most_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::TooManyRows)), "{:?}", res);
+
+    let res: Result<Option<(i32,)>, _> =
+        sqlx::query_as("SELECT 1 UNION ALL SELECT 2 UNION ALL SELECT 3")
+            .fetch_at_most_one(&mut conn)
+            .await;
+
+    assert!(matches!(res, Err(sqlx::Error::TooManyRows)), "{:?}", res);
+
+    // the rest of the rows are discarded
+    let (value,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(&mut conn).await?;
+
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_gets_columns_that_may_not_exist() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut cursor =
+        sqlx::query("SELECT 1 AS id, NULL AS name, 5000000000 AS big").fetch(&mut conn);
+    let row: SqliteRow = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get_opt::<i32, _>("id")?, Some(1));
+    assert_eq!(row.try_get_opt::<i32, _>(0)?, Some(1));
+
+    // a NULL column is distinct from a missing one
+    assert_eq!(row.try_get_opt::<Option<String>, _>("name")?, Some(None));
+    assert_eq!(row.try_get_opt::<Option<String>, _>("email")?, None);
+    assert_eq!(row.try_get_opt::<Option<String>, _>(3)?, None);
+
+    // a value that cannot be decoded is still an error
+    assert!(row.try_get_opt::<i32, _>("big").is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_describes_the_columns_of_a_row() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TABLE column_info (id INTEGER PRIMARY KEY, name VARCHAR(255))")
+        .await?;
+    conn.execute("INSERT INTO column_info (name) VALUES ('Alice')")
+        .await?;
+
+    let mut cursor = sqlx::query("SELECT id, name, 1 + 1 AS two FROM column_info").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+
+    let columns: Vec<_> = row
+        .columns()
+        .iter()
+        .map(|column| {
+            (
+                column.name(),
+                column.ordinal(),
+                column.decl_type(),
+                column.type_info().map(|ty| ty.to_string()),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        columns,
+        [
+            ("id", 0, Some("INTEGER"), Some("INTEGER".to_owned())),
+            ("name", 1, Some("VARCHAR(255)"), Some("TEXT".to_owned())),
+            ("two", 2, None, None),
+        ]
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_gets_the_last_insert_rowid_and_changes() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE rowid_users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .await?;
+
+    let total = conn.total_changes().await;
+
+    sqlx::query("INSERT INTO rowid_users (name) VALUES (?)")
+        .bind("Alice")
+        .execute(&mut conn)
+        .await?;
+
+    let rowid = conn.last_insert_rowid().await;
+
+    let (id,): (i64,) = sqlx::query_as("SELECT id FROM rowid_users WHERE name = 'Alice'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(rowid, id);
+    assert_eq!(conn.changes().await, 1);
+
+    conn.execute("INSERT INTO rowid_users (name) VALUES ('Bob'), ('Carol')")
+        .await?;
+
+    assert_eq!(conn.last_insert_rowid().await, rowid + 2);
+    assert_eq!(conn.changes().await, 2);
+    assert_eq!(conn.total_changes().await, total + 3);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_calls_the_query_hook() -> anyhow::Result<()> {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    let queries = Arc::new(Mutex::new(Vec::new()));
+    let mut conn = new::<Sqlite>().await?;
+
+    let log = Arc::clone(&queries);
+    conn.on_query(move |query| {
+        let query = (query.sql().to_owned(), query.arguments(), query.elapsed());
+        log.lock().unwrap().push(query);
+    });
+
+    conn.execute("CREATE TEMPORARY TABLE hooked (id INTEGER)")
+        .await?;
+
+    let ids: Vec<(i32,)> = sqlx::query_as("SELECT id FROM hooked WHERE id > $1")
+        .bind(10_i32)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert!(ids.is_empty());
+
+    let queries = queries.lock().unwrap();
+
+    assert_eq!(queries.len(), 2);
+
+    assert_eq!(queries[0].0, "CREATE TEMPORARY TABLE hooked (id INTEGER)");
+    assert_eq!(queries[0].1, 0);
+    assert!(queries[0].2 > Duration::from_secs(0));
+
+    // the values of the arguments are never passed to the hook
+    assert_eq!(queries[1].0, "SELECT id FROM hooked WHERE id > $1");
+    assert_eq!(queries[1].1, 1);
+    assert!(queries[1].2 > Duration::from_secs(0));
+
+    Ok(())
+}
+
+#[cfg(feature = "tracing")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_logs_slow_queries() -> anyhow::Result<()> {
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tracing_::field::{Field, Visit};
+    use tracing_::span::{Attributes, Id, Record};
+    use tracing_::{Event, Level, Metadata, Subscriber};
+
+    // Captures the SQL of each warning
+    struct Capture(Arc<Mutex<Vec<String>>>);
+
+    struct SqlField(Option<String>);
+
+    impl Visit for SqlField {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            if field.name() == "sql" {
+                self.0 = Some(format!("{:?}", value));
+            }
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            if *event.metadata().level() == Level::WARN {
+                let mut sql = SqlField(None);
+                event.record(&mut sql);
+
+                self.0.lock().unwrap().extend(sql.0);
+            }
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let _guard = tracing_::subscriber::set_default(Capture(Arc::clone(&warnings)));
+
+    let mut conn = new::<Sqlite>().await?;
+    conn.log_slow_queries(Duration::from_millis(10));
+
+    // a fast statement is not logged
+    conn.execute("SELECT 1").await?;
+
+    let slow = "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c WHERE x < 3000000) \
+                SELECT COUNT(*) FROM c";
+
+    let (count,): (i64,) = sqlx::query_as(slow).fetch_one(&mut conn).await?;
+    assert_eq!(count, 3000000);
+
+    assert_eq!(*warnings.lock().unwrap(), vec![slow.to_owned()]);
+
+    Ok(())
+}